@@ -3,8 +3,8 @@ use ascii_canvas::{
     AsciiCanvas, AsciiView,
 };
 use leftwm_layouts::{
-    geometry::{Flip, Rect, Reserve, Rotation, Split},
-    layouts::{Columns, Main, SecondStack, Stack},
+    geometry::{Flip, Orientation, Rect, Reserve, Rotation, Split},
+    layouts::{Columns, Main, SecondStack, Stack, StackFillOrder},
     Layout,
 };
 
@@ -75,6 +75,9 @@ fn demo_layout() -> Layout {
         columns: Columns {
             flip: Flip::None,
             rotate: Rotation::South,
+            orientation: Orientation::Horizontal,
+            main_position: None,
+            adapt_to_portrait: false,
             main: Some(Main {
                 count: 2,
                 ..Default::default()
@@ -84,7 +87,13 @@ fn demo_layout() -> Layout {
                 ..Default::default()
             },
             second_stack: None,
+            balance_stacks: None,
+            stack_distribution: None,
+            stack_fill_order: StackFillOrder::StackFirst,
+            second_main: None,
+            list: vec![],
         },
+        ..Default::default()
     }*/
     leftwm_layouts::Layout {
         name: "Demo".to_string(),
@@ -94,6 +103,9 @@ fn demo_layout() -> Layout {
         columns: Columns {
             flip: Flip::Vertical,
             rotate: Rotation::North,
+            orientation: Orientation::Horizontal,
+            main_position: None,
+            adapt_to_portrait: false,
             main: Some(Main {
                 ..Default::default()
             }),
@@ -103,6 +115,12 @@ fn demo_layout() -> Layout {
             second_stack: Some(SecondStack {
                 ..Default::default()
             }),
+            balance_stacks: None,
+            stack_distribution: None,
+            stack_fill_order: StackFillOrder::StackFirst,
+            second_main: None,
+            list: vec![],
         },
+        ..Default::default()
     }
 }
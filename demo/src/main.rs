@@ -21,11 +21,10 @@ struct DemoState {
 impl Default for DemoState {
     fn default() -> Self {
         let layouts = Layouts::default();
-        let names = layouts.names();
-        let name = names.get(0).unwrap();
+        let name = layouts.names().next().unwrap().to_owned();
         Self {
             layouts,
-            current_layout: name.to_owned(),
+            current_layout: name,
             window_count: 3,
         }
     }
@@ -70,11 +69,11 @@ impl DemoState {
     }
 
     fn toggle_flipped_horizontal(&mut self) {
-        //self.current_mut().flipped = self.current().flipped.toggle_horizontal()
+        self.current_mut().toggle_flip_horizontal()
     }
 
     fn toggle_flipped_vertical(&mut self) {
-        //self.current_mut().flipped = self.current().flipped.toggle_vertical()
+        self.current_mut().toggle_flip_vertical()
     }
 
     fn toggle_balance_stacks(&mut self) {
@@ -119,10 +118,10 @@ fn build_root_widget() -> impl Widget<DemoState> {
 }
 
 fn controls() -> impl Widget<DemoState> {
-    let names = Layouts::default().names();
+    let layouts = Layouts::default();
 
     let mut col = Flex::column();
-    for key in names {
+    for key in layouts.names().map(str::to_owned) {
         let button = button(key.to_owned())
             .on_click(move |_ctx, data: &mut DemoState, _env| data.current_layout = key.to_owned());
         col.add_child(button)
@@ -146,10 +145,10 @@ fn controls() -> impl Widget<DemoState> {
     let dec_main_count = button("DecreaseMainCount")
         .on_click(move |_ctx, data: &mut DemoState, _env| data.decrease_main_count());
 
-    /*let flip_h = button(|data: &DemoState, _env: &_| {
+    let flip_h = button(|data: &DemoState, _env: &_| {
         format!(
-            "FlipHorziontal: {}",
-            data.current().flipped.is_flipped_horizontal()
+            "FlipHorizontal: {}",
+            data.current().flip.is_flipped_horizontal()
         )
     })
     .on_click(move |_ctx, data: &mut DemoState, _env| data.toggle_flipped_horizontal());
@@ -157,10 +156,10 @@ fn controls() -> impl Widget<DemoState> {
     let flip_v = button(|data: &DemoState, _env: &_| {
         format!(
             "FlipVertical: {}",
-            data.current().flipped.is_flipped_vertical()
+            data.current().flip.is_flipped_vertical()
         )
     })
-    .on_click(move |_ctx, data: &mut DemoState, _env| data.toggle_flipped_vertical());*/
+    .on_click(move |_ctx, data: &mut DemoState, _env| data.toggle_flipped_vertical());
 
     let rotation =
         button(|data: &DemoState, _env: &_| format!("Rotation: {:?}", data.current().rotate))
@@ -189,10 +188,10 @@ fn controls() -> impl Widget<DemoState> {
         .with_child(dec_main_count)
         .with_child(add_window)
         .with_child(remove_window)
-        .with_child(rotation);
-    /*.with_child(flip_h)
-    .with_child(flip_v)
-    .with_child(balance_stacks)
+        .with_child(rotation)
+        .with_child(flip_h)
+        .with_child(flip_v);
+    /*.with_child(balance_stacks)
     .with_child(reserve_space)*/
 
     flex.fix_width(260.0).expand_height().background(PRIMARY)
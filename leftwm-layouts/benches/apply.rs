@@ -0,0 +1,58 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use leftwm_layouts::{
+    apply,
+    geometry::{Rect, Remainder, Rotation, Split},
+    layouts::Layouts,
+};
+
+const CONTAINER: Rect = Rect {
+    x: 0,
+    y: 0,
+    w: 1920,
+    h: 1080,
+};
+
+/// Applies every default layout to a growing amount of windows, rotated and
+/// with the remainder in the center to hit the slower paths. The time should
+/// grow linearly, except for the binary tree split, which grows by `n log n`.
+fn apply_layouts(c: &mut Criterion) {
+    for mut layout in Layouts::default() {
+        layout.rotate = Rotation::East;
+        layout.remainder = Remainder::Center;
+        let mut group = c.benchmark_group(format!("apply/{}", layout.name));
+        for window_count in [1_000, 8_000] {
+            group.bench_with_input(
+                BenchmarkId::from_parameter(window_count),
+                &window_count,
+                |b, &window_count| b.iter(|| apply(&layout, black_box(window_count), &CONTAINER)),
+            );
+        }
+        group.finish();
+    }
+}
+
+/// Applies a stack split by each of the slower splits to a growing amount of windows.
+fn apply_splits(c: &mut Criterion) {
+    let base = Layouts::default().get("MainAndVertStack").unwrap().clone();
+    for split in [
+        Split::Fibonacci,
+        Split::BinaryTree(0.6),
+        Split::Tatami,
+        Split::AspectRatio(Split::DEFAULT_ASPECT_RATIO),
+    ] {
+        let mut layout = base.clone();
+        layout.columns.stack.split = Some(split);
+        let mut group = c.benchmark_group(format!("apply_split/{split:?}"));
+        for window_count in [1_000, 8_000] {
+            group.bench_with_input(
+                BenchmarkId::from_parameter(window_count),
+                &window_count,
+                |b, &window_count| b.iter(|| apply(&layout, black_box(window_count), &CONTAINER)),
+            );
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, apply_layouts, apply_splits);
+criterion_main!(benches);
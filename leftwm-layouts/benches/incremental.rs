@@ -0,0 +1,40 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use leftwm_layouts::{apply, geometry::Rect, layouts::Layouts, IncrementalApply};
+
+const CONTAINER: Rect = Rect {
+    x: 0,
+    y: 0,
+    w: 1920,
+    h: 1080,
+};
+
+/// Compares a full [`apply`] with an incremental insert and remove
+/// for a growing amount of windows. The incremental update of the
+/// fibonacci layout should take the same time for all counts.
+fn add_window(c: &mut Criterion) {
+    let layout = Layouts::default().get("Fibonacci").unwrap().clone();
+    let mut group = c.benchmark_group("add_window");
+    for window_count in [10, 100, 1_000, 10_000] {
+        group.bench_with_input(
+            BenchmarkId::new("apply", window_count),
+            &window_count,
+            |b, &window_count| b.iter(|| apply(&layout, black_box(window_count + 1), &CONTAINER)),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("incremental", window_count),
+            &window_count,
+            |b, &window_count| {
+                let mut incremental =
+                    IncrementalApply::new(layout.clone(), window_count, CONTAINER);
+                b.iter(|| {
+                    incremental.insert(black_box(window_count / 2));
+                    incremental.remove(black_box(window_count / 2))
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, add_window);
+criterion_main!(benches);
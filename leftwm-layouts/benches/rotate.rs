@@ -0,0 +1,33 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use leftwm_layouts::geometry::{rotate, split, Rect, Rotation, Split};
+
+const CONTAINER: Rect = Rect {
+    x: 0,
+    y: 0,
+    w: 1920,
+    h: 1080,
+};
+
+/// Rotates a grid of a growing amount of tiles, which happens on every
+/// frame while resizing a rotated layout. The time should grow linearly.
+fn rotate_grid(c: &mut Criterion) {
+    let mut group = c.benchmark_group("rotate_grid");
+    for tile_count in [10, 100, 1_000, 10_000] {
+        let tiles = split(&CONTAINER, tile_count, Some(Split::Grid));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(tile_count),
+            &tiles,
+            |b, tiles| {
+                b.iter(|| {
+                    let mut tiles = tiles.clone();
+                    rotate(&mut tiles, black_box(Rotation::East), &CONTAINER);
+                    tiles
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, rotate_grid);
+criterion_main!(benches);
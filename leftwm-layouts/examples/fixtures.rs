@@ -0,0 +1,13 @@
+//! Prints the behavior fixtures of the default layouts as JSON,
+//! see [`leftwm_layouts::compat::fixtures`].
+//!
+//! ```sh
+//! cargo run --example fixtures > fixtures.json
+//! ```
+
+use leftwm_layouts::compat::fixtures;
+
+fn main() {
+    let json = serde_json::to_string_pretty(&fixtures()).expect("fixtures are serializable");
+    println!("{json}");
+}
@@ -0,0 +1,171 @@
+//! Versioning of the geometry of the default layouts, so that window managers
+//! can tell their users when the layouts they are used to changed after an upgrade.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{apply, geometry::Rect, layouts::Layouts, Layout};
+
+/// Version of the behavior of the default layouts.
+/// It is increased whenever the tiles returned for any of the default layouts change.
+pub const BEHAVIOR_VERSION: u32 = 6;
+
+/// A change of the [`BEHAVIOR_VERSION`].
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BehaviorChange {
+    /// The [`BEHAVIOR_VERSION`] introduced by this change.
+    pub version: u32,
+
+    /// A short description of the change, meant to be shown to users.
+    pub description: &'static str,
+}
+
+/// Every change of the [`BEHAVIOR_VERSION`], the oldest first.
+pub const BEHAVIOR_CHANGES: &[BehaviorChange] = &[
+    BehaviorChange {
+        version: 1,
+        description: "Start of the versioning of the default layouts",
+    },
+    BehaviorChange {
+        version: 2,
+        description: "Add the BSP default layout",
+    },
+    BehaviorChange {
+        version: 3,
+        description: "Add the TopMainAndStack default layout",
+    },
+    BehaviorChange {
+        version: 4,
+        description: "Add the MainAndGridStack default layout",
+    },
+    BehaviorChange {
+        version: 5,
+        description: "Add the ThreeColumnEqual default layout",
+    },
+    BehaviorChange {
+        version: 6,
+        description: "Add the MainAndDualStack default layout",
+    },
+];
+
+/// The changes of the default layouts since the provided `recorded` version,
+/// eg. the [`BEHAVIOR_VERSION`] a window manager stored the last time it started.
+/// An empty result means that the default layouts didn't change.
+///
+/// ## Example
+/// ```rust
+/// use leftwm_layouts::compat::{changes_since, BEHAVIOR_VERSION};
+///
+/// assert!(changes_since(BEHAVIOR_VERSION).is_empty());
+/// for change in changes_since(0) {
+///     println!("layouts changed in version {}: {}", change.version, change.description);
+/// }
+/// ```
+pub fn changes_since(recorded: u32) -> &'static [BehaviorChange] {
+    let first_new = BEHAVIOR_CHANGES.partition_point(|change| change.version <= recorded);
+    &BEHAVIOR_CHANGES[first_new..]
+}
+
+/// The containers the [`fixtures`] are calculated in.
+pub const FIXTURE_CONTAINERS: [Rect; 2] =
+    [Rect::new(0, 0, 1920, 1080), Rect::new(0, 0, 1279, 1023)];
+
+/// The tiles of a layout for a specific amount of windows and container,
+/// as returned by [`crate::apply`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Fixture {
+    /// The layout definition.
+    pub layout: Layout,
+
+    /// The amount of windows.
+    pub window_count: usize,
+
+    /// The container the windows are displayed in.
+    pub container: Rect,
+
+    /// The expected tiles.
+    pub rects: Vec<Rect>,
+}
+
+/// The tiles of every default layout for 0 to 9 windows in each of the
+/// [`FIXTURE_CONTAINERS`], which the [`BEHAVIOR_VERSION`] is based on.
+///
+/// Serialized as JSON (see the `fixtures` example), these allow bindings and
+/// other implementations to verify that they calculate the exact same tiles.
+/// ```sh
+/// cargo run --example fixtures > fixtures.json
+/// ```
+pub fn fixtures() -> Vec<Fixture> {
+    let mut fixtures = vec![];
+    for layout in Layouts::default() {
+        for container in FIXTURE_CONTAINERS {
+            for window_count in 0..10 {
+                fixtures.push(Fixture {
+                    rects: apply(&layout, window_count, &container),
+                    layout: layout.clone(),
+                    window_count,
+                    container,
+                });
+            }
+        }
+    }
+    fixtures
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{changes_since, fixtures, Fixture, BEHAVIOR_CHANGES, BEHAVIOR_VERSION};
+    use crate::apply;
+
+    /// The fingerprint of the tiles of all default layouts at the current [`BEHAVIOR_VERSION`].
+    const FINGERPRINT: u64 = 0xe059_7a25_b85d_f25b;
+
+    /// FNV-1a over the tiles of the [`fixtures`], which other than
+    /// [`std::collections::hash_map::DefaultHasher`] is stable across Rust versions.
+    fn fingerprint() -> u64 {
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        for tile in fixtures().into_iter().flat_map(|fixture| fixture.rects) {
+            let values = [tile.x as u32, tile.y as u32, tile.w, tile.h];
+            for byte in values.iter().flat_map(|value| value.to_le_bytes()) {
+                hash ^= u64::from(byte);
+                hash = hash.wrapping_mul(0x0100_0000_01b3);
+            }
+        }
+        hash
+    }
+
+    #[test]
+    fn behavior_version_is_bumped_when_default_layouts_change() {
+        assert_eq!(
+            FINGERPRINT,
+            fingerprint(),
+            "the tiles of the default layouts changed, increase the BEHAVIOR_VERSION, \
+             add a BehaviorChange, and update the FINGERPRINT"
+        );
+    }
+
+    #[test]
+    fn behavior_changes_end_with_the_current_version() {
+        assert_eq!(
+            Some(BEHAVIOR_VERSION),
+            BEHAVIOR_CHANGES.last().map(|c| c.version)
+        );
+        assert!(BEHAVIOR_CHANGES
+            .windows(2)
+            .all(|w| w[0].version < w[1].version));
+        assert_eq!(BEHAVIOR_CHANGES, changes_since(0));
+        assert!(changes_since(BEHAVIOR_VERSION).is_empty());
+    }
+
+    #[test]
+    fn fixtures_survive_a_json_round_trip() {
+        let json = serde_json::to_string(&fixtures()).unwrap();
+        let parsed: Vec<Fixture> = serde_json::from_str(&json).unwrap();
+        assert_eq!(fixtures(), parsed);
+        for fixture in parsed {
+            assert_eq!(
+                fixture.rects,
+                apply(&fixture.layout, fixture.window_count, &fixture.container)
+            );
+        }
+    }
+}
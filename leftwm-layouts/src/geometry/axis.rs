@@ -0,0 +1,101 @@
+use serde::{Deserialize, Serialize};
+
+use super::Rect;
+
+/// Represents the two axes along which a [`Rect`] can be divided.
+///
+/// Dividing a [`Rect`] along the [`Axis::X`] results in tiles next to each other,
+/// while dividing along the [`Axis::Y`] results in tiles on top of each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Axis {
+    /// The horizontal axis, dividing along it
+    /// means cutting vertically (see [`crate::geometry::Split::Vertical`]).
+    ///
+    /// ```txt
+    /// +--+--+--+
+    /// |  |  |  |
+    /// |  |  |  |
+    /// +--+--+--+
+    /// ```
+    X,
+
+    /// The vertical axis, dividing along it
+    /// means cutting horizontally (see [`crate::geometry::Split::Horizontal`]).
+    ///
+    /// ```txt
+    /// +--------+
+    /// +--------+
+    /// +--------+
+    /// +--------+
+    /// ```
+    Y,
+}
+
+impl Axis {
+    /// Get the respective other [`Axis`].
+    pub fn other(&self) -> Self {
+        match self {
+            Axis::X => Axis::Y,
+            Axis::Y => Axis::X,
+        }
+    }
+
+    /// Get the position where the provided [`Rect`] starts on this [`Axis`].
+    pub fn start(&self, rect: &Rect) -> i32 {
+        match self {
+            Axis::X => rect.x,
+            Axis::Y => rect.y,
+        }
+    }
+
+    /// Get the length of the provided [`Rect`] on this [`Axis`].
+    pub fn length(&self, rect: &Rect) -> u32 {
+        match self {
+            Axis::X => rect.w,
+            Axis::Y => rect.h,
+        }
+    }
+
+    /// Get a copy of the provided [`Rect`] with a new `start`
+    /// and `length` on this [`Axis`], the other [`Axis`] stays untouched.
+    pub fn with_span(&self, rect: &Rect, start: i32, length: u32) -> Rect {
+        match self {
+            Axis::X => Rect {
+                x: start,
+                w: length,
+                ..*rect
+            },
+            Axis::Y => Rect {
+                y: start,
+                h: length,
+                ..*rect
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::{Axis, Rect};
+
+    #[test]
+    fn with_span_keeps_the_other_axis() {
+        let rect = Rect::new(10, 20, 300, 400);
+        assert_eq!(
+            Rect::new(50, 20, 100, 400),
+            Axis::X.with_span(&rect, 50, 100)
+        );
+        assert_eq!(
+            Rect::new(10, 50, 300, 100),
+            Axis::Y.with_span(&rect, 50, 100)
+        );
+    }
+
+    #[test]
+    fn start_and_length() {
+        let rect = Rect::new(10, 20, 300, 400);
+        assert_eq!((10, 300), (Axis::X.start(&rect), Axis::X.length(&rect)));
+        assert_eq!((20, 400), (Axis::Y.start(&rect), Axis::Y.length(&rect)));
+        assert_eq!(Axis::Y, Axis::X.other());
+    }
+}
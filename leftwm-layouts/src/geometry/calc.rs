@@ -1,7 +1,10 @@
-use crate::geometry::{Flip, Rect, Rotation, Split};
+use crate::geometry::{Axis, Flip, Gaps, Rect, Remainder, Rotation, Spiral, Split};
 use std::{ops::Rem, vec};
 
-use super::split::{dwindle, fibonacci, grid, horizontal, vertical};
+use super::split::{
+    aspect_ratio, auto, auto_axis, binary_tree, columns, dwindle, even, fibonacci, fixed_grid,
+    grid, horizontal_accordion, row_grid, rows, tatami, vertical_accordion, weighted,
+};
 
 /// Divide the provided `a` by `b` and return the
 /// result of the integer division as well as the remainder.
@@ -27,18 +30,151 @@ pub fn divrem(a: usize, b: usize) -> (usize, usize) {
 /// assert_eq!(vec![4,4,3], result);
 /// ```
 pub fn remainderless_division(a: usize, b: usize) -> Vec<usize> {
-    let mut vec: Vec<usize> = vec![];
-    let (div, mut rem) = divrem(a, b);
-    for _ in 0..b {
-        let val = if rem > 0 {
-            rem -= 1;
-            div + 1
-        } else {
-            div
-        };
-        vec.push(val);
+    remainderless_division_with(a, b, Remainder::First)
+}
+
+/// Same as [`remainderless_division`], but the provided [`Remainder`]
+/// decides which of the results get the remainder.
+///
+/// ## Example
+/// ```rust
+/// use leftwm_layouts::geometry::{remainderless_division_with, Remainder};
+///
+/// assert_eq!(vec![2, 3, 3, 2, 2], remainderless_division_with(12, 5, Remainder::Center));
+/// assert_eq!(vec![2, 3, 2, 3, 2], remainderless_division_with(12, 5, Remainder::Spread));
+/// ```
+pub fn remainderless_division_with(a: usize, b: usize, remainder: Remainder) -> Vec<usize> {
+    let (div, rem) = divrem(a, b);
+    (0..b)
+        .map(|i| {
+            if remainder.gets_extra(i, b, rem) {
+                div + 1
+            } else {
+                div
+            }
+        })
+        .collect()
+}
+
+/// Divide the provided `total` into parts proportional to the
+/// provided `weights`, while making sure the parts always add up
+/// to exactly `total`.
+///
+/// Each part is rounded down first, the remaining units are then
+/// given to the parts with the largest fractional remainders
+/// (the earlier part wins on a tie).
+///
+/// ## Hint
+/// Negative or non-finite weights are treated as zero. If none of the
+/// weights is larger than zero, `total` is divided evenly as in
+/// [`remainderless_division`].
+///
+/// ## Example
+/// ```rust
+/// let result = leftwm_layouts::geometry::weighted_division(100, &[2.0, 1.0, 1.0]);
+/// assert_eq!(vec![50, 25, 25], result);
+/// ```
+#[cfg(not(feature = "no-fp"))]
+pub fn weighted_division(total: usize, weights: &[f32]) -> Vec<usize> {
+    if weights.is_empty() {
+        return vec![];
+    }
+
+    let weights: Vec<f64> = weights
+        .iter()
+        .map(|w| {
+            if w.is_finite() && *w > 0.0 {
+                f64::from(*w)
+            } else {
+                0.0
+            }
+        })
+        .collect();
+    let sum: f64 = weights.iter().sum();
+    if sum <= 0.0 {
+        return remainderless_division(total, weights.len());
+    }
+
+    let exact: Vec<f64> = weights.iter().map(|w| total as f64 * w / sum).collect();
+    let mut parts: Vec<usize> = exact.iter().map(|e| e.floor() as usize).collect();
+
+    // hand out the units lost to rounding down, largest fraction first
+    let mut order: Vec<usize> = (0..parts.len()).collect();
+    order.sort_by(|a, b| {
+        let fraction_a = exact[*a] - exact[*a].floor();
+        let fraction_b = exact[*b] - exact[*b].floor();
+        fraction_b.total_cmp(&fraction_a)
+    });
+    let remainder = total.saturating_sub(parts.iter().sum());
+    for i in order.into_iter().cycle().take(remainder) {
+        parts[i] += 1;
     }
-    vec
+    parts
+}
+
+/// Divide the provided `total` into parts proportional to the
+/// provided `weights`, while making sure the parts always add up
+/// to exactly `total`.
+///
+/// Each part is rounded down first, the remaining units are then
+/// given to the parts with the largest fractional remainders
+/// (the earlier part wins on a tie).
+///
+/// ## Hint
+/// Negative or non-finite weights are treated as zero. If none of the
+/// weights is larger than zero, `total` is divided evenly as in
+/// [`remainderless_division`].
+///
+/// With the `no-fp` feature, the weights are converted to integers
+/// (in steps of `2^-20`) and divided using integer math only.
+///
+/// ## Example
+/// ```rust
+/// let result = leftwm_layouts::geometry::weighted_division(100, &[2.0, 1.0, 1.0]);
+/// assert_eq!(vec![50, 25, 25], result);
+/// ```
+#[cfg(feature = "no-fp")]
+pub fn weighted_division(total: usize, weights: &[f32]) -> Vec<usize> {
+    /// The integer weight of `1.0`.
+    const UNIT: u32 = 1 << 20;
+
+    if weights.is_empty() {
+        return vec![];
+    }
+
+    let weights: Vec<u128> = weights
+        .iter()
+        .map(|w| {
+            if w.is_finite() && w.is_sign_positive() {
+                u128::from(super::scale(UNIT, *w))
+            } else {
+                0
+            }
+        })
+        .collect();
+    let sum: u128 = weights.iter().sum();
+    if sum == 0 {
+        return remainderless_division(total, weights.len());
+    }
+
+    // each part is `total * weight / sum`, the fraction is kept as the remainder
+    let exact: Vec<(u128, u128)> = weights
+        .iter()
+        .map(|w| {
+            let scaled = (total as u128).saturating_mul(*w);
+            (scaled / sum, scaled % sum)
+        })
+        .collect();
+    let mut parts: Vec<usize> = exact.iter().map(|(part, _)| *part as usize).collect();
+
+    // hand out the units lost to rounding down, largest fraction first
+    let mut order: Vec<usize> = (0..parts.len()).collect();
+    order.sort_by(|a, b| exact[*b].1.cmp(&exact[*a].1));
+    let remainder = total.saturating_sub(parts.iter().sum());
+    for i in order.into_iter().cycle().take(remainder) {
+        parts[i] += 1;
+    }
+    parts
 }
 
 /// Flip an array of [`Rect`] inside the container, according to the provided `flip` parameter
@@ -52,13 +188,13 @@ pub fn flip(rects: &mut [Rect], flip: Flip, container: &Rect) {
             // from top edge as far away as bottom side was from bottom edge before being flipped
             let bottom_window_edge = rect.y + rect.h as i32;
             let bottom_container_edge = container.y + container.h as i32;
-            rect.y = bottom_container_edge - bottom_window_edge;
+            rect.y = container.y + bottom_container_edge - bottom_window_edge;
         }
         if flip.is_flipped_vertical() {
             // from left edge as far away as right side is from right edge before being flipped
             let right_window_edge = rect.x + rect.w as i32;
             let right_container_edge = container.x + container.w as i32;
-            rect.x = right_container_edge - right_window_edge;
+            rect.x = container.x + right_container_edge - right_window_edge;
         }
     }
 }
@@ -77,93 +213,45 @@ pub fn rotate(rects: &mut [Rect], rotation: Rotation, container: &Rect) {
     for rect in rects.iter_mut() {
         rotate_single_rect(rect, rotation, container);
     }
-
-    // Fill missing pixels
-    let n_rects = rects.len();
-    for i in 0..n_rects {
-        let mut wide_enough = true;
-        let mut high_enough = true;
-
-        // check whether rect "almost bounds" another rect
-        for other in rects.iter() {
-            if other != &rects[i]
-                && !other.contains((rects[i].x + rects[i].w as i32, rects[i].y + 1))
-                && other.contains((rects[i].x + rects[i].w as i32 + 1, rects[i].y + 1))
-            {
-                wide_enough = false;
-            }
-            if other != &rects[i]
-                && !other.contains((rects[i].x + 1, rects[i].y + rects[i].h as i32))
-                && other.contains((rects[i].x + 1, rects[i].y + rects[i].w as i32 + 1))
-            {
-                high_enough = false;
-            }
-        }
-
-        // check whether rect "almost bounds" the container
-        if rects[i].x + rects[i].w as i32 + 1 == container.x + container.w as i32 {
-            wide_enough = false;
-        }
-
-        // check whether rect "almost bounds" the container
-        if rects[i].y + rects[i].h as i32 + 1 == container.y + container.h as i32 {
-            high_enough = false;
-        }
-
-        if !wide_enough && container.contains((rects[i].x + rects[i].w as i32 + 1, rects[i].y)) {
-            rects[i].w += 1;
-        }
-        if !high_enough && container.contains((rects[i].x, rects[i].y + rects[i].h as i32 + 1)) {
-            rects[i].h += 1;
-        }
-    }
 }
 
+/// Rotates a single [`Rect`] inside the container.
+///
+/// Each edge of the [`Rect`] is mapped onto the rotated container on its own,
+/// edges shared by neighbouring [`Rect`]s therefore stay shared after the rotation
+/// and the result does not depend on any other [`Rect`].
 fn rotate_single_rect(rect: &mut Rect, rotation: Rotation, container: &Rect) {
-    // normalize so that Rect is at position (0/0)
-    rect.x -= container.x;
-    rect.y -= container.y;
-
-    // rotate
-    let next_anchor = rotation.next_anchor(rect);
-    match rotation {
-        Rotation::North => {}
-        Rotation::East => {
-            rect.x = container.h as i32 - next_anchor.1;
-            rect.y = next_anchor.0;
-            std::mem::swap(&mut rect.w, &mut rect.h);
-        }
-        Rotation::South => {
-            let next_anchor = rotation.next_anchor(rect);
-            rect.x = container.w as i32 - next_anchor.0;
-            rect.y = container.h as i32 - next_anchor.1;
-        }
-        Rotation::West => {
-            let next_anchor = rotation.next_anchor(rect);
-            rect.x = next_anchor.1;
-            rect.y = container.w as i32 - next_anchor.0;
-            std::mem::swap(&mut rect.w, &mut rect.h);
-        }
-    }
-
-    // new aspect ratio
-    match rotation {
-        Rotation::North | Rotation::South => {}
-        Rotation::East | Rotation::West => {
-            rect.x *= container.w as i32;
-            rect.x /= container.h as i32;
-            rect.y *= container.h as i32;
-            rect.y /= container.w as i32;
-            rect.w *= container.w;
-            rect.w /= container.h;
-            rect.h *= container.h;
-            rect.h /= container.w;
-        }
-    }
+    let (width, height) = (i64::from(container.w), i64::from(container.h));
+    // edges relative to the container
+    let left = i64::from(rect.x - container.x);
+    let top = i64::from(rect.y - container.y);
+    let right = left + i64::from(rect.w);
+    let bottom = top + i64::from(rect.h);
+
+    // scale an edge from one side length of the container to the other
+    let scale = |edge: i64, from: i64, to: i64| if from == 0 { 0 } else { edge * to / from };
+
+    let (left, top, right, bottom) = match rotation {
+        Rotation::North => (left, top, right, bottom),
+        Rotation::East => (
+            scale(height - bottom, height, width),
+            scale(left, width, height),
+            scale(height - top, height, width),
+            scale(right, width, height),
+        ),
+        Rotation::South => (width - right, height - bottom, width - left, height - top),
+        Rotation::West => (
+            scale(top, height, width),
+            scale(width - right, width, height),
+            scale(bottom, height, width),
+            scale(width - left, width, height),
+        ),
+    };
 
-    // revert normalization
-    rect.x += container.x;
-    rect.y += container.y;
+    rect.x = container.x + left as i32;
+    rect.y = container.y + top as i32;
+    rect.w = (right - left) as u32;
+    rect.h = (bottom - top) as u32;
 }
 
 /// Splits the provided [`Rect`] into smaller rectangles
@@ -180,24 +268,190 @@ fn rotate_single_rect(rect: &mut Rect, rotation: Rotation, container: &Rect) {
 /// The rectangles will differ by 1px at maximum. The remaining space of the division is
 /// distributed evenly and by order accross the resulting rectangles, until no remaining space is left.
 pub fn split(rect: &Rect, amount: usize, axis: Option<Split>) -> Vec<Rect> {
+    split_with(rect, amount, axis, &SplitOptions::default())
+}
+
+/// Options to fine-tune how [`split_with`] splits a [`Rect`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SplitOptions {
+    /// The spiral of [`Split::Fibonacci`] and [`Split::Dwindle`] splits.
+    pub spiral: Spiral,
+
+    /// The minimum `(width, height)` of the resulting tiles (default: [`None`]).
+    ///
+    /// If splitting into the requested amount of tiles would result in smaller tiles,
+    /// the [`Rect`] is split into fewer tiles instead. The windows that don't get a tile
+    /// of their own are meant to share the last tile, see [`split_with_merged`].
+    pub min_size: Option<(u32, u32)>,
+
+    /// Which tiles get the remaining pixels if the [`Rect`] can not be divided evenly.
+    /// Only supported by the splits dividing into tiles of the same size, ie. [`Split::Vertical`],
+    /// [`Split::Horizontal`], [`Split::Auto`], [`Split::Grid`], [`Split::RowGrid`],
+    /// [`Split::Columns`], [`Split::Rows`], [`Split::AspectRatio`], and [`Split::FixedGrid`].
+    pub remainder: Remainder,
+
+    /// The space in pixels between neighbouring tiles (default: `0`).
+    ///
+    /// The tiles and the spaces between them still add up to the whole [`Rect`],
+    /// there is no space along its outer edges. The space is left the same way as
+    /// the [`Gaps::inner`] of a layout, so tiles of the same size stay the same size.
+    /// Splits whose tiles overlap on purpose (see [`Split::may_overlap`]) are not spaced apart.
+    pub spacing: u32,
+}
+
+/// Same as [`split`], but with further [`SplitOptions`].
+///
+/// ## Example
+/// ```rust
+/// use leftwm_layouts::geometry::{split_with, Rect, Spiral, Split, SplitOptions};
+///
+/// let options = SplitOptions {
+///     spiral: Spiral { horizontal_first: false, ..Default::default() },
+///     ..Default::default()
+/// };
+/// let rects = split_with(&Rect::new(0, 0, 400, 200), 2, Some(Split::Fibonacci), &options);
+/// assert_eq!(vec![Rect::new(0, 0, 200, 200), Rect::new(200, 0, 200, 200)], rects);
+/// ```
+pub fn split_with(
+    rect: &Rect,
+    amount: usize,
+    axis: Option<Split>,
+    options: &SplitOptions,
+) -> Vec<Rect> {
+    split_with_merged(rect, amount, axis, options).0
+}
+
+/// Same as [`split_with`], but also returns the amount of tiles that were
+/// merged into the last tile because of the [`SplitOptions::min_size`].
+///
+/// The [`Rect`] is split into as many tiles as possible (but at least one) without
+/// going below the minimum size, the last tile is meant to be shared by the
+/// remaining windows. Without a minimum size, nothing is ever merged.
+///
+/// The amount of tiles is found by a binary search, assuming that splitting
+/// into more tiles never results in larger tiles.
+///
+/// ## Example
+/// ```rust
+/// use leftwm_layouts::geometry::{split_with_merged, Rect, Split, SplitOptions};
+///
+/// let options = SplitOptions {
+///     min_size: Some((0, 50)),
+///     ..Default::default()
+/// };
+/// let (rects, merged) =
+///     split_with_merged(&Rect::new(0, 0, 400, 200), 20, Some(Split::Horizontal), &options);
+/// assert_eq!(4, rects.len());
+/// assert_eq!(16, merged);
+/// ```
+pub fn split_with_merged(
+    rect: &Rect,
+    amount: usize,
+    axis: Option<Split>,
+    options: &SplitOptions,
+) -> (Vec<Rect>, usize) {
+    let Some((min_width, min_height)) = options.min_size else {
+        return (split_unchecked(rect, amount, axis, options), 0);
+    };
+    let fits = |tiles: &[Rect]| {
+        tiles
+            .iter()
+            .all(|tile| tile.w >= min_width && tile.h >= min_height)
+    };
+    // binary search for the most tiles that still fit, falling back to a single tile
+    let (mut fitting, mut too_many) = (amount.min(1), amount + 1);
+    while too_many - fitting > 1 {
+        let count = fitting + (too_many - fitting) / 2;
+        if fits(&split_unchecked(rect, count, axis, options)) {
+            fitting = count;
+        } else {
+            too_many = count;
+        }
+    }
+    let tiles = split_unchecked(rect, fitting, axis, options);
+    (tiles, amount - fitting)
+}
+
+fn split_unchecked(
+    rect: &Rect,
+    amount: usize,
+    axis: Option<Split>,
+    options: &SplitOptions,
+) -> Vec<Rect> {
+    if axis.is_some_and(|split| split.may_overlap()) {
+        return split_tiles(rect, amount, axis, options);
+    }
+    let gaps = Gaps {
+        inner: options.spacing,
+        ..Default::default()
+    };
+    let mut tiles = split_tiles(&gaps.grow(rect), amount, axis, options);
+    gaps.shrink(&mut tiles);
+    tiles
+}
+
+fn split_tiles(
+    rect: &Rect,
+    amount: usize,
+    axis: Option<Split>,
+    options: &SplitOptions,
+) -> Vec<Rect> {
     match (amount, axis) {
         (0, _) => vec![],
         (_, None) => vec![*rect],
         (_, Some(a)) => match a {
-            Split::Vertical => vertical(rect, amount),
-            Split::Horizontal => horizontal(rect, amount),
-            Split::Grid => grid(rect, amount),
-            Split::Fibonacci => fibonacci(rect, amount),
-            Split::Dwindle => dwindle(rect, amount),
+            Split::Vertical => even(rect, amount, Axis::X, options.remainder),
+            Split::Horizontal => even(rect, amount, Axis::Y, options.remainder),
+            Split::Grid => grid(rect, amount, options.remainder),
+            Split::RowGrid => row_grid(rect, amount, options.remainder),
+            Split::Fibonacci => fibonacci(rect, amount, options.spiral),
+            Split::Dwindle => dwindle(rect, amount, options.spiral),
+            Split::BinaryTree(ratio) => binary_tree(rect, amount, ratio),
+            Split::Columns(count) => columns(rect, amount, count, options.remainder),
+            Split::Rows(count) => rows(rect, amount, count, options.remainder),
+            Split::Tatami => tatami(rect, amount),
+            Split::HorizontalAccordion => horizontal_accordion(rect, amount),
+            Split::VerticalAccordion => vertical_accordion(rect, amount),
+            Split::Auto => auto(rect, amount, options.remainder),
+            Split::AspectRatio(ratio) => aspect_ratio(rect, amount, ratio, options.remainder),
+            Split::FixedGrid(grid) => fixed_grid(rect, amount, grid, options.remainder),
         },
     }
 }
 
+/// Splits the provided [`Rect`] into smaller rectangles proportional
+/// to the provided `weights`, one rectangle per weight.
+///
+/// Weights are only supported by [`Split::Vertical`] and [`Split::Horizontal`]
+/// (and [`Split::Auto`], which is either of them), see [`weighted_division`] for
+/// how the weights are handled. Every other [`Split`] (or [`None`]) ignores the
+/// weights and falls back to [`split`], with the amount of weights as the amount.
+///
+/// ## Example
+/// ```rust
+/// use leftwm_layouts::geometry::{split_weighted, Rect, Split};
+///
+/// let rects = split_weighted(&Rect::new(0, 0, 400, 200), &[3.0, 1.0], Some(Split::Vertical));
+/// assert_eq!(vec![Rect::new(0, 0, 300, 200), Rect::new(300, 0, 100, 200)], rects);
+/// ```
+pub fn split_weighted(rect: &Rect, weights: &[f32], axis: Option<Split>) -> Vec<Rect> {
+    match axis {
+        _ if weights.is_empty() => vec![],
+        Some(Split::Vertical) => weighted(rect, weights, Axis::X),
+        Some(Split::Horizontal) => weighted(rect, weights, Axis::Y),
+        Some(Split::Auto) => weighted(rect, weights, auto_axis(rect)),
+        _ => split(rect, weights.len(), axis),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
-        geometry::calc::{divrem, flip, remainderless_division, split},
-        geometry::{Flip, Rect, Rotation, Split},
+        geometry::calc::{
+            divrem, flip, remainderless_division, remainderless_division_with, split,
+            split_weighted, split_with, split_with_merged, weighted_division, SplitOptions,
+        },
+        geometry::{Flip, Rect, Remainder, Rotation, Split},
     };
 
     use super::rotate;
@@ -232,6 +486,38 @@ mod tests {
         assert_eq!(vec![4, 4, 4, 4, 4, 3, 3, 3], result);
     }
 
+    #[test]
+    fn weighted_division_sums_up_to_total() {
+        let result = weighted_division(1001, &[0.7, 0.2, 0.1]);
+        assert_eq!(vec![701, 200, 100], result);
+        assert_eq!(1001usize, result.iter().sum::<usize>());
+    }
+
+    #[test]
+    fn weighted_division_with_equal_weights_matches_remainderless_division() {
+        for total in 0..50 {
+            for amount in 1..8 {
+                let weights = vec![1.0; amount];
+                assert_eq!(
+                    remainderless_division(total, amount),
+                    weighted_division(total, &weights)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn weighted_division_ignores_invalid_weights() {
+        let result = weighted_division(90, &[-1.0, f32::NAN, 1.0, 2.0]);
+        assert_eq!(vec![0, 0, 30, 60], result);
+    }
+
+    #[test]
+    fn weighted_division_without_weights() {
+        assert_eq!(Vec::<usize>::new(), weighted_division(100, &[]));
+        assert_eq!(vec![34, 33, 33], weighted_division(100, &[0.0, 0.0, 0.0]));
+    }
+
     const CONTAINER: Rect = Rect {
         x: 0,
         y: 0,
@@ -388,6 +674,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn flip_in_offset_container() {
+        let container = Rect::new(1000, 500, 400, 200);
+        let mut rects = vec![
+            Rect::new(1000, 500, 300, 200),
+            Rect::new(1300, 500, 100, 50),
+        ];
+
+        flip(&mut rects, Flip::Both, &container);
+
+        assert_eq!(
+            rects,
+            vec![
+                Rect::new(1100, 500, 300, 200),
+                Rect::new(1000, 650, 100, 50),
+            ]
+        );
+    }
+
     #[test]
     fn rotate_0_degrees() {
         let container = Rect::new(0, 0, 400, 200);
@@ -692,5 +997,190 @@ mod tests {
         );
     }
 
+    #[test]
+    fn rotate_each_tile_on_its_own() {
+        let container = Rect::new(10, 20, 1921, 1079);
+        let tiles = split(&container, 7, Some(Split::Grid));
+        for rotation in [Rotation::East, Rotation::South, Rotation::West] {
+            let mut rotated = tiles.clone();
+            rotate(&mut rotated, rotation, &container);
+            for (tile, rotated) in tiles.iter().zip(&rotated) {
+                let mut alone = [*tile];
+                rotate(&mut alone, rotation, &container);
+                assert_eq!(alone[0], *rotated);
+            }
+            // the tiles still cover the whole container without overlapping
+            let area: u32 = rotated.iter().map(|tile| tile.w * tile.h).sum();
+            assert_eq!(container.w * container.h, area);
+        }
+    }
+
     // todo: test with negative offset
+
+    #[test]
+    fn split_weighted_horizontal() {
+        let rects = split_weighted(&CONTAINER, &[1.0, 2.0, 1.0], Some(Split::Horizontal));
+        assert_eq!(
+            vec![
+                Rect::new(0, 0, 400, 50),
+                Rect::new(0, 50, 400, 100),
+                Rect::new(0, 150, 400, 50),
+            ],
+            rects
+        );
+    }
+
+    #[test]
+    fn split_weighted_falls_back_to_even_split() {
+        let weights = [1.0, 2.0, 3.0, 4.0];
+        for axis in [None, Some(Split::Grid), Some(Split::Fibonacci)] {
+            assert_eq!(
+                split(&CONTAINER, 4, axis),
+                split_weighted(&CONTAINER, &weights, axis)
+            );
+        }
+        assert!(split_weighted(&CONTAINER, &[], Some(Split::Vertical)).is_empty());
+    }
+
+    #[test]
+    fn split_with_min_size_merges_overflowing_tiles() {
+        let options = SplitOptions {
+            min_size: Some((100, 80)),
+            ..Default::default()
+        };
+        let (rects, merged) = split_with_merged(&CONTAINER, 20, Some(Split::Grid), &options);
+        // a 3x2 grid, a seventh tile would need a third row
+        assert_eq!(6, rects.len());
+        assert_eq!(14, merged);
+        assert!(rects.iter().all(|rect| rect.w >= 100 && rect.h >= 80));
+    }
+
+    #[test]
+    fn split_with_min_size_keeps_a_single_tile() {
+        let options = SplitOptions {
+            min_size: Some((1000, 1000)),
+            ..Default::default()
+        };
+        let (rects, merged) = split_with_merged(&CONTAINER, 3, Some(Split::Vertical), &options);
+        assert_eq!(vec![CONTAINER], rects);
+        assert_eq!(2, merged);
+        let (rects, merged) = split_with_merged(&CONTAINER, 0, Some(Split::Vertical), &options);
+        assert!(rects.is_empty());
+        assert_eq!(0, merged);
+    }
+
+    #[test]
+    fn remainderless_division_with_strategies() {
+        assert_eq!(
+            vec![3, 3, 4, 4],
+            remainderless_division_with(14, 4, Remainder::Last)
+        );
+        assert_eq!(
+            vec![3, 4, 4, 3],
+            remainderless_division_with(14, 4, Remainder::Center)
+        );
+        assert_eq!(
+            vec![4, 3, 4, 3],
+            remainderless_division_with(14, 4, Remainder::Spread)
+        );
+        for remainder in [Remainder::Last, Remainder::Center, Remainder::Spread] {
+            assert_eq!(vec![3, 3, 3], remainderless_division_with(9, 3, remainder));
+            assert_eq!(
+                29,
+                remainderless_division_with(29, 8, remainder)
+                    .iter()
+                    .sum::<usize>()
+            );
+        }
+    }
+
+    #[test]
+    fn split_with_remainder_keeps_symmetry() {
+        let options = SplitOptions {
+            remainder: Remainder::Center,
+            ..Default::default()
+        };
+        let rects = split_with(
+            &Rect::new(0, 0, 400, 200),
+            3,
+            Some(Split::Vertical),
+            &options,
+        );
+        assert_eq!(
+            vec![
+                Rect::new(0, 0, 133, 200),
+                Rect::new(133, 0, 134, 200),
+                Rect::new(267, 0, 133, 200),
+            ],
+            rects
+        );
+    }
+
+    #[test]
+    fn split_with_spacing_leaves_space_between_tiles_only() {
+        let options = SplitOptions {
+            spacing: 5,
+            ..Default::default()
+        };
+        let rects = split_with(
+            &Rect::new(0, 0, 400, 200),
+            2,
+            Some(Split::Vertical),
+            &options,
+        );
+        assert_eq!(
+            vec![Rect::new(0, 0, 198, 200), Rect::new(203, 0, 197, 200)],
+            rects
+        );
+
+        let rects = split_with(
+            &Rect::new(0, 0, 400, 200),
+            3,
+            Some(Split::Dwindle),
+            &options,
+        );
+        assert_eq!(
+            vec![
+                Rect::new(0, 0, 400, 98),
+                Rect::new(0, 103, 198, 97),
+                Rect::new(203, 103, 197, 97),
+            ],
+            rects
+        );
+    }
+
+    #[test]
+    fn split_with_spacing_keeps_equal_tiles_equal() {
+        let options = SplitOptions {
+            spacing: 10,
+            ..Default::default()
+        };
+        let rects = split_with(
+            &Rect::new(0, 0, 400, 200),
+            3,
+            Some(Split::Vertical),
+            &options,
+        );
+        assert_eq!(
+            vec![
+                Rect::new(0, 0, 127, 200),
+                Rect::new(137, 0, 127, 200),
+                Rect::new(274, 0, 126, 200),
+            ],
+            rects
+        );
+    }
+
+    #[test]
+    fn split_with_spacing_keeps_overlapping_splits() {
+        let options = SplitOptions {
+            spacing: 10,
+            ..Default::default()
+        };
+        let accordion = Some(Split::HorizontalAccordion);
+        assert_eq!(
+            split(&CONTAINER, 4, accordion),
+            split_with(&CONTAINER, 4, accordion, &options)
+        );
+    }
 }
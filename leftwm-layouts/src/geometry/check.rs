@@ -0,0 +1,107 @@
+use super::Rect;
+
+/// Describes a way in which a list of tiles violates
+/// the invariants expected from a tiling layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TilingIssue {
+    /// The tile at the index reaches outside of the container
+    OutOfBounds(usize),
+
+    /// The tile at the index has no surface area
+    Empty(usize),
+
+    /// The tiles at the two indices overlap each other
+    Overlap(usize, usize),
+}
+
+/// Check the provided tiles against the invariants of a tiling layout
+/// and return every violation that was found.
+///
+/// Every tile must lie within the `container` and have a surface area.
+/// Unless `allow_overlap` is set, no two tiles may overlap each other.
+/// Layouts which overlap on purpose can be detected with
+/// [`crate::Layout::may_overlap`] or [`crate::geometry::Split::may_overlap`].
+///
+/// ## Example
+/// ```rust
+/// use leftwm_layouts::geometry::{check_tiling, Rect, TilingIssue};
+///
+/// let container = Rect::new(0, 0, 400, 200);
+/// let tiles = vec![Rect::new(0, 0, 250, 200), Rect::new(200, 0, 200, 200)];
+/// assert_eq!(vec![TilingIssue::Overlap(0, 1)], check_tiling(&tiles, &container, false));
+/// assert!(check_tiling(&tiles, &container, true).is_empty());
+/// ```
+pub fn check_tiling(tiles: &[Rect], container: &Rect, allow_overlap: bool) -> Vec<TilingIssue> {
+    let mut issues = vec![];
+    for (i, tile) in tiles.iter().enumerate() {
+        if tile.surface_area() == 0 {
+            issues.push(TilingIssue::Empty(i));
+        }
+        if tile.left_edge() < container.left_edge()
+            || tile.top_edge() < container.top_edge()
+            || tile.right_edge() > container.right_edge()
+            || tile.bottom_edge() > container.bottom_edge()
+        {
+            issues.push(TilingIssue::OutOfBounds(i));
+        }
+    }
+
+    if !allow_overlap {
+        for (i, a) in tiles.iter().enumerate() {
+            for (j, b) in tiles.iter().enumerate().skip(i + 1) {
+                if a.overlaps(b) {
+                    issues.push(TilingIssue::Overlap(i, j));
+                }
+            }
+        }
+    }
+
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check_tiling, TilingIssue};
+    use crate::geometry::Rect;
+
+    const CONTAINER: Rect = Rect {
+        x: 0,
+        y: 0,
+        w: 400,
+        h: 200,
+    };
+
+    #[test]
+    fn proper_tiling_has_no_issues() {
+        let tiles = vec![Rect::new(0, 0, 200, 200), Rect::new(200, 0, 200, 200)];
+        assert!(check_tiling(&tiles, &CONTAINER, false).is_empty());
+    }
+
+    #[test]
+    fn detects_tiles_out_of_bounds() {
+        let tiles = vec![Rect::new(-1, 0, 200, 200), Rect::new(200, 0, 201, 200)];
+        assert_eq!(
+            vec![TilingIssue::OutOfBounds(0), TilingIssue::OutOfBounds(1)],
+            check_tiling(&tiles, &CONTAINER, false)
+        );
+    }
+
+    #[test]
+    fn detects_empty_tiles() {
+        let tiles = vec![Rect::new(0, 0, 0, 200)];
+        assert_eq!(
+            vec![TilingIssue::Empty(0)],
+            check_tiling(&tiles, &CONTAINER, false)
+        );
+    }
+
+    #[test]
+    fn overlap_is_only_reported_if_not_allowed() {
+        let tiles = vec![Rect::new(0, 0, 400, 200), Rect::new(0, 100, 400, 100)];
+        assert_eq!(
+            vec![TilingIssue::Overlap(0, 1)],
+            check_tiling(&tiles, &CONTAINER, false)
+        );
+        assert!(check_tiling(&tiles, &CONTAINER, true).is_empty());
+    }
+}
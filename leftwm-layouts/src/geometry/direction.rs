@@ -75,170 +75,95 @@ impl FromStr for Direction {
     }
 }
 
-// Find the north neighbor starting from a given `Rect` with index `current` in an array of
-// [`Rect`].
-fn find_north(rects: &[Rect], current: usize) -> Option<usize> {
-    let current_rect = rects.get(current).or(None)?;
-
-    // We are all the way up, no neighbor available
-    if current_rect.top_edge() <= 0 {
-        return None;
+// Whether the `current` rect is at the edge of the container in the provided direction,
+// in which case no neighbor is available in that direction.
+fn is_at_edge(current: &Rect, direction: Direction, container: &Rect) -> bool {
+    match direction {
+        // We are all the way up
+        Direction::North => current.top_edge() <= container.top_edge(),
+        // We are all the way right
+        Direction::East => current.right_edge() >= container.right_edge(),
+        // We are at the bottom
+        Direction::South => current.bottom_edge() >= container.bottom_edge(),
+        // We are all the way left
+        Direction::West => current.left_edge() <= container.left_edge(),
     }
-
-    let mut nearest_rect: Option<usize> = None;
-    let mut min_x: Option<i32> = None;
-    let mut min_y: Option<i32> = None;
-
-    for (i, r) in rects.iter().enumerate() {
-        if r == current_rect || // skip current rect
-        r.right_edge() - 1 < current_rect.left_edge() || // skip too right
-        r.left_edge() + 1 > current_rect.right_edge() || // skip too left
-        r.top_edge() + 1 > current_rect.bottom_edge()
-        // skip too low
-        {
-            continue;
-        }
-
-        let x_distance = current_rect.left_edge() - r.right_edge();
-        let y_distance = current_rect.top_edge() - r.bottom_edge();
-
-        find_nearest_rect(
-            &mut min_x,
-            &mut min_y,
-            &mut nearest_rect,
-            x_distance,
-            y_distance,
-            i,
-            true,
-        );
-    }
-
-    nearest_rect
 }
 
-// Find the east neighbor starting from a given `Rect` with index `current` in an array of
-// [`Rect`].
-fn find_east(rects: &[Rect], current: usize, display_width: u32) -> Option<usize> {
-    let current_rect = rects.get(current).or(None)?;
-
-    // We are all the way right, no neighbor available
-    if current_rect.right_edge() >= display_width as i32 {
+// The x and y distance between the `current` rect and the rect `r`, if `r` is
+// a candidate for the neighbor of the `current` rect in the provided direction.
+fn distances(current: &Rect, r: &Rect, direction: Direction) -> Option<(i32, i32)> {
+    // skip current rect
+    if r == current {
         return None;
     }
 
-    let mut nearest_rect: Option<usize> = None;
-    let mut min_x: Option<i32> = None;
-    let mut min_y: Option<i32> = None;
-
-    for (i, r) in rects.iter().enumerate() {
-        if r == current_rect || // skip current rect
-        r.right_edge() - 1 < current_rect.right_edge() || // skip too left
-        r.bottom_edge() - 1 < current_rect.top_edge() || // skip too high
-        r.top_edge() + 1 > current_rect.bottom_edge()
-        // skip too low
-        {
-            continue;
+    let skip = match direction {
+        Direction::North => {
+            r.right_edge() - 1 < current.left_edge() || // skip too right
+            r.left_edge() + 1 > current.right_edge() || // skip too left
+            r.top_edge() + 1 > current.bottom_edge() // skip too low
         }
-
-        let x_distance = r.left_edge() - current_rect.right_edge();
-        let y_distance = r.top_edge() - current_rect.bottom_edge();
-
-        find_nearest_rect(
-            &mut min_x,
-            &mut min_y,
-            &mut nearest_rect,
-            x_distance,
-            y_distance,
-            i,
-            false,
-        );
-    }
-
-    nearest_rect
-}
-
-// Find the south neighbor starting from a given `Rect` with index `current` in an array of
-// [`Rect`].
-fn find_south(rects: &[Rect], current: usize, display_height: u32) -> Option<usize> {
-    let current_rect = rects.get(current).or(None)?;
-
-    // We are at the bottom, no neighbor available
-    if current_rect.y + current_rect.h as i32 >= display_height as i32 {
-        return None;
-    }
-
-    let mut nearest_rect: Option<usize> = None;
-    let mut min_x: Option<i32> = None;
-    let mut min_y: Option<i32> = None;
-
-    for (i, r) in rects.iter().enumerate() {
-        if r == current_rect || // skip current rect
-        r.right_edge() - 1 < current_rect.left_edge() || // skip too left
-        r.left_edge() + 1 > current_rect.right_edge() || // skip too right
-        r.bottom_edge() - 1 < current_rect.top_edge()
-        // skip too high
-        {
-            // skip current rect
-            continue;
+        Direction::East => {
+            r.right_edge() - 1 < current.right_edge() || // skip too left
+            r.bottom_edge() - 1 < current.top_edge() || // skip too high
+            r.top_edge() + 1 > current.bottom_edge() // skip too low
         }
-
-        let x_distance = current_rect.left_edge() - r.right_edge();
-        let y_distance = r.top_edge() - current_rect.bottom_edge();
-
-        find_nearest_rect(
-            &mut min_x,
-            &mut min_y,
-            &mut nearest_rect,
-            x_distance,
-            y_distance,
-            i,
-            true,
-        );
+        Direction::South => {
+            r.right_edge() - 1 < current.left_edge() || // skip too left
+            r.left_edge() + 1 > current.right_edge() || // skip too right
+            r.bottom_edge() - 1 < current.top_edge() // skip too high
+        }
+        Direction::West => {
+            r.left_edge() + 1 > current.right_edge() || // skip too right
+            r.bottom_edge() - 1 < current.top_edge() || // skip too high
+            r.top_edge() + 1 > current.bottom_edge() // skip too low
+        }
+    };
+    if skip {
+        return None;
     }
 
-    nearest_rect
+    Some(match direction {
+        Direction::North => (
+            current.left_edge() - r.right_edge(),
+            current.top_edge() - r.bottom_edge(),
+        ),
+        Direction::East => (
+            r.left_edge() - current.right_edge(),
+            r.top_edge() - current.bottom_edge(),
+        ),
+        Direction::South | Direction::West => (
+            current.left_edge() - r.right_edge(),
+            r.top_edge() - current.bottom_edge(),
+        ),
+    })
 }
 
-// Find the west neighbor starting from a given `Rect` with index `current` in an array of
-// [`Rect`].
-fn find_west(rects: &[Rect], current: usize) -> Option<usize> {
-    let current_rect = rects.get(current).or(None)?;
-
-    // We are all the way left; no neighbor available
-    if current_rect.left_edge() <= 0 {
-        return None;
-    }
+// The nearest candidate found so far while searching for a neighbor.
+#[derive(Debug, Default, Clone, Copy)]
+struct Nearest {
+    min_x: Option<i32>,
+    min_y: Option<i32>,
+    rect: Option<usize>,
+}
 
-    let mut nearest_rect: Option<usize> = None;
-    let mut min_x: Option<i32> = None;
-    let mut min_y: Option<i32> = None;
-
-    for (i, r) in rects.iter().enumerate() {
-        if r == current_rect || // skip current rect
-         r.left_edge() + 1 > current_rect.right_edge() || // skip too right
-         r.bottom_edge() - 1 < current_rect.top_edge() || // skip too high
-         r.top_edge() + 1 > current_rect.bottom_edge()
-        // skip too low
-        {
-            // skip current rect
-            continue;
+impl Nearest {
+    // Consider the rect `r` with the provided `index` as the neighbor
+    // of the `current` rect in the provided direction.
+    fn consider(&mut self, current: &Rect, r: &Rect, index: usize, direction: Direction) {
+        if let Some((x_distance, y_distance)) = distances(current, r, direction) {
+            find_nearest_rect(
+                &mut self.min_x,
+                &mut self.min_y,
+                &mut self.rect,
+                x_distance,
+                y_distance,
+                index,
+                matches!(direction, Direction::North | Direction::South),
+            );
         }
-
-        let x_distance = current_rect.left_edge() - r.right_edge();
-        let y_distance = r.top_edge() - current_rect.bottom_edge();
-
-        find_nearest_rect(
-            &mut min_x,
-            &mut min_y,
-            &mut nearest_rect,
-            x_distance,
-            y_distance,
-            i,
-            false,
-        );
     }
-
-    nearest_rect
 }
 
 // Find the nearest `Rect`. If updown is true, evaluate y_distance and then x_distance. If updown
@@ -286,28 +211,113 @@ fn find_nearest_rect(
 impl Direction {
     /// Find the neighbor in a given direction (`North`, `East`, `South`, `West`), starting from a
     /// given `Rect` identified by the index `current` in an array of [`Rect`]
+    ///
+    /// *Note: To find the neighbors of all rects, [`neighbors`] is more efficient
+    /// than calling this for every rect and direction.*
     pub fn find_neighbor(
         rects: &[Rect],
         current: usize,
         direction: Direction,
         container: &Rect,
     ) -> Option<usize> {
-        if current >= rects.len() {
+        let current_rect = rects.get(current)?;
+        if is_at_edge(current_rect, direction, container) {
             return None;
         }
 
+        let mut nearest = Nearest::default();
+        for (i, r) in rects.iter().enumerate() {
+            nearest.consider(current_rect, r, i, direction);
+        }
+        nearest.rect
+    }
+}
+
+/// The indices of the neighbors of a [`Rect`] in each [`Direction`],
+/// as found by [`Direction::find_neighbor`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Neighbors {
+    /// The neighbor above the rect
+    pub north: Option<usize>,
+
+    /// The neighbor to the right of the rect
+    pub east: Option<usize>,
+
+    /// The neighbor below the rect
+    pub south: Option<usize>,
+
+    /// The neighbor to the left of the rect
+    pub west: Option<usize>,
+}
+
+impl Neighbors {
+    /// Get the neighbor in the provided direction
+    pub fn get(&self, direction: Direction) -> Option<usize> {
         match direction {
-            Direction::North => find_north(rects, current),
-            Direction::East => find_east(rects, current, container.w),
-            Direction::South => find_south(rects, current, container.h),
-            Direction::West => find_west(rects, current),
+            Direction::North => self.north,
+            Direction::East => self.east,
+            Direction::South => self.south,
+            Direction::West => self.west,
         }
     }
 }
 
+/// Find the neighbors of all the provided `rects` in every [`Direction`] at once.
+///
+/// The result is the same as calling [`Direction::find_neighbor`] for each rect
+/// and direction, but the rects are only iterated once per rect instead of once
+/// per rect and direction.
+///
+/// ## Example
+/// ```rust
+/// use leftwm_layouts::geometry::{neighbors, Rect};
+///
+/// let container = Rect::new(0, 0, 400, 200);
+/// let rects = vec![
+///     Rect::new(0, 0, 200, 200),
+///     Rect::new(200, 0, 200, 100),
+///     Rect::new(200, 100, 200, 100),
+/// ];
+/// let neighbors = neighbors(&rects, &container);
+/// assert_eq!(Some(1), neighbors[0].east);
+/// assert_eq!(Some(2), neighbors[1].south);
+/// assert_eq!(Some(0), neighbors[2].west);
+/// assert_eq!(None, neighbors[2].east);
+/// ```
+pub fn neighbors(rects: &[Rect], container: &Rect) -> Vec<Neighbors> {
+    const DIRECTIONS: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    rects
+        .iter()
+        .map(|current| {
+            let searched = DIRECTIONS.map(|direction| !is_at_edge(current, direction, container));
+            let mut nearest = [Nearest::default(); 4];
+            for (i, r) in rects.iter().enumerate() {
+                for (d, direction) in DIRECTIONS.into_iter().enumerate() {
+                    if searched[d] {
+                        nearest[d].consider(current, r, i, direction);
+                    }
+                }
+            }
+            let [north, east, south, west] = nearest.map(|nearest| nearest.rect);
+            Neighbors {
+                north,
+                east,
+                south,
+                west,
+            }
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::geometry::{Direction, Rect};
+    use crate::geometry::{neighbors, Direction, Rect};
 
     const CONTAINER: Rect = Rect {
         x: 0,
@@ -444,4 +454,51 @@ mod tests {
         let res = Direction::find_neighbor(&ARRAY, 6, Direction::West, &CONTAINER);
         assert_eq!(res, Some(1));
     }
+
+    #[test]
+    fn neighbors_match_find_neighbor() {
+        let directions = [
+            Direction::North,
+            Direction::East,
+            Direction::South,
+            Direction::West,
+        ];
+        let all_neighbors = neighbors(&ARRAY, &CONTAINER);
+        assert_eq!(ARRAY.len(), all_neighbors.len());
+        for (i, neighbors) in all_neighbors.iter().enumerate() {
+            for direction in directions {
+                assert_eq!(
+                    Direction::find_neighbor(&ARRAY, i, direction, &CONTAINER),
+                    neighbors.get(direction),
+                    "{direction:?} of {i}"
+                );
+            }
+        }
+        assert!(neighbors(&[], &CONTAINER).is_empty());
+    }
+
+    #[test]
+    fn neighbors_with_offset() {
+        let directions = [
+            Direction::North,
+            Direction::East,
+            Direction::South,
+            Direction::West,
+        ];
+        for (x, y) in [(2560, 0), (0, 1440), (-1920, -1080)] {
+            let offset = |rect: &Rect| Rect::new(rect.x + x, rect.y + y, rect.w, rect.h);
+            let container = offset(&CONTAINER);
+            let array = ARRAY.map(|rect| offset(&rect));
+            for i in 0..ARRAY.len() {
+                for direction in directions {
+                    assert_eq!(
+                        Direction::find_neighbor(&ARRAY, i, direction, &CONTAINER),
+                        Direction::find_neighbor(&array, i, direction, &container),
+                        "{direction:?} of {i} with offset ({x}, {y})"
+                    );
+                }
+            }
+            assert_eq!(neighbors(&ARRAY, &CONTAINER), neighbors(&array, &container));
+        }
+    }
 }
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
 
 /// Represents the four states an object can be in,
 /// if it can be flipped horizontally and vertically.
@@ -91,3 +92,48 @@ impl Flip {
         }
     }
 }
+
+impl fmt::Display for Flip {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Flip::None => "None",
+            Flip::Horizontal => "Horizontal",
+            Flip::Vertical => "Vertical",
+            Flip::Both => "Both",
+        })
+    }
+}
+
+impl FromStr for Flip {
+    type Err = ();
+
+    /// Parses the name of a variant, ignoring the case (eg. `horizontal`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "none" => Ok(Flip::None),
+            "horizontal" => Ok(Flip::Horizontal),
+            "vertical" => Ok(Flip::Vertical),
+            "both" => Ok(Flip::Both),
+            _ => Err(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Flip;
+
+    #[test]
+    fn flip_display_round_trips() {
+        for flip in [Flip::None, Flip::Horizontal, Flip::Vertical, Flip::Both] {
+            assert_eq!(Ok(flip), flip.to_string().parse());
+        }
+    }
+
+    #[test]
+    fn flip_from_str_ignores_case() {
+        assert_eq!(Ok(Flip::Both), "both".parse());
+        assert_eq!(Ok(Flip::Horizontal), "HORIZONTAL".parse());
+        assert_eq!(Err(()), "diagonal".parse::<Flip>());
+    }
+}
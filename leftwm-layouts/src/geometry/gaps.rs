@@ -0,0 +1,133 @@
+use serde::{Deserialize, Serialize};
+
+use super::Rect;
+
+/// The spacing between the tiles of a layout, see [`crate::Layout::gaps`].
+///
+/// The gaps are only inserted between adjacent tiles, the tiles along the edges
+/// of the container stay flush with it. To divide the space exactly, the tiles
+/// are calculated for a container enlarged by the gap, before the gap is taken
+/// off the right and the bottom of every tile. Tiles of the same size therefore
+/// stay the same size, no matter whether they are at the edge or in the middle.
+/// This is the same mechanism [`super::SplitOptions::spacing`] uses to space apart
+/// the tiles of a single split.
+///
+/// ```txt
+/// +------+ +------+
+/// |      | |      |
+/// |      | +------+
+/// |      | +------+
+/// |      | |      |
+/// +------+ +------+
+/// ```
+///
+/// ```rust
+/// use leftwm_layouts::{apply, geometry::{Gaps, Rect}, layouts::Layouts};
+///
+/// let mut layout = Layouts::default().get("EvenHorizontal").unwrap().clone();
+/// layout.gaps = Some(Gaps { inner: 10, ..Default::default() });
+/// let tiles = apply(&layout, 3, &Rect::new(0, 0, 400, 200));
+/// assert_eq!(
+///     vec![
+///         Rect::new(0, 0, 127, 200),
+///         Rect::new(137, 0, 127, 200),
+///         Rect::new(274, 0, 126, 200),
+///     ],
+///     tiles
+/// );
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Gaps {
+    /// The pixels between two adjacent tiles (default: `0`).
+    pub inner: u32,
+
+    /// The pixels between two adjacent columns, eg. between the `main` column
+    /// and the stack, on top of the [`Gaps::inner`] gap (default: `0`).
+    /// This separates the columns without spacing out the tiles within them.
+    ///
+    /// ```rust
+    /// use leftwm_layouts::{apply, geometry::{Gaps, Rect}, layouts::Layouts};
+    ///
+    /// let mut layout = Layouts::default().get("MainAndVertStack").unwrap().clone();
+    /// layout.gaps = Some(Gaps { gutter: 20, ..Default::default() });
+    /// let tiles = apply(&layout, 3, &Rect::new(0, 0, 420, 200));
+    /// assert_eq!(
+    ///     vec![
+    ///         Rect::new(0, 0, 200, 200),
+    ///         Rect::new(220, 0, 200, 100),
+    ///         Rect::new(220, 100, 200, 100),
+    ///     ],
+    ///     tiles
+    /// );
+    /// ```
+    pub gutter: u32,
+}
+
+impl Gaps {
+    /// The container to calculate the tiles in, which is enlarged by the gap
+    /// to the right and the bottom, see [`Gaps::shrink`].
+    pub(crate) fn grow(self, container: &Rect) -> Rect {
+        grow_by(container, self.inner)
+    }
+
+    /// Take the gap off the right and the bottom of every provided [`Rect`], which
+    /// are calculated for the container enlarged by [`Gaps::grow`]. A [`Rect`]
+    /// which is not larger than the gap is left without any width or height.
+    pub(crate) fn shrink(self, rects: &mut [Rect]) {
+        for rect in rects {
+            shrink_by(rect, self.inner);
+        }
+    }
+
+    /// Make the provided [`Rect`]s, which are calculated for the `container` enlarged
+    /// by [`Gaps::grow`], take in the gaps next to them instead of leaving them.
+    /// Each [`Rect`] reaches back over the gap of the tile before it, and only
+    /// the part beyond the edges of the original container is taken off.
+    pub(crate) fn collapse(self, rects: &mut [Rect], container: &Rect) {
+        for rect in rects {
+            if rect.right_edge() >= container.right_edge() {
+                rect.w = rect.w.saturating_sub(self.inner);
+            }
+            if rect.bottom_edge() >= container.bottom_edge() {
+                rect.h = rect.h.saturating_sub(self.inner);
+            }
+            if rect.x > container.x {
+                rect.x -= self.inner as i32;
+                rect.w += self.inner;
+            }
+            if rect.y > container.y {
+                rect.y -= self.inner as i32;
+                rect.h += self.inner;
+            }
+        }
+    }
+
+    /// Same as [`Gaps::grow`], but for the columns and the [`Gaps::gutter`].
+    pub(crate) fn grow_columns(self, container: &Rect) -> Rect {
+        grow_by(container, self.gutter)
+    }
+
+    /// Same as [`Gaps::shrink`], but for the columns and the [`Gaps::gutter`].
+    pub(crate) fn shrink_columns<'a>(
+        self,
+        columns: impl IntoIterator<Item = &'a mut Option<Rect>>,
+    ) {
+        for column in columns.into_iter().flatten() {
+            shrink_by(column, self.gutter);
+        }
+    }
+}
+
+fn grow_by(container: &Rect, pixels: u32) -> Rect {
+    Rect {
+        w: container.w.saturating_add(pixels),
+        h: container.h.saturating_add(pixels),
+        ..*container
+    }
+}
+
+fn shrink_by(rect: &mut Rect, pixels: u32) {
+    rect.w = rect.w.saturating_sub(pixels);
+    rect.h = rect.h.saturating_sub(pixels);
+}
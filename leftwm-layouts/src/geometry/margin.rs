@@ -0,0 +1,78 @@
+use serde::{Deserialize, Serialize};
+
+use super::Rect;
+
+/// The space kept free along each edge of the container, see [`crate::Layout::margin`].
+///
+/// Other than the [`super::Gaps`] between the tiles, the margin shrinks the container
+/// before anything is calculated, so the reserved space of a [`super::Reserve`] and
+/// a centered `main` column are relative to the remaining space.
+///
+/// ```rust
+/// use leftwm_layouts::{apply, geometry::{Margin, Rect}, layouts::Layouts};
+///
+/// let mut layout = Layouts::default().get("Monocle").unwrap().clone();
+/// layout.margin = Some(Margin::uniform(10));
+/// let tiles = apply(&layout, 1, &Rect::new(0, 0, 400, 200));
+/// assert_eq!(vec![Rect::new(10, 10, 380, 180)], tiles);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Margin {
+    /// The pixels kept free along the top edge (default: `0`).
+    pub top: u32,
+
+    /// The pixels kept free along the right edge (default: `0`).
+    pub right: u32,
+
+    /// The pixels kept free along the bottom edge (default: `0`).
+    pub bottom: u32,
+
+    /// The pixels kept free along the left edge (default: `0`).
+    pub left: u32,
+}
+
+impl Margin {
+    /// The same margin along every edge.
+    pub const fn uniform(margin: u32) -> Self {
+        Self {
+            top: margin,
+            right: margin,
+            bottom: margin,
+            left: margin,
+        }
+    }
+
+    /// The part of the container inside the margin. If the margin takes up
+    /// the whole container, the remaining [`Rect`] has no width or height.
+    pub fn shrink(&self, container: &Rect) -> Rect {
+        let horizontal = self.left.saturating_add(self.right);
+        let vertical = self.top.saturating_add(self.bottom);
+        Rect {
+            x: container.x + self.left.min(container.w) as i32,
+            y: container.y + self.top.min(container.h) as i32,
+            w: container.w.saturating_sub(horizontal),
+            h: container.h.saturating_sub(vertical),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Margin;
+    use crate::geometry::Rect;
+
+    #[test]
+    fn margins_larger_than_the_container_leave_an_empty_rect() {
+        let margin = Margin {
+            top: 5,
+            right: 20,
+            bottom: 0,
+            left: 300,
+        };
+        assert_eq!(
+            Rect::new(200, 5, 0, 95),
+            margin.shrink(&Rect::new(0, 0, 200, 100))
+        );
+    }
+}
@@ -1,17 +1,36 @@
+mod axis;
 mod calc;
+mod check;
 mod direction;
 mod flip;
+mod gaps;
+mod margin;
+mod orientation;
 mod rect;
+mod remainder;
 mod reserve;
 mod rotation;
 mod size;
 mod split;
+mod split_iter;
 
-pub use calc::{divrem, flip, remainderless_division, rotate, split};
-pub use direction::Direction;
+pub use axis::Axis;
+pub use calc::{
+    divrem, flip, remainderless_division, remainderless_division_with, rotate, split,
+    split_weighted, split_with, split_with_merged, weighted_division, SplitOptions,
+};
+pub use check::{check_tiling, TilingIssue};
+pub use direction::{neighbors, Direction, Neighbors};
 pub use flip::Flip;
+pub use gaps::Gaps;
+pub use margin::Margin;
+pub use orientation::Orientation;
 pub use rect::Rect;
-pub use reserve::Reserve;
+pub use remainder::Remainder;
+pub use reserve::{Reserve, ReservedGaps, TakeoverOrder};
 pub use rotation::Rotation;
 pub use size::Size;
-pub use split::Split;
+pub(crate) use size::{grow_ratio, ratio_of, scale};
+pub(crate) use split::{dwindle_step, fibonacci_step, SplitStep};
+pub use split::{FixedGrid, GridFill, GridOverflow, Spiral, Split};
+pub use split_iter::{split_iter, SplitIter};
@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+
+use super::{Axis, Rect};
+
+/// Determines whether the columns of a layout are arranged next to each other
+/// or on top of each other. Other than a [`crate::geometry::Rotation`],
+/// this only changes the arrangement of the columns, not their contents.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Orientation {
+    /// The columns are arranged next to each other.
+    /// This is the default value.
+    ///
+    /// ```txt
+    /// +--------+-----+
+    /// |        |     |
+    /// |  MAIN  +-----+
+    /// |        |     |
+    /// +--------+-----+
+    /// ```
+    #[default]
+    Horizontal,
+
+    /// The columns are arranged on top of each other,
+    /// the size of the `main` column applies to its height.
+    ///
+    /// ```txt
+    /// +--------------+
+    /// |     MAIN     |
+    /// |              |
+    /// +-------+------+
+    /// |       |      |
+    /// +-------+------+
+    /// ```
+    Vertical,
+}
+
+impl Orientation {
+    /// The [`Axis`] along which the columns are arranged.
+    pub fn axis(&self) -> Axis {
+        match self {
+            Orientation::Horizontal => Axis::X,
+            Orientation::Vertical => Axis::Y,
+        }
+    }
+
+    /// Swap the axes of the provided [`Rect`] if the orientation is [`Orientation::Vertical`],
+    /// so that columns calculated along the width are arranged along the height instead.
+    /// Orienting a [`Rect`] twice results in the original [`Rect`].
+    pub(crate) fn orient(&self, rect: Rect) -> Rect {
+        match self {
+            Orientation::Horizontal => rect,
+            Orientation::Vertical => Rect::new(rect.y, rect.x, rect.h, rect.w),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Orientation;
+    use crate::geometry::Rect;
+
+    #[test]
+    fn orienting_twice_results_in_the_original_rect() {
+        let rect = Rect::new(-10, 20, 300, 400);
+        assert_eq!(rect, Orientation::Horizontal.orient(rect));
+        assert_eq!(
+            Rect::new(20, -10, 400, 300),
+            Orientation::Vertical.orient(rect)
+        );
+        let vertical = Orientation::Vertical;
+        assert_eq!(rect, vertical.orient(vertical.orient(rect)));
+    }
+}
@@ -1,3 +1,7 @@
+use serde::{Deserialize, Serialize};
+
+use super::{split, Axis, Remainder};
+
 /// Represents a rectangle with a position ([`Rect::x`], [`Rect::y`])
 /// and dimensions ([`Rect::w`], [`Rect::h`]).
 ///
@@ -12,7 +16,7 @@
 ///   <------->
 ///       w
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Rect {
     /// X-Coordinate, can be negative
     pub x: i32,
@@ -30,7 +34,7 @@ pub struct Rect {
 impl Rect {
     /// Shorthand method to create a new [`Rect`] with
     /// the provided `x`, `y`, `w`, and `h`.
-    pub fn new(x: i32, y: i32, w: u32, h: u32) -> Self {
+    pub const fn new(x: i32, y: i32, w: u32, h: u32) -> Self {
         Self { x, y, w, h }
     }
 
@@ -44,8 +48,8 @@ impl Rect {
     /// The center coordinate is rounded to the nearest integer
     /// and might not be at the exact center position.
     pub fn center(&self) -> (i32, i32) {
-        let x = self.x + (self.w as f32 / 2.0).round() as i32;
-        let y = self.y + (self.h as f32 / 2.0).round() as i32;
+        let x = self.x + self.w.div_ceil(2) as i32;
+        let y = self.y + self.h.div_ceil(2) as i32;
         (x, y)
     }
 
@@ -59,6 +63,17 @@ impl Rect {
             && point.1 <= self.y + self.h as i32
     }
 
+    /// Check whether the [`Rect`] overlaps with another [`Rect`].
+    ///
+    /// Other than in [`Rect::contains`], the boundary does not count,
+    /// so two [`Rect`] which only share an edge do not overlap.
+    pub fn overlaps(&self, other: &Rect) -> bool {
+        self.left_edge() < other.right_edge()
+            && other.left_edge() < self.right_edge()
+            && self.top_edge() < other.bottom_edge()
+            && other.top_edge() < self.bottom_edge()
+    }
+
     /// Get the top left corner point of the [`Rect`].
     ///
     /// ```txt
@@ -166,6 +181,30 @@ impl Rect {
     pub fn left_edge(&self) -> i32 {
         self.x
     }
+
+    /// Split the [`Rect`] into exactly `n` parts of the same size along the provided [`Axis`],
+    /// the first parts get the remaining pixels (same as [`Split::Vertical`] along [`Axis::X`]
+    /// and [`Split::Horizontal`] along [`Axis::Y`]).
+    ///
+    /// ## Example
+    /// ```rust
+    /// use leftwm_layouts::geometry::{Axis, Rect};
+    ///
+    /// let parts = Rect::new(0, 0, 100, 50).split_evenly(3, Axis::X);
+    /// assert_eq!(
+    ///     vec![Rect::new(0, 0, 34, 50), Rect::new(34, 0, 33, 50), Rect::new(67, 0, 33, 50)],
+    ///     parts
+    /// );
+    /// ```
+    ///
+    /// [`Split::Vertical`]: super::Split::Vertical
+    /// [`Split::Horizontal`]: super::Split::Horizontal
+    pub fn split_evenly(&self, n: usize, axis: Axis) -> Vec<Rect> {
+        match n {
+            0 => vec![],
+            _ => split::even(self, n, axis, Remainder::First),
+        }
+    }
 }
 
 impl Default for Rect {
@@ -182,6 +221,22 @@ impl Default for Rect {
 #[cfg(test)]
 mod tests {
     use super::Rect;
+    use crate::geometry::{split, Axis, Split};
+
+    #[test]
+    fn split_evenly_matches_split() {
+        let rect = Rect::new(-7, 13, 401, 199);
+        for n in 0..10 {
+            assert_eq!(
+                split(&rect, n, Some(Split::Vertical)),
+                rect.split_evenly(n, Axis::X)
+            );
+            assert_eq!(
+                split(&rect, n, Some(Split::Horizontal)),
+                rect.split_evenly(n, Axis::Y)
+            );
+        }
+    }
 
     #[test]
     fn surface_area_calculation() {
@@ -234,4 +289,18 @@ mod tests {
         assert!(!rect.contains((500, 201)));
         assert!(!rect.contains((100, 201)));
     }
+
+    #[test]
+    fn rects_sharing_an_edge_do_not_overlap() {
+        let rect = Rect::new(100, 100, 400, 100);
+        assert!(!rect.overlaps(&Rect::new(500, 100, 100, 100)));
+        assert!(!rect.overlaps(&Rect::new(100, 200, 400, 100)));
+    }
+
+    #[test]
+    fn overlapping_rects() {
+        let rect = Rect::new(100, 100, 400, 100);
+        assert!(rect.overlaps(&Rect::new(499, 199, 100, 100)));
+        assert!(rect.overlaps(&Rect::new(200, 120, 10, 10)));
+    }
 }
@@ -0,0 +1,108 @@
+use std::cmp;
+
+use serde::{Deserialize, Serialize};
+
+/// Describes which parts get the remaining pixels, if a length
+/// can not be divided evenly (see [`crate::geometry::remainderless_division_with`]).
+///
+/// The examples show which of five parts get an extra pixel
+/// when dividing 12 pixels, marked by a `+`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Remainder {
+    /// The first parts get the remaining pixels.
+    /// This is the default value.
+    ///
+    /// ```txt
+    /// +-+-+-+-+-+
+    /// |+|+| | | |
+    /// +-+-+-+-+-+
+    /// ```
+    #[default]
+    First,
+
+    /// The last parts get the remaining pixels.
+    ///
+    /// ```txt
+    /// +-+-+-+-+-+
+    /// | | | |+|+|
+    /// +-+-+-+-+-+
+    /// ```
+    Last,
+
+    /// The parts closest to the center get the remaining pixels,
+    /// the earlier one wins if two parts are equally close.
+    /// Useful to keep symmetric layouts symmetric.
+    ///
+    /// ```txt
+    /// +-+-+-+-+-+
+    /// | |+|+| | |
+    /// +-+-+-+-+-+
+    /// ```
+    Center,
+
+    /// The remaining pixels are spread evenly across all parts.
+    ///
+    /// ```txt
+    /// +-+-+-+-+-+
+    /// | |+| |+| |
+    /// +-+-+-+-+-+
+    /// ```
+    Spread,
+}
+
+impl Remainder {
+    /// Returns `true` if the part at `index` of `amount` parts gets
+    /// one of the `remainder` pixels, `remainder` being less than `amount`.
+    pub(crate) fn gets_extra(&self, index: usize, amount: usize, remainder: usize) -> bool {
+        match self {
+            Remainder::First => index < remainder,
+            Remainder::Last => index >= amount - remainder,
+            Remainder::Center => {
+                // the amount of parts closer to the center, alternating
+                // between the earlier and the later side of the center
+                let center = (amount - 1) / 2;
+                let closer = match (index.cmp(&center), amount % 2) {
+                    (cmp::Ordering::Equal, _) => 0,
+                    (cmp::Ordering::Less, 0) => 2 * (center - index),
+                    (cmp::Ordering::Less, _) => 2 * (center - index) - 1,
+                    (cmp::Ordering::Greater, 0) => 2 * (index - center) - 1,
+                    (cmp::Ordering::Greater, _) => 2 * (index - center),
+                };
+                closer < remainder
+            }
+            Remainder::Spread => {
+                // rounded positions of the remaining pixels, centered in their share
+                let position = |i: usize| (2 * i * remainder + amount) / (2 * amount);
+                position(index + 1) > position(index)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Remainder;
+
+    #[test]
+    fn center_prefers_the_parts_closest_to_the_center() {
+        for amount in 1..30 {
+            // the distance to the center, doubled to stay whole
+            let distance = |i: usize| (2 * i).abs_diff(amount - 1);
+            for remainder in 0..amount {
+                for index in 0..amount {
+                    let closer = (0..amount)
+                        .filter(|&i| {
+                            distance(i) < distance(index)
+                                || distance(i) == distance(index) && i < index
+                        })
+                        .count();
+                    assert_eq!(
+                        closer < remainder,
+                        Remainder::Center.gets_extra(index, amount, remainder),
+                        "part {index} of {amount} with {remainder} remaining"
+                    );
+                }
+            }
+        }
+    }
+}
@@ -51,6 +51,102 @@ pub enum Reserve {
     ReserveAndCenter,
 }
 
+/// Determines how a window manager should treat gaps around a reserved,
+/// but empty column space (see [`Reserve`]).
+///
+/// The [`crate::Layout::gaps`] are taken off the reserved areas reported in
+/// [`crate::Tiling::reserved`] accordingly, the setting is passed along with them
+/// for the window manager to honor when rendering overlays.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReservedGaps {
+    /// The reserved area is treated like an invisible tile,
+    /// it is surrounded by gaps just like any other tile.
+    /// This is the default variant.
+    ///
+    /// ```txt
+    /// +--------------+
+    /// | +------+ +-+ |
+    /// | | MAIN | | | |
+    /// | +------+ +-+ |
+    /// +--------------+
+    /// ```
+    #[default]
+    Keep,
+
+    /// No gaps are rendered around the reserved area,
+    /// so the reserved hole blends into the outer gaps and looks intentional.
+    ///
+    /// ```txt
+    /// +--------------+
+    /// | +------+     |
+    /// | | MAIN |     |
+    /// | +------+     |
+    /// +--------------+
+    /// ```
+    Collapse,
+}
+
+/// Determines which column takes over the space of an empty second stack
+/// in three column layouts (eg. `CenterMain`), if the space isn't reserved
+/// (see [`Reserve::None`]).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TakeoverOrder {
+    /// The other stack takes over the empty space first.
+    /// This is the default variant.
+    ///
+    /// ```txt
+    /// +-----+--------+
+    /// |     |        |
+    /// |     |  MAIN  |
+    /// |     |        |
+    /// +-----+--------+
+    /// ```
+    #[default]
+    StacksFirst,
+
+    /// The `main` column takes over the empty space first.
+    ///
+    /// ```txt
+    /// +--+-----------+
+    /// |  |           |
+    /// |  |   MAIN    |
+    /// |  |           |
+    /// +--+-----------+
+    /// ```
+    MainFirst,
+
+    /// The `main` column and the other stack take over the
+    /// empty space in proportion to their sizes.
+    ///
+    /// ```txt
+    /// +---+----------+
+    /// |   |          |
+    /// |   |   MAIN   |
+    /// |   |          |
+    /// +---+----------+
+    /// ```
+    Proportional,
+}
+
+impl TakeoverOrder {
+    /// Distribute the `freed` width of an empty column among the
+    /// `main` column and the `stack`, returning their new widths.
+    pub(crate) fn take_over(&self, main: usize, stack: usize, freed: usize) -> (usize, usize) {
+        match self {
+            TakeoverOrder::StacksFirst => (main, stack + freed),
+            TakeoverOrder::MainFirst => (main + freed, stack),
+            TakeoverOrder::Proportional => {
+                let whole = main + stack + freed;
+                let main = match main + stack {
+                    0 => whole / 2,
+                    occupied => (whole as u64 * main as u64 / occupied as u64) as usize,
+                };
+                (main, whole - main)
+            }
+        }
+    }
+}
+
 impl Reserve {
     pub fn is_reserved(&self) -> bool {
         match self {
@@ -61,4 +157,23 @@ impl Reserve {
 }
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::TakeoverOrder;
+
+    #[test]
+    fn take_over_keeps_the_whole_width() {
+        assert_eq!(
+            (400, 600),
+            TakeoverOrder::StacksFirst.take_over(400, 300, 300)
+        );
+        assert_eq!(
+            (700, 300),
+            TakeoverOrder::MainFirst.take_over(400, 300, 300)
+        );
+        assert_eq!(
+            (571, 429),
+            TakeoverOrder::Proportional.take_over(400, 300, 300)
+        );
+        assert_eq!((5, 5), TakeoverOrder::Proportional.take_over(0, 0, 10));
+    }
+}
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
 
 use super::Rect;
 
@@ -113,6 +114,32 @@ impl Rotation {
     }
 }
 
+impl fmt::Display for Rotation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Rotation::North => "North",
+            Rotation::East => "East",
+            Rotation::South => "South",
+            Rotation::West => "West",
+        })
+    }
+}
+
+impl FromStr for Rotation {
+    type Err = ();
+
+    /// Parses the name of a variant, ignoring the case (eg. `east`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "north" => Ok(Rotation::North),
+            "east" => Ok(Rotation::East),
+            "south" => Ok(Rotation::South),
+            "west" => Ok(Rotation::West),
+            _ => Err(()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Rotation;
@@ -184,4 +211,20 @@ mod tests {
         let anchor = Rotation::West.next_anchor(&rect);
         assert_eq!(anchor, (1920, 0));
     }
+
+    #[test]
+    fn rotation_display_round_trips() {
+        let mut rotation = Rotation::North;
+        for _ in 0..4 {
+            assert_eq!(Ok(rotation), rotation.to_string().parse());
+            rotation = rotation.clockwise();
+        }
+    }
+
+    #[test]
+    fn rotation_from_str_ignores_case() {
+        assert_eq!(Ok(Rotation::West), "west".parse());
+        assert_eq!(Ok(Rotation::East), " East ".parse());
+        assert_eq!(Err(()), "up".parse::<Rotation>());
+    }
 }
@@ -1,18 +1,49 @@
-use serde::{Deserialize, Serialize};
+use std::hash::{Hash, Hasher};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The amount of basis points (ie. 0.01%) in a whole.
+const BASIS_POINTS: i32 = 10_000;
 
 /// Helper enum to represent a size which can be
 /// an absolute pixel value or a relative ratio value
-#[derive(Debug, Clone, PartialEq, Copy, Serialize, Deserialize)]
+///
+/// Ratios are compared, hashed, and serialized in whole basis points (ie. 0.01%),
+/// so that float noise like `0.550000012` after repeatedly changing a ratio
+/// doesn't affect equality and doesn't end up in configs.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Size {
     /// Size in pixels (ie. 10 means 10 pixels)
     Pixel(i32),
 
     /// Relative size as a ratio between 0 to 1 (ie. 0.5 means 50%)
-    Ratio(f32),
+    Ratio(
+        #[serde(
+            serialize_with = "serialize_ratio",
+            deserialize_with = "deserialize_ratio"
+        )]
+        f32,
+    ),
 }
 
 impl Size {
+    /// Round a ratio value to whole basis points (ie. 0.01%),
+    /// a pixel value will be returned as is.
+    ///
+    /// ```
+    /// use leftwm_layouts::geometry::Size;
+    ///
+    /// let noisy = Size::Ratio(0.5 + 0.01 + 0.01 + 0.01 + 0.01 + 0.01);
+    /// assert_eq!(Size::Ratio(0.55), noisy.quantized());
+    /// ```
+    pub fn quantized(self) -> Self {
+        match self {
+            Size::Pixel(x) => Size::Pixel(x),
+            Size::Ratio(x) => Size::Ratio(from_basis_points(basis_points(x))),
+        }
+    }
+
     /// Turn the size into an absolute value.
     ///
     /// A pixel value will be returned as is, a ratio
@@ -22,17 +53,145 @@ impl Size {
     /// ## Hint
     /// A negative ratio value will be converted into
     /// an absolute number before being applied.
+    ///
+    /// With the `no-fp` feature, the ratio is applied using integer math only,
+    /// resulting in the exact (rounded) value on every platform.
     pub fn into_absolute(self, whole: u32) -> i32 {
         match self {
             Size::Pixel(x) => x,
-            Size::Ratio(x) => (whole as f32 * x.abs()).round() as i32,
+            Size::Ratio(x) => i32::try_from(scale(whole, x)).unwrap_or(i32::MAX),
+        }
+    }
+}
+
+impl PartialEq for Size {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Size::Pixel(a), Size::Pixel(b)) => a == b,
+            (Size::Ratio(a), Size::Ratio(b)) => basis_points(*a) == basis_points(*b),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Size {}
+
+impl Hash for Size {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Size::Pixel(x) => (0u8, *x).hash(state),
+            Size::Ratio(x) => (1u8, basis_points(*x)).hash(state),
         }
     }
 }
 
+/// The ratio in whole basis points, rounded to the nearest one (ie. `0.55` => `5500`).
+/// `NaN` is treated as `0`.
+#[cfg(not(feature = "no-fp"))]
+pub(crate) fn basis_points(ratio: f32) -> i32 {
+    (f64::from(ratio) * f64::from(BASIS_POINTS)).round() as i32
+}
+
+/// The ratio in whole basis points, rounded to the nearest one (ie. `0.55` => `5500`).
+/// `NaN` is treated as `0`.
+#[cfg(feature = "no-fp")]
+pub(crate) fn basis_points(ratio: f32) -> i32 {
+    let basis_points = i32::try_from(scale(BASIS_POINTS as u32, ratio)).unwrap_or(i32::MAX);
+    if ratio.is_sign_negative() {
+        -basis_points
+    } else {
+        basis_points
+    }
+}
+
+/// The share of `part` in `whole` in whole basis points, rounded to the nearest one.
+fn basis_points_of(part: i64, whole: u32) -> i32 {
+    if whole == 0 {
+        return 0;
+    }
+    let scaled = part.saturating_mul(i64::from(BASIS_POINTS));
+    let whole = i64::from(whole);
+    let rounded = (scaled.abs() + whole / 2) / whole * scaled.signum();
+    i32::try_from(rounded).unwrap_or(if part < 0 { i32::MIN } else { i32::MAX })
+}
+
+/// The ratio of `part` in `whole`, rounded to whole basis points.
+///
+/// The ratio is calculated using integer math only, resulting in
+/// the exact same value on every platform.
+pub(crate) fn ratio_of(part: i64, whole: u32) -> f32 {
+    from_basis_points(basis_points_of(part, whole))
+}
+
+/// Grow the `ratio` by `part` of `whole` (shrink it if `part` is negative),
+/// rounded to whole basis points and kept between `0` and `1`.
+///
+/// Same as [`ratio_of`], this uses integer math only.
+pub(crate) fn grow_ratio(ratio: f32, part: i64, whole: u32) -> f32 {
+    let grown = basis_points(ratio).saturating_add(basis_points_of(part, whole));
+    from_basis_points(grown.clamp(0, BASIS_POINTS))
+}
+
+/// The ratio of the provided basis points (ie. `5500` => `0.55`).
+pub(crate) fn from_basis_points(basis_points: i32) -> f32 {
+    basis_points as f32 / BASIS_POINTS as f32
+}
+
+/// Serialize a ratio as its basis points in a whole, so that `0.55`
+/// is written as `0.55` instead of `0.550000011920929`.
+fn serialize_ratio<S: Serializer>(ratio: &f32, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(f64::from(basis_points(*ratio)) / f64::from(BASIS_POINTS))
+}
+
+/// Deserialize a ratio, rounded to whole basis points.
+fn deserialize_ratio<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f32, D::Error> {
+    f32::deserialize(deserializer).map(|ratio| from_basis_points(basis_points(ratio)))
+}
+
+/// Multiply `whole` by the absolute value of `ratio`, rounded to the nearest integer.
+#[cfg(not(feature = "no-fp"))]
+pub(crate) fn scale(whole: u32, ratio: f32) -> u64 {
+    (whole as f32 * ratio.abs()).round() as u64
+}
+
+/// Multiply `whole` by the absolute value of `ratio`, rounded to the nearest integer.
+///
+/// Other than the floating point variant, this only uses integer math,
+/// resulting in the exact same value on every platform.
+#[cfg(feature = "no-fp")]
+pub(crate) fn scale(whole: u32, ratio: f32) -> u64 {
+    if ratio.is_nan() {
+        return 0;
+    }
+    if ratio.is_infinite() {
+        return if whole == 0 { 0 } else { u64::MAX };
+    }
+    // a finite f32 is exactly `mantissa * 2^exponent` (the sign bit is ignored)
+    let bits = ratio.to_bits();
+    let biased_exponent = ((bits >> 23) & 0xff) as i32;
+    let fraction = u128::from(bits & 0x7f_ffff);
+    let (mantissa, exponent) = match biased_exponent {
+        0 => (fraction, -149),
+        _ => (fraction | 0x80_0000, biased_exponent - 150),
+    };
+    let product = u128::from(whole) * mantissa;
+    let scaled = match exponent {
+        // the product has less than 56 bits, it can be shifted by up to 72 bits
+        0..=72 => product << exponent,
+        73.. => u128::MAX,
+        // round half up
+        -127..=-1 => (product + (1 << (-exponent - 1))) >> -exponent,
+        _ => 0,
+    };
+    u64::try_from(scaled).unwrap_or(u64::MAX)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Size;
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    use super::{grow_ratio, ratio_of, scale, Size};
 
     #[test]
     fn absolute_size_into_absolute_stays_same() {
@@ -79,4 +238,83 @@ mod tests {
         let absolute = size.into_absolute(33);
         assert_eq!(absolute, 17);
     }
+
+    #[test]
+    fn relative_size_of_huge_whole_does_not_overflow() {
+        assert_eq!(i32::MAX, Size::Ratio(1000.0).into_absolute(u32::MAX));
+        assert_eq!(0, Size::Ratio(f32::NAN).into_absolute(1000));
+    }
+
+    #[test]
+    fn ratios_are_equal_by_basis_points() {
+        let mut ratio = 0.5f32;
+        for _ in 0..5 {
+            ratio += 0.01;
+        }
+        assert_ne!(0.55, ratio);
+        assert_eq!(Size::Ratio(0.55), Size::Ratio(ratio));
+        assert_ne!(Size::Ratio(0.55), Size::Ratio(0.5501));
+        assert_ne!(Size::Ratio(0.0), Size::Pixel(0));
+
+        let hash = |size: Size| {
+            let mut hasher = DefaultHasher::new();
+            size.hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash(Size::Ratio(0.55)), hash(Size::Ratio(ratio)));
+    }
+
+    #[test]
+    fn ratios_are_serialized_without_float_noise() {
+        let mut ratio = 0.5f32;
+        for _ in 0..5 {
+            ratio += 0.01;
+        }
+        let json = serde_json::to_string(&Size::Ratio(ratio)).unwrap();
+        assert_eq!("0.55", json);
+        assert_eq!("0.333", serde_json::to_string(&Size::Ratio(0.333)).unwrap());
+        assert_eq!("400", serde_json::to_string(&Size::Pixel(400)).unwrap());
+
+        let Size::Ratio(deserialized) = serde_json::from_str("0.550000012").unwrap() else {
+            panic!("a ratio must be deserialized as a ratio");
+        };
+        assert_eq!(0.55, deserialized);
+        assert_eq!(Size::Pixel(400), serde_json::from_str("400").unwrap());
+    }
+
+    #[test]
+    fn scale_rounds_to_nearest() {
+        assert_eq!(17, scale(33, 0.5));
+        assert_eq!(16, scale(33, 0.49));
+        assert_eq!(1920, scale(1920, 1.0));
+        assert_eq!(0, scale(1920, 0.0));
+    }
+
+    #[test]
+    fn ratios_of_pixels_are_rounded_to_basis_points() {
+        assert_eq!(0.3333, ratio_of(1, 3));
+        assert_eq!(-0.6667, ratio_of(-2, 3));
+        assert_eq!(0.0, ratio_of(10, 0));
+        assert_eq!(0.6, grow_ratio(0.5, 41, 410));
+        assert_eq!(1.0, grow_ratio(0.9, 200, 400));
+        assert_eq!(0.0, grow_ratio(0.1, -200, 400));
+    }
+
+    #[cfg(feature = "no-fp")]
+    #[test]
+    fn integer_scale_is_exact() {
+        // 0.01 is slightly less than 0.01 as f32, so the exact result is
+        // slightly less than 3.5, while floating point math rounds to 4
+        assert_eq!(3, scale(350, 0.01));
+        for percent in 0..=100u16 {
+            let ratio = f32::from(percent) / 100.0;
+            for whole in (0..=7680).step_by(7) {
+                let float = (whole as f32 * ratio).round() as u64;
+                assert!(
+                    float.abs_diff(scale(whole, ratio)) <= 1,
+                    "{whole} * {ratio}"
+                );
+            }
+        }
+    }
 }
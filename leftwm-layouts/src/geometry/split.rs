@@ -1,6 +1,11 @@
+use std::{cmp::Reverse, collections::BinaryHeap, fmt, mem, str::FromStr};
+
 use serde::{Deserialize, Serialize};
 
-use super::{divrem, remainderless_division, split, Rect, Rotation};
+use super::{
+    divrem, remainderless_division, remainderless_division_with, scale, size, split,
+    weighted_division, Axis, Rect, Remainder, Rotation, Size,
+};
 
 /// Describes different ways a [`crate::geometry::Rect`] can be split.
 ///
@@ -8,7 +13,7 @@ use super::{divrem, remainderless_division, split, Rect, Rotation};
 /// not the orientation of the resulting stack. For example, [`Split::Horizontal`]
 /// splits a rect by **horizontal cuts**, resulting in a "vertically stacked" list of rects.
 /// See the variants' documentation for clarification.*
-#[derive(PartialEq, Eq, Clone, Copy, Serialize, Deserialize, Debug)]
+#[derive(Clone, Copy, Serialize, Deserialize, Debug)]
 pub enum Split {
     /// Rectangle is split by `horizontal` cuts.
     ///
@@ -50,6 +55,20 @@ pub enum Split {
     /// ```
     Grid,
 
+    /// Rectangle is split in a "Grid" pattern like [`Split::Grid`], but it is filled
+    /// row-by-row instead of column-by-column, prioritizing wide rows over high columns.
+    ///
+    /// ```txt
+    /// +-------+      +-------+
+    /// |       |      |       |
+    /// |       |      |       |
+    /// |       |  =>  +---+---+
+    /// |       |      |   |   |
+    /// |       |      |   |   |
+    /// +-------+      +---+---+
+    /// ```
+    RowGrid,
+
     /// Rectangle is split in a "Fibonacci" pattern.
     ///
     /// ```txt
@@ -76,112 +95,780 @@ pub enum Split {
     /// +-------+      +---+---+
     /// ```
     Dwindle,
+
+    /// Rectangle is split like a binary space partition, similar to the automatic scheme of `bspwm`.
+    /// Each additional tile is created by splitting the largest existing tile along its longer side.
+    /// The split tile keeps the given ratio (between 0 and 1) of its size, the new tile gets the rest.
+    ///
+    /// ```txt
+    /// +-------+      +---+---+
+    /// |       |      |   |   |
+    /// |       |      | 1 | 2 |
+    /// |       |  =>  +---+---+
+    /// |       |      |   |   |
+    /// |       |      | 3 | 4 |
+    /// +-------+      +---+---+
+    /// ```
+    BinaryTree(f32),
+
+    /// Rectangle is split into the given amount of equally wide columns by `vertical` cuts.
+    /// Windows are distributed evenly across the columns (earlier columns receive the
+    /// overflow first) and every column is then split by `horizontal` cuts.
+    /// If there are fewer windows than columns, only as many columns as windows are created.
+    ///
+    /// ```txt
+    /// +--------+      +--+--+--+
+    /// |        |      |  |  |  |
+    /// |        |      |  |  |  |
+    /// |        |  =>  +--+--+  |
+    /// |        |      |  |  |  |
+    /// |        |      |  |  |  |
+    /// +--------+      +--+--+--+
+    /// ```
+    Columns(usize),
+
+    /// Rectangle is split into the given amount of equally high rows by `horizontal` cuts.
+    /// Windows are distributed evenly across the rows (earlier rows receive the
+    /// overflow first) and every row is then split by `vertical` cuts.
+    /// If there are fewer windows than rows, only as many rows as windows are created.
+    ///
+    /// ```txt
+    /// +--------+      +----+---+
+    /// |        |      |    |   |
+    /// |        |      +----+---+
+    /// |        |  =>  |    |   |
+    /// |        |      +----+---+
+    /// |        |      |        |
+    /// +--------+      +--------+
+    /// ```
+    Rows(usize),
+
+    /// Rectangle is split like a floor covered with tatami mats, where no four
+    /// corners ever meet in a single point. Five windows are arranged in the classic
+    /// four-and-a-half mat pattern, four mats spiraling around a square half mat.
+    /// Three and four windows use smaller variations of that pattern.
+    /// More than five windows are grouped into columns of up to five windows,
+    /// each of which is split like a tatami floor on its own.
+    ///
+    /// ```txt
+    /// +--------+      +-----+--+
+    /// |        |      |     |  |
+    /// |        |      +--+--+  |
+    /// |        |  =>  |  |  |  |
+    /// |        |      |  +--+--+
+    /// |        |      |  |     |
+    /// +--------+      +--+-----+
+    /// ```
+    Tatami,
+
+    /// Rectangle is split into overlapping tiles, like an accordion folded by `horizontal` cuts.
+    /// Every tile is half as high as the rectangle, and each tile is shifted down by a strip
+    /// so that the tiles cover the whole rectangle. Later tiles are meant to be stacked
+    /// above earlier ones, leaving the top strip of every window visible.
+    ///
+    /// ```txt
+    /// +--------+      +--------+
+    /// |        |      +--------+
+    /// |        |      +--------+
+    /// |        |  =>  |        |
+    /// |        |      |        |
+    /// |        |      |        |
+    /// +--------+      +--------+
+    /// ```
+    HorizontalAccordion,
+
+    /// Rectangle is split into overlapping tiles, like an accordion folded by `vertical` cuts.
+    /// Every tile is half as wide as the rectangle, and each tile is shifted right by a strip
+    /// so that the tiles cover the whole rectangle. Later tiles are meant to be stacked
+    /// above earlier ones, leaving the left strip of every window visible.
+    ///
+    /// ```txt
+    /// +--------+      +-+-+----+
+    /// |        |      | | |    |
+    /// |        |      | | |    |
+    /// |        |  =>  | | |    |
+    /// |        |      | | |    |
+    /// |        |      | | |    |
+    /// +--------+      +-+-+----+
+    /// ```
+    VerticalAccordion,
+
+    /// Rectangle is split by `vertical` cuts if it is wider than tall,
+    /// by `horizontal` cuts otherwise. Useful for columns whose orientation
+    /// depends on the rotation of the layout, avoiding unusably thin tiles.
+    ///
+    /// ```txt
+    /// +--------+      +--+--+--+
+    /// |        |  =>  |  |  |  |
+    /// +--------+      +--+--+--+
+    ///
+    /// +----+          +----+
+    /// |    |          |    |
+    /// |    |          +----+
+    /// |    |    =>    |    |
+    /// |    |          +----+
+    /// |    |          |    |
+    /// +----+          +----+
+    /// ```
+    Auto,
+
+    /// Rectangle is split into a grid of rows or columns, whichever keeps the
+    /// tiles closest to the provided aspect ratio (width / height, eg. `16.0 / 9.0`,
+    /// see [`Split::DEFAULT_ASPECT_RATIO`]). Useful for video-wall like grids
+    /// on monitors of unusual proportions.
+    ///
+    /// ```txt
+    /// +-----------------+      +-----+-----+-----+
+    /// |                 |      |     |     |     |
+    /// |                 |  =>  +-----+--+--+-----+
+    /// |                 |      |        |        |
+    /// +-----------------+      +--------+--------+
+    /// ```
+    AspectRatio(f32),
+
+    /// Rectangle is split into a grid of exactly as many rows and columns as configured,
+    /// no matter how many tiles are requested. The tiles fill the cells in the configured
+    /// order, see [`FixedGrid`] for what happens to the tiles that don't fit.
+    ///
+    /// ```txt
+    /// +-----------------+      +-----+-----+-----+
+    /// |                 |      |  1  |  2  |  3  |
+    /// |                 |  =>  +-----+-----+-----+
+    /// |                 |      |  4  |     |     |
+    /// +-----------------+      +-----+-----+-----+
+    /// ```
+    FixedGrid(FixedGrid),
+}
+
+/// The ratios of [`Split::BinaryTree`] and [`Split::AspectRatio`] are compared
+/// in whole basis points like the ones of a [`Size`], so that every split
+/// (even one with a `NaN` ratio) is equal to itself.
+impl PartialEq for Split {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Split::BinaryTree(a), Split::BinaryTree(b))
+            | (Split::AspectRatio(a), Split::AspectRatio(b)) => {
+                size::basis_points(*a) == size::basis_points(*b)
+            }
+            (Split::Columns(a), Split::Columns(b)) | (Split::Rows(a), Split::Rows(b)) => a == b,
+            (Split::FixedGrid(a), Split::FixedGrid(b)) => a == b,
+            // the remaining variants don't carry any values
+            _ => mem::discriminant(self) == mem::discriminant(other),
+        }
+    }
+}
+
+impl Eq for Split {}
+
+/// Configures the cells of a [`Split::FixedGrid`].
+#[derive(PartialEq, Eq, Clone, Copy, Serialize, Deserialize, Debug)]
+#[serde(default)]
+pub struct FixedGrid {
+    /// The amount of rows, at least one (default: `2`).
+    pub rows: usize,
+
+    /// The amount of columns, at least one (default: `2`).
+    pub columns: usize,
+
+    /// The order in which the tiles fill the cells (default: [`GridFill::RowByRow`]).
+    pub fill: GridFill,
+
+    /// What happens to the tiles that don't fit into the cells
+    /// (default: [`GridOverflow::ShareLastCell`]).
+    pub overflow: GridOverflow,
+}
+
+impl Default for FixedGrid {
+    fn default() -> Self {
+        Self {
+            rows: 2,
+            columns: 2,
+            fill: GridFill::default(),
+            overflow: GridOverflow::default(),
+        }
+    }
+}
+
+/// The order in which the tiles fill the cells of a [`Split::FixedGrid`].
+#[derive(PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize, Debug)]
+pub enum GridFill {
+    /// The first row is filled from left to right, then the next row.
+    #[default]
+    RowByRow,
+
+    /// The first column is filled from top to bottom, then the next column.
+    ColumnByColumn,
+}
+
+/// What happens to the tiles that don't fit into the cells of a [`Split::FixedGrid`].
+#[derive(PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize, Debug)]
+pub enum GridOverflow {
+    /// The tiles that don't fit get the same cell as the last tile that fits,
+    /// stacked on top of each other.
+    #[default]
+    ShareLastCell,
+
+    /// The tiles that don't fit are left out, their windows are meant to be hidden.
+    Hide,
+}
+
+/// Configures the spiral of the [`Split::Fibonacci`] and [`Split::Dwindle`] splits.
+///
+/// The default values result in the same spirals as in other
+/// window managers, where the first cut is horizontal and
+/// the fibonacci spiral turns clockwise.
+#[derive(PartialEq, Eq, Clone, Copy, Serialize, Deserialize, Debug)]
+#[serde(default)]
+pub struct Spiral {
+    /// Whether the [`Split::Fibonacci`] spiral turns clockwise,
+    /// otherwise it turns counter-clockwise (default: `true`).
+    /// This does not apply to [`Split::Dwindle`], which always spirals into the same corner.
+    pub clockwise: bool,
+
+    /// Whether the first cut is a horizontal cut,
+    /// otherwise it is a vertical cut (default: `true`).
+    pub horizontal_first: bool,
+}
+
+impl Default for Spiral {
+    fn default() -> Self {
+        Self {
+            clockwise: true,
+            horizontal_first: true,
+        }
+    }
+}
+
+impl Split {
+    /// The aspect ratio of a [`Split::AspectRatio`] if none is provided, `16:9`.
+    pub const DEFAULT_ASPECT_RATIO: f32 = 16.0 / 9.0;
+
+    /// Returns `true` if the tiles resulting from this [`Split`]
+    /// are meant to overlap each other.
+    ///
+    /// Validation tooling like [`crate::geometry::check_tiling`] should permit
+    /// overlapping tiles for splits that overlap on purpose.
+    pub fn may_overlap(&self) -> bool {
+        match self {
+            Split::Horizontal
+            | Split::Vertical
+            | Split::Grid
+            | Split::RowGrid
+            | Split::Fibonacci
+            | Split::Dwindle
+            | Split::BinaryTree(_)
+            | Split::Columns(_)
+            | Split::Rows(_)
+            | Split::Tatami
+            | Split::Auto
+            | Split::AspectRatio(_) => false,
+            Split::FixedGrid(grid) => grid.overflow == GridOverflow::ShareLastCell,
+            Split::HorizontalAccordion | Split::VerticalAccordion => true,
+        }
+    }
+}
+
+impl fmt::Display for Split {
+    /// Writes the name of the variant as used in configurations,
+    /// with its parameter in parentheses (eg. `Dwindle` or `Columns(3)`).
+    /// A [`Split::FixedGrid`] is written as `FixedGrid(2x3)`, followed by
+    /// its [`GridFill`] and [`GridOverflow`] if they aren't the default.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Split::FixedGrid(grid) => {
+                write!(f, "FixedGrid({}x{}", grid.rows, grid.columns)?;
+                if grid.fill != GridFill::default() {
+                    write!(f, ", {:?}", grid.fill)?;
+                }
+                if grid.overflow != GridOverflow::default() {
+                    write!(f, ", {:?}", grid.overflow)?;
+                }
+                write!(f, ")")
+            }
+            _ => fmt::Debug::fmt(self, f),
+        }
+    }
+}
+
+impl FromStr for Split {
+    type Err = ();
+
+    /// Parses the name of a variant, ignoring the case, with its
+    /// parameter in parentheses (eg. `dwindle` or `Columns(3)`).
+    /// The ratio of a [`Split::AspectRatio`] may be written as `16:9`,
+    /// or left out for the [`Split::DEFAULT_ASPECT_RATIO`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, parameter) = match s.trim().strip_suffix(')') {
+            Some(call) => {
+                let (name, parameter) = call.split_once('(').ok_or(())?;
+                (name.trim(), Some(parameter.trim()))
+            }
+            None => (s.trim(), None),
+        };
+        let parse_count = || parameter.and_then(|p| p.parse().ok()).ok_or(());
+        match (name.to_ascii_lowercase().as_str(), parameter) {
+            ("horizontal", None) => Ok(Split::Horizontal),
+            ("vertical", None) => Ok(Split::Vertical),
+            ("grid", None) => Ok(Split::Grid),
+            ("rowgrid", None) => Ok(Split::RowGrid),
+            ("fibonacci", None) => Ok(Split::Fibonacci),
+            ("dwindle", None) => Ok(Split::Dwindle),
+            ("binarytree", Some(ratio)) => ratio.parse().map(Split::BinaryTree).map_err(|_| ()),
+            ("columns", Some(_)) => parse_count().map(Split::Columns),
+            ("rows", Some(_)) => parse_count().map(Split::Rows),
+            ("tatami", None) => Ok(Split::Tatami),
+            ("horizontalaccordion", None) => Ok(Split::HorizontalAccordion),
+            ("verticalaccordion", None) => Ok(Split::VerticalAccordion),
+            ("auto", None) => Ok(Split::Auto),
+            ("aspectratio", None) => Ok(Split::AspectRatio(Split::DEFAULT_ASPECT_RATIO)),
+            ("aspectratio", Some(ratio)) => parse_aspect_ratio(ratio).map(Split::AspectRatio),
+            ("fixedgrid", Some(grid)) => parse_fixed_grid(grid).map(Split::FixedGrid),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Parse the cells of a fixed grid written as `rowsxcolumns` (eg. `2x3`),
+/// optionally followed by the names of its [`GridFill`] and [`GridOverflow`].
+fn parse_fixed_grid(s: &str) -> Result<FixedGrid, ()> {
+    let mut parts = s.split(',').map(str::trim);
+    let (rows, columns) = parts
+        .next()
+        .and_then(|cells| cells.split_once('x'))
+        .ok_or(())?;
+    let mut grid = FixedGrid {
+        rows: rows.trim().parse().map_err(|_| ())?,
+        columns: columns.trim().parse().map_err(|_| ())?,
+        ..Default::default()
+    };
+    for part in parts {
+        match part.to_ascii_lowercase().as_str() {
+            "rowbyrow" => grid.fill = GridFill::RowByRow,
+            "columnbycolumn" => grid.fill = GridFill::ColumnByColumn,
+            "sharelastcell" => grid.overflow = GridOverflow::ShareLastCell,
+            "hide" => grid.overflow = GridOverflow::Hide,
+            _ => return Err(()),
+        }
+    }
+    Ok(grid)
+}
+
+/// Parse an aspect ratio written as a number (eg. `1.5`) or as `width:height` (eg. `16:9`).
+fn parse_aspect_ratio(s: &str) -> Result<f32, ()> {
+    let ratio = match s.split_once(':') {
+        Some((width, height)) => {
+            let width: f32 = width.trim().parse().map_err(|_| ())?;
+            let height: f32 = height.trim().parse().map_err(|_| ())?;
+            width / height
+        }
+        None => s.parse().map_err(|_| ())?,
+    };
+    if ratio.is_finite() && ratio > 0.0 {
+        Ok(ratio)
+    } else {
+        Err(())
+    }
 }
 
 pub fn vertical(rect: &Rect, amount: usize) -> Vec<Rect> {
-    let mut from_left = rect.x;
-    remainderless_division(rect.w as usize, amount)
-        .iter()
-        .map(|width| {
-            let rect = Rect::new(from_left, rect.y, *width as u32, rect.h);
-            from_left += *width as i32;
-            rect
-        })
-        .collect()
+    even(rect, amount, Axis::X, Remainder::First)
 }
 
 pub fn horizontal(rect: &Rect, amount: usize) -> Vec<Rect> {
-    let mut from_top = rect.y;
-    remainderless_division(rect.h as usize, amount)
+    even(rect, amount, Axis::Y, Remainder::First)
+}
+
+pub fn auto(rect: &Rect, amount: usize, remainder: Remainder) -> Vec<Rect> {
+    even(rect, amount, auto_axis(rect), remainder)
+}
+
+/// The [`Axis`] a [`Split::Auto`] divides the provided [`Rect`] along,
+/// which is its longer side.
+pub fn auto_axis(rect: &Rect) -> Axis {
+    if rect.w > rect.h {
+        Axis::X
+    } else {
+        Axis::Y
+    }
+}
+
+pub fn weighted(rect: &Rect, weights: &[f32], axis: Axis) -> Vec<Rect> {
+    let lengths = weighted_division(axis.length(rect) as usize, weights);
+    consecutive(rect, &lengths, axis)
+}
+
+/// Divide the provided [`Rect`] along the provided [`Axis`]
+/// into `amount` parts of (nearly) the same length,
+/// the [`Remainder`] decides which parts are longer.
+pub fn even(rect: &Rect, amount: usize, axis: Axis, remainder: Remainder) -> Vec<Rect> {
+    let lengths = remainderless_division_with(axis.length(rect) as usize, amount, remainder);
+    consecutive(rect, &lengths, axis)
+}
+
+/// Divide the provided [`Rect`] along the provided [`Axis`]
+/// into consecutive parts of the provided `lengths`.
+fn consecutive(rect: &Rect, lengths: &[usize], axis: Axis) -> Vec<Rect> {
+    let mut from = axis.start(rect);
+    lengths
         .iter()
-        .map(|height| {
-            let rect = Rect::new(rect.x, from_top, rect.w, *height as u32);
-            from_top += *height as i32;
-            rect
+        .map(|length| {
+            let tile = axis.with_span(rect, from, *length as u32);
+            from += *length as i32;
+            tile
         })
         .collect()
 }
 
-pub fn grid(rect: &Rect, amount: usize) -> Vec<Rect> {
-    let cols = (amount as f64).sqrt().ceil() as usize;
-    let col_tiles = vertical(rect, cols);
-    // the minimum amount of rows per column
-    let min_rows = (amount as f64 / cols as f64).floor() as usize;
-    // the amount of columns in which there are only the minimum amount of rows
-    let min_row_amount = col_tiles.len() - divrem(amount, cols).1;
+pub fn grid(rect: &Rect, amount: usize, remainder: Remainder) -> Vec<Rect> {
+    grid_along(rect, amount, Axis::X, remainder)
+}
 
-    col_tiles
+pub fn row_grid(rect: &Rect, amount: usize, remainder: Remainder) -> Vec<Rect> {
+    grid_along(rect, amount, Axis::Y, remainder)
+}
+
+/// Divide the provided [`Rect`] along the provided [`Axis`] into lines
+/// (ie. columns along [`Axis::X`], rows along [`Axis::Y`]) and each line
+/// along the other [`Axis`], resulting in a (nearly) square grid.
+/// The last lines get the additional tiles if the grid is not full.
+fn grid_along(rect: &Rect, amount: usize, axis: Axis, remainder: Remainder) -> Vec<Rect> {
+    // the square root, rounded up
+    let lines = match amount.isqrt() {
+        root if root * root < amount => root + 1,
+        root => root,
+    };
+    let line_tiles = even(rect, lines, axis, remainder);
+    // the minimum amount of tiles per line
+    let min_tiles = amount.checked_div(lines).unwrap_or(0);
+    // the amount of lines in which there are only the minimum amount of tiles
+    let min_tile_amount = line_tiles.len() - divrem(amount, lines).1;
+
+    line_tiles
         .iter()
         .enumerate()
-        .flat_map(|(i, col_tile)| {
-            let rows = if i < min_row_amount {
-                min_rows
+        .flat_map(|(i, line_tile)| {
+            let tiles = if i < min_tile_amount {
+                min_tiles
             } else {
-                min_rows + 1
+                min_tiles + 1
             };
-            horizontal(col_tile, rows)
+            even(line_tile, tiles, axis.other(), remainder)
         })
         .collect()
 }
 
-pub fn fibonacci(rect: &Rect, amount: usize) -> Vec<Rect> {
-    let tiles: &mut Vec<Rect> = &mut Vec::new();
-    let mut remaining_tile = *rect;
-    let mut direction = Rotation::East;
-    for i in 0..amount {
-        let has_next = i < amount - 1;
-        direction = direction.clockwise();
-        if has_next {
-            let split_axis = match direction {
-                Rotation::North | Rotation::South => Split::Horizontal,
-                Rotation::East | Rotation::West => Split::Vertical,
-            };
-            let backwards = match direction {
-                Rotation::East | Rotation::South => false,
-                Rotation::West | Rotation::North => true,
-            };
-            let splitted_tiles = split(&remaining_tile, 2, Some(split_axis));
-            if backwards {
-                tiles.push(splitted_tiles[1]);
-                remaining_tile = splitted_tiles[0];
-            } else {
-                tiles.push(splitted_tiles[0]);
-                remaining_tile = splitted_tiles[1];
-            }
+pub fn fibonacci(rect: &Rect, amount: usize, spiral: Spiral) -> Vec<Rect> {
+    split_in_steps(rect, amount, spiral, fibonacci_step)
+}
+
+pub fn dwindle(rect: &Rect, amount: usize, spiral: Spiral) -> Vec<Rect> {
+    split_in_steps(rect, amount, spiral, dwindle_step)
+}
+
+/// Splits off the tile of the `i`-th window from the remaining tile,
+/// see [`fibonacci_step`] and [`dwindle_step`].
+pub(crate) type SplitStep = fn(&Rect, usize, Spiral) -> (Rect, Rect);
+
+/// Split the `remaining` tile of a fibonacci split for the `i`-th window.
+/// Returns the tile of the `i`-th window and the tile remaining for the following windows.
+pub(crate) fn fibonacci_step(remaining: &Rect, i: usize, spiral: Spiral) -> (Rect, Rect) {
+    let start = if spiral.horizontal_first {
+        Rotation::South
+    } else {
+        Rotation::East
+    };
+    // the direction turns with every window
+    let direction = (0..i % 4).fold(start, |direction, _| {
+        if spiral.clockwise {
+            direction.clockwise()
         } else {
-            tiles.push(remaining_tile);
+            direction.counter_clockwise()
         }
+    });
+    let split_axis = match direction {
+        Rotation::North | Rotation::South => Split::Horizontal,
+        Rotation::East | Rotation::West => Split::Vertical,
+    };
+    let backwards = match direction {
+        Rotation::East | Rotation::South => false,
+        Rotation::West | Rotation::North => true,
+    };
+    let splitted_tiles = split(remaining, 2, Some(split_axis));
+    if backwards {
+        (splitted_tiles[1], splitted_tiles[0])
+    } else {
+        (splitted_tiles[0], splitted_tiles[1])
     }
-    tiles.clone()
 }
 
-pub fn dwindle(rect: &Rect, amount: usize) -> Vec<Rect> {
-    let tiles: &mut Vec<Rect> = &mut Vec::new();
+/// Split the `remaining` tile of a dwindle split for the `i`-th window.
+/// Returns the tile of the `i`-th window and the tile remaining for the following windows.
+pub(crate) fn dwindle_step(remaining: &Rect, i: usize, spiral: Spiral) -> (Rect, Rect) {
+    let split_axis = if (i % 2 == 0) == spiral.horizontal_first {
+        Split::Horizontal
+    } else {
+        Split::Vertical
+    };
+    let splitted_tiles = split(remaining, 2, Some(split_axis));
+    (splitted_tiles[0], splitted_tiles[1])
+}
+
+fn split_in_steps(rect: &Rect, amount: usize, spiral: Spiral, step: SplitStep) -> Vec<Rect> {
+    let mut tiles = Vec::with_capacity(amount);
     let mut remaining_tile = *rect;
-    let mut last_axis = Split::Vertical;
-    for i in 0..amount {
-        let has_next = i < amount - 1;
-        last_axis = if last_axis == Split::Vertical {
-            Split::Horizontal
-        } else {
-            Split::Vertical
+    for i in 0..amount.saturating_sub(1) {
+        let (tile, rest) = step(&remaining_tile, i, spiral);
+        tiles.push(tile);
+        remaining_tile = rest;
+    }
+    if amount > 0 {
+        tiles.push(remaining_tile);
+    }
+    tiles
+}
+
+pub fn binary_tree(rect: &Rect, amount: usize, ratio: f32) -> Vec<Rect> {
+    let ratio = if ratio.is_finite() {
+        ratio.abs().min(1.0)
+    } else {
+        0.5
+    };
+    let mut tiles = vec![*rect];
+    // largest tile first, the earlier tile wins on a tie
+    let mut heap = BinaryHeap::new();
+    heap.push((u64::from(rect.w) * u64::from(rect.h), Reverse(0)));
+    while tiles.len() < amount {
+        let Some((_, Reverse(index))) = heap.pop() else {
+            break;
         };
-        if has_next {
-            let splitted_tiles = split(&remaining_tile, 2, Some(last_axis));
-            tiles.push(splitted_tiles[0]);
-            remaining_tile = splitted_tiles[1];
+        let tile = tiles[index];
+        let (kept, new) = if tile.w >= tile.h {
+            let w = Size::Ratio(ratio).into_absolute(tile.w) as u32;
+            (
+                Rect { w, ..tile },
+                Rect::new(tile.x + w as i32, tile.y, tile.w - w, tile.h),
+            )
         } else {
-            tiles.push(remaining_tile);
+            let h = Size::Ratio(ratio).into_absolute(tile.h) as u32;
+            (
+                Rect { h, ..tile },
+                Rect::new(tile.x, tile.y + h as i32, tile.w, tile.h - h),
+            )
+        };
+        tiles[index] = kept;
+        tiles.push(new);
+        heap.push((u64::from(kept.w) * u64::from(kept.h), Reverse(index)));
+        heap.push((
+            u64::from(new.w) * u64::from(new.h),
+            Reverse(tiles.len() - 1),
+        ));
+    }
+    tiles
+}
+
+pub fn columns(rect: &Rect, amount: usize, count: usize, remainder: Remainder) -> Vec<Rect> {
+    lines(rect, amount, count, Axis::X, remainder)
+}
+
+pub fn rows(rect: &Rect, amount: usize, count: usize, remainder: Remainder) -> Vec<Rect> {
+    lines(rect, amount, count, Axis::Y, remainder)
+}
+
+/// Divide the provided [`Rect`] along the provided [`Axis`] into `count` lines
+/// and distribute the `amount` of tiles evenly among them, earlier lines
+/// get the additional tiles.
+fn lines(rect: &Rect, amount: usize, count: usize, axis: Axis, remainder: Remainder) -> Vec<Rect> {
+    if amount == 0 {
+        return vec![];
+    }
+    let count = count.clamp(1, amount);
+    even(rect, count, axis, remainder)
+        .iter()
+        .zip(remainderless_division(amount, count))
+        .flat_map(|(line, tiles)| even(line, tiles, axis.other(), remainder))
+        .collect()
+}
+
+/// Divide the provided [`Rect`] into the cells of the provided [`FixedGrid`]
+/// and assign them to the tiles in the order of its [`GridFill`].
+/// If there are more tiles than cells, the [`GridOverflow`] decides what happens to them.
+pub fn fixed_grid(rect: &Rect, amount: usize, grid: FixedGrid, remainder: Remainder) -> Vec<Rect> {
+    let (lines, cells, axis) = match grid.fill {
+        GridFill::RowByRow => (grid.rows, grid.columns, Axis::Y),
+        GridFill::ColumnByColumn => (grid.columns, grid.rows, Axis::X),
+    };
+    let mut tiles: Vec<Rect> = even(rect, lines.max(1), axis, remainder)
+        .iter()
+        .flat_map(|line| even(line, cells.max(1), axis.other(), remainder))
+        .take(amount)
+        .collect();
+    if grid.overflow == GridOverflow::ShareLastCell {
+        if let Some(last) = tiles.last().copied() {
+            tiles.resize(amount, last);
+        }
+    }
+    tiles
+}
+
+/// The precision of the target ratio of [`aspect_ratio`], which is compared
+/// to the tiles as a fraction of this denominator.
+const ASPECT_RATIO_DENOMINATOR: u32 = 10_000;
+
+/// Divide the provided [`Rect`] into lines (see [`columns`] and [`rows`]),
+/// choosing the amount and direction of the lines whose worst tile is the
+/// closest to the provided `ratio` (width / height).
+/// Fewer lines are preferred if multiple choices are equally close, rows before columns.
+///
+/// Only the amounts of lines around the ideal amount are tried,
+/// keeping the split linear in the amount of tiles.
+pub fn aspect_ratio(rect: &Rect, amount: usize, ratio: f32, remainder: Remainder) -> Vec<Rect> {
+    if amount == 0 {
+        return vec![];
+    }
+    // the ratio as a fraction, so that tiles are compared without floating point math
+    let target = (
+        scale(ASPECT_RATIO_DENOMINATOR, ratio).max(1) as u128,
+        u128::from(ASPECT_RATIO_DENOMINATOR),
+    );
+    let (w, h, n) = (u128::from(rect.w), u128::from(rect.h), amount as u128);
+    // with `r` rows, tiles are about `w * r / n` wide and `h / r` high, the other way round for columns
+    let ideal_rows = (target.0 * n * h).checked_div(target.1 * w).unwrap_or(0);
+    let ideal_columns = (target.1 * n * w).checked_div(target.0 * h).unwrap_or(0);
+    let candidates = |ideal: u128| {
+        let lines = usize::try_from(ideal.isqrt())
+            .unwrap_or(usize::MAX)
+            .clamp(1, amount);
+        // as the tiles are distributed unevenly, the amounts of lines
+        // that evenly fit the tiles per line around the ideal are tried too
+        let tiles = amount / lines;
+        let mut candidates: Vec<usize> = (lines.saturating_sub(1)..=lines.saturating_add(2))
+            .chain((tiles.saturating_sub(1)..=tiles + 2).flat_map(|tiles| {
+                let lines = amount.checked_div(tiles).unwrap_or(0);
+                [lines, lines + usize::from(lines * tiles < amount)]
+            }))
+            .filter(|lines| (1..=amount).contains(lines))
+            .collect();
+        candidates.sort_unstable();
+        candidates.dedup();
+        candidates
+    };
+    [(Axis::Y, ideal_rows), (Axis::X, ideal_columns)]
+        .into_iter()
+        .flat_map(|(axis, ideal)| {
+            candidates(ideal)
+                .into_iter()
+                .map(move |count| lines(rect, amount, count, axis, remainder))
+        })
+        .map(|tiles| (worst_deviation(&tiles, target), tiles))
+        .min_by(|(a, _), (b, _)| (a.0 * b.1).cmp(&(b.0 * a.1)))
+        .map(|(_, tiles)| tiles)
+        .unwrap_or_default()
+}
+
+/// How far the ratio of the worst of the `tiles` deviates from the `target` ratio,
+/// as a fraction greater than or equal to one. Empty tiles deviate infinitely (`1 / 0`).
+fn worst_deviation(tiles: &[Rect], target: (u128, u128)) -> (u128, u128) {
+    tiles
+        .iter()
+        .map(|tile| {
+            let width = u128::from(tile.w) * target.1;
+            let height = u128::from(tile.h) * target.0;
+            match (width.max(height), width.min(height)) {
+                (_, 0) => (1, 0),
+                deviation => deviation,
+            }
+        })
+        .max_by(|a, b| (a.0 * b.1).cmp(&(b.0 * a.1)))
+        .unwrap_or((1, 1))
+}
+
+pub fn tatami(rect: &Rect, amount: usize) -> Vec<Rect> {
+    if amount > 5 {
+        let groups = amount.div_ceil(5);
+        return vertical(rect, groups)
+            .iter()
+            .zip(remainderless_division(amount, groups))
+            .flat_map(|(column, amount)| tatami(column, amount))
+            .collect();
+    }
+
+    let x = remainderless_division(rect.w as usize, 3);
+    let y = remainderless_division(rect.h as usize, 3);
+    let (x1, x2) = (x[0] as u32, x[1] as u32);
+    let (y1, y2) = (y[0] as u32, y[1] as u32);
+    match amount {
+        0 => vec![],
+        1 => vec![*rect],
+        2 => vertical(rect, 2),
+        3 => {
+            let columns = vertical(rect, 2);
+            let mut tiles = vec![columns[0]];
+            tiles.append(&mut horizontal(&columns[1], 2));
+            tiles
+        }
+        4 => {
+            let rest = Rect::new(rect.x + x1 as i32, rect.y, rect.w - x1, rect.h);
+            let rows = horizontal(&rest, 2);
+            let mut tiles = vec![Rect { w: x1, ..*rect }, rows[0]];
+            tiles.append(&mut vertical(&rows[1], 2));
+            tiles
+        }
+        _ => {
+            let (left, top) = (rect.x, rect.y);
+            vec![
+                Rect::new(left, top, x1 + x2, y1),
+                Rect::new(left + (x1 + x2) as i32, top, rect.w - x1 - x2, y1 + y2),
+                Rect::new(
+                    left + x1 as i32,
+                    top + (y1 + y2) as i32,
+                    rect.w - x1,
+                    rect.h - y1 - y2,
+                ),
+                Rect::new(left, top + y1 as i32, x1, rect.h - y1),
+                Rect::new(left + x1 as i32, top + y1 as i32, x2, y2),
+            ]
         }
     }
-    tiles.clone()
+}
+
+pub fn horizontal_accordion(rect: &Rect, amount: usize) -> Vec<Rect> {
+    accordion(rect, amount, Axis::Y)
+}
+
+pub fn vertical_accordion(rect: &Rect, amount: usize) -> Vec<Rect> {
+    accordion(rect, amount, Axis::X)
+}
+
+/// Fold `amount` overlapping tiles along the provided [`Axis`],
+/// leaving a strip of each tile visible.
+fn accordion(rect: &Rect, amount: usize, axis: Axis) -> Vec<Rect> {
+    let (strip, length) = accordion_strip(axis.length(rect), amount);
+    let start = axis.start(rect);
+    (0..amount)
+        .map(|i| axis.with_span(rect, start + (i as u32 * strip) as i32, length))
+        .collect()
+}
+
+/// The size of the visible strip of each accordion tile,
+/// and the size of the tiles themselves along the folded side.
+fn accordion_strip(length: u32, amount: usize) -> (u32, u32) {
+    if amount < 2 {
+        return (0, length);
+    }
+    let folds = amount as u32 - 1;
+    let strip = length / (2 * folds);
+    (strip, length - folds * strip)
 }
 
 #[cfg(test)]
 mod tests {
     use crate::geometry::{
-        split::{dwindle, fibonacci, grid, horizontal, vertical},
-        Rect,
+        check_tiling,
+        split::{
+            aspect_ratio, auto, binary_tree, columns, dwindle, fibonacci, fixed_grid, grid,
+            horizontal, horizontal_accordion, row_grid, rows, tatami, vertical, vertical_accordion,
+        },
+        FixedGrid, GridFill, GridOverflow, Rect, Remainder, Spiral, Split,
     };
 
     const CONTAINER: Rect = Rect {
@@ -239,7 +926,7 @@ mod tests {
 
     #[test]
     fn split_grid_three_windows() {
-        let rects = grid(&CONTAINER, 3);
+        let rects = grid(&CONTAINER, 3, Remainder::First);
         assert_eq!(rects.len(), 3);
         let expected_first = Rect::new(0, 0, 200, 200);
         let expected_second = Rect::new(200, 0, 200, 100);
@@ -251,7 +938,7 @@ mod tests {
 
     #[test]
     fn split_grid_four_windows() {
-        let rects = grid(&CONTAINER, 4);
+        let rects = grid(&CONTAINER, 4, Remainder::First);
         assert_eq!(rects.len(), 4);
         let expected_first = Rect::new(0, 0, 200, 100);
         let expected_second = Rect::new(0, 100, 200, 100);
@@ -263,9 +950,35 @@ mod tests {
         assert!(rects[3].eq(&expected_fourth));
     }
 
+    #[test]
+    fn split_row_grid_three_windows() {
+        let rects = row_grid(&CONTAINER, 3, Remainder::First);
+        assert_eq!(rects.len(), 3);
+        let expected_first = Rect::new(0, 0, 400, 100);
+        let expected_second = Rect::new(0, 100, 200, 100);
+        let expected_third = Rect::new(200, 100, 200, 100);
+        assert!(rects[0].eq(&expected_first));
+        assert!(rects[1].eq(&expected_second));
+        assert!(rects[2].eq(&expected_third));
+    }
+
+    #[test]
+    fn split_row_grid_four_windows() {
+        let rects = row_grid(&CONTAINER, 4, Remainder::First);
+        assert_eq!(rects.len(), 4);
+        let expected_first = Rect::new(0, 0, 200, 100);
+        let expected_second = Rect::new(200, 0, 200, 100);
+        let expected_third = Rect::new(0, 100, 200, 100);
+        let expected_fourth = Rect::new(200, 100, 200, 100);
+        assert!(rects[0].eq(&expected_first));
+        assert!(rects[1].eq(&expected_second));
+        assert!(rects[2].eq(&expected_third));
+        assert!(rects[3].eq(&expected_fourth));
+    }
+
     #[test]
     fn split_fibonacci_four_windows() {
-        let rects = fibonacci(&CONTAINER, 4);
+        let rects = fibonacci(&CONTAINER, 4, Spiral::default());
         assert_eq!(rects.len(), 4);
         let expected_first = Rect::new(0, 0, 400, 100);
         let expected_second = Rect::new(200, 100, 200, 100);
@@ -279,7 +992,7 @@ mod tests {
 
     #[test]
     fn split_fibonacci_five_windows() {
-        let rects = fibonacci(&CONTAINER, 5);
+        let rects = fibonacci(&CONTAINER, 5, Spiral::default());
         assert_eq!(rects.len(), 5);
         let expected_first = Rect::new(0, 0, 400, 100);
         let expected_second = Rect::new(200, 100, 200, 100);
@@ -295,7 +1008,7 @@ mod tests {
 
     #[test]
     fn split_dwindle_four_windows() {
-        let rects = dwindle(&CONTAINER, 4);
+        let rects = dwindle(&CONTAINER, 4, Spiral::default());
         assert_eq!(rects.len(), 4);
         let expected_first = Rect::new(0, 0, 400, 100);
         let expected_second = Rect::new(0, 100, 200, 100);
@@ -309,7 +1022,7 @@ mod tests {
 
     #[test]
     fn split_dwindle_five_windows() {
-        let rects = dwindle(&CONTAINER, 5);
+        let rects = dwindle(&CONTAINER, 5, Spiral::default());
         assert_eq!(rects.len(), 5);
         let expected_first = Rect::new(0, 0, 400, 100);
         let expected_second = Rect::new(0, 100, 200, 100);
@@ -322,4 +1035,435 @@ mod tests {
         assert!(rects[3].eq(&expected_fourth));
         assert!(rects[4].eq(&expected_fifth));
     }
+
+    #[test]
+    fn split_binary_tree_four_windows() {
+        let rects = binary_tree(&CONTAINER, 4, 0.5);
+        assert_eq!(
+            vec![
+                Rect::new(0, 0, 100, 200),
+                Rect::new(200, 0, 100, 200),
+                Rect::new(100, 0, 100, 200),
+                Rect::new(300, 0, 100, 200),
+            ],
+            rects
+        );
+    }
+
+    #[test]
+    fn split_binary_tree_keeps_ratio_on_split_tile() {
+        let rects = binary_tree(&CONTAINER, 3, 0.75);
+        assert_eq!(
+            vec![
+                Rect::new(0, 0, 225, 200),
+                Rect::new(300, 0, 100, 200),
+                Rect::new(225, 0, 75, 200),
+            ],
+            rects
+        );
+    }
+
+    #[test]
+    fn split_binary_tree_single_window() {
+        let rects = binary_tree(&CONTAINER, 1, 0.5);
+        assert_eq!(vec![CONTAINER], rects);
+    }
+
+    #[test]
+    fn split_columns_stacks_overflow_vertically() {
+        let rects = columns(&CONTAINER, 5, 3, Remainder::First);
+        assert_eq!(
+            vec![
+                Rect::new(0, 0, 134, 100),
+                Rect::new(0, 100, 134, 100),
+                Rect::new(134, 0, 133, 100),
+                Rect::new(134, 100, 133, 100),
+                Rect::new(267, 0, 133, 200),
+            ],
+            rects
+        );
+    }
+
+    #[test]
+    fn split_columns_with_fewer_windows_than_columns() {
+        let rects = columns(&CONTAINER, 2, 3, Remainder::First);
+        assert_eq!(
+            vec![Rect::new(0, 0, 200, 200), Rect::new(200, 0, 200, 200)],
+            rects
+        );
+    }
+
+    #[test]
+    fn split_rows_fills_rows_side_by_side() {
+        let rects = rows(&CONTAINER, 3, 2, Remainder::First);
+        assert_eq!(
+            vec![
+                Rect::new(0, 0, 200, 100),
+                Rect::new(200, 0, 200, 100),
+                Rect::new(0, 100, 400, 100),
+            ],
+            rects
+        );
+    }
+
+    #[test]
+    fn split_columns_and_rows_without_windows() {
+        assert!(columns(&CONTAINER, 0, 3, Remainder::First).is_empty());
+        assert!(rows(&CONTAINER, 0, 0, Remainder::First).is_empty());
+    }
+
+    #[test]
+    fn split_tatami_five_windows_spiral_around_center() {
+        let container = Rect::new(0, 0, 300, 300);
+        let rects = tatami(&container, 5);
+        assert_eq!(
+            vec![
+                Rect::new(0, 0, 200, 100),
+                Rect::new(200, 0, 100, 200),
+                Rect::new(100, 200, 200, 100),
+                Rect::new(0, 100, 100, 200),
+                Rect::new(100, 100, 100, 100),
+            ],
+            rects
+        );
+    }
+
+    #[test]
+    fn split_tatami_four_windows() {
+        let container = Rect::new(0, 0, 300, 300);
+        let rects = tatami(&container, 4);
+        assert_eq!(
+            vec![
+                Rect::new(0, 0, 100, 300),
+                Rect::new(100, 0, 200, 150),
+                Rect::new(100, 150, 100, 150),
+                Rect::new(200, 150, 100, 150),
+            ],
+            rects
+        );
+    }
+
+    #[test]
+    fn split_tatami_covers_container_for_many_windows() {
+        for amount in 0..=23 {
+            let rects = tatami(&CONTAINER, amount);
+            assert_eq!(amount, rects.len());
+            let area: u32 = rects.iter().map(|r| r.surface_area()).sum();
+            assert_eq!(
+                if amount > 0 {
+                    CONTAINER.surface_area()
+                } else {
+                    0
+                },
+                area
+            );
+            assert!(check_tiling(&rects, &CONTAINER, false).is_empty());
+        }
+    }
+
+    #[test]
+    fn split_horizontal_accordion_leaves_strips_visible() {
+        let rects = horizontal_accordion(&CONTAINER, 3);
+        assert_eq!(
+            vec![
+                Rect::new(0, 0, 400, 100),
+                Rect::new(0, 50, 400, 100),
+                Rect::new(0, 100, 400, 100),
+            ],
+            rects
+        );
+    }
+
+    #[test]
+    fn split_vertical_accordion_covers_whole_rect() {
+        let rects = vertical_accordion(&CONTAINER, 4);
+        assert_eq!(
+            vec![
+                Rect::new(0, 0, 202, 200),
+                Rect::new(66, 0, 202, 200),
+                Rect::new(132, 0, 202, 200),
+                Rect::new(198, 0, 202, 200),
+            ],
+            rects
+        );
+    }
+
+    #[test]
+    fn split_accordion_single_window() {
+        assert_eq!(vec![CONTAINER], vertical_accordion(&CONTAINER, 1));
+        assert!(horizontal_accordion(&CONTAINER, 0).is_empty());
+    }
+
+    #[test]
+    fn split_fibonacci_counter_clockwise_mirrors_spiral() {
+        let spiral = Spiral {
+            clockwise: false,
+            ..Default::default()
+        };
+        let rects = fibonacci(&CONTAINER, 4, spiral);
+        assert_eq!(
+            vec![
+                Rect::new(0, 0, 400, 100),
+                Rect::new(0, 100, 200, 100),
+                Rect::new(200, 150, 200, 50),
+                Rect::new(200, 100, 200, 50),
+            ],
+            rects
+        );
+    }
+
+    #[test]
+    fn split_auto_cuts_along_the_longer_side() {
+        assert_eq!(
+            vertical(&CONTAINER, 3),
+            auto(&CONTAINER, 3, Remainder::First)
+        );
+        let portrait = Rect::new(0, 0, 200, 400);
+        assert_eq!(
+            horizontal(&portrait, 3),
+            auto(&portrait, 3, Remainder::First)
+        );
+    }
+
+    #[test]
+    fn split_fibonacci_and_dwindle_vertical_first() {
+        let spiral = Spiral {
+            horizontal_first: false,
+            ..Default::default()
+        };
+        let expected = vec![
+            Rect::new(0, 0, 200, 200),
+            Rect::new(200, 0, 200, 100),
+            Rect::new(200, 100, 200, 100),
+        ];
+        assert_eq!(expected, fibonacci(&CONTAINER, 3, spiral));
+        assert_eq!(expected, dwindle(&CONTAINER, 3, spiral));
+    }
+
+    #[test]
+    fn split_display_round_trips() {
+        let splits = [
+            Split::Horizontal,
+            Split::Vertical,
+            Split::Grid,
+            Split::RowGrid,
+            Split::Fibonacci,
+            Split::Dwindle,
+            Split::BinaryTree(0.6),
+            Split::Columns(3),
+            Split::Rows(2),
+            Split::Tatami,
+            Split::HorizontalAccordion,
+            Split::VerticalAccordion,
+            Split::Auto,
+            Split::AspectRatio(1.5),
+            Split::FixedGrid(FixedGrid::default()),
+            Split::FixedGrid(FixedGrid {
+                rows: 1,
+                columns: 4,
+                fill: GridFill::ColumnByColumn,
+                overflow: GridOverflow::Hide,
+            }),
+        ];
+        for split in splits {
+            assert_eq!(Ok(split), split.to_string().parse());
+        }
+    }
+
+    #[test]
+    fn split_from_str_ignores_case_and_whitespace() {
+        assert_eq!(Ok(Split::Dwindle), "dwindle".parse());
+        assert_eq!(Ok(Split::RowGrid), "ROWGRID".parse());
+        assert_eq!(Ok(Split::Columns(4)), " columns( 4 ) ".parse());
+        assert_eq!(Ok(Split::BinaryTree(0.5)), "BinaryTree(0.5)".parse());
+        assert_eq!(Ok(Split::AspectRatio(2.0)), "aspectratio(4 : 2)".parse());
+        assert_eq!(
+            Ok(Split::AspectRatio(Split::DEFAULT_ASPECT_RATIO)),
+            "AspectRatio".parse()
+        );
+    }
+
+    #[test]
+    fn splits_with_ratios_are_equal_to_themselves() {
+        for split in [Split::BinaryTree(f32::NAN), Split::AspectRatio(f32::NAN)] {
+            assert_eq!(split, split);
+        }
+        assert_eq!(Split::BinaryTree(0.6), Split::BinaryTree(0.600_001));
+        assert_ne!(Split::BinaryTree(0.6), Split::BinaryTree(0.61));
+        assert_ne!(Split::BinaryTree(0.6), Split::AspectRatio(0.6));
+        assert_ne!(Split::Columns(2), Split::Rows(2));
+        assert_ne!(Split::Grid, Split::RowGrid);
+    }
+
+    #[test]
+    fn split_from_str_rejects_invalid_input() {
+        for s in [
+            "",
+            "Spiral",
+            "Columns",
+            "Columns()",
+            "Columns(-1)",
+            "Grid(2)",
+            "Rows(2",
+            "AspectRatio(0)",
+            "AspectRatio(1:0)",
+        ] {
+            assert_eq!(Err(()), s.parse::<Split>(), "{s}");
+        }
+    }
+
+    #[test]
+    fn aspect_ratio_keeps_tiles_close_to_the_ratio() {
+        let wide = Rect::new(0, 0, 1920, 1080);
+        let tiles = aspect_ratio(&wide, 4, 16.0 / 9.0, Remainder::First);
+        assert_eq!(
+            vec![
+                Rect::new(0, 0, 960, 540),
+                Rect::new(960, 0, 960, 540),
+                Rect::new(0, 540, 960, 540),
+                Rect::new(960, 540, 960, 540),
+            ],
+            tiles
+        );
+
+        let tall = Rect::new(0, 0, 1080, 1920);
+        let tiles = aspect_ratio(&tall, 3, 16.0 / 9.0, Remainder::First);
+        assert_eq!(
+            vec![
+                Rect::new(0, 0, 1080, 640),
+                Rect::new(0, 640, 1080, 640),
+                Rect::new(0, 1280, 1080, 640),
+            ],
+            tiles
+        );
+
+        let square = aspect_ratio(&tall, 2, 1.0, Remainder::First);
+        assert_eq!(
+            vec![Rect::new(0, 0, 1080, 960), Rect::new(0, 960, 1080, 960)],
+            square
+        );
+    }
+
+    #[test]
+    fn aspect_ratio_tries_enough_amounts_of_lines() {
+        // the lines whose worst tile deviates the least of all amounts of lines
+        let best = |rect: &Rect, amount: usize, ratio: f32| {
+            let deviation = |tiles: &[Rect]| {
+                tiles
+                    .iter()
+                    .map(|t| (t.w as f32 / t.h as f32 / ratio).ln().abs())
+                    .fold(0.0, f32::max)
+            };
+            [rows, columns]
+                .iter()
+                .flat_map(|lines| {
+                    (1..=amount).map(move |count| lines(rect, amount, count, Remainder::First))
+                })
+                .map(|tiles| deviation(&tiles))
+                .fold(f32::INFINITY, f32::min)
+        };
+        let rects = [
+            Rect::new(0, 0, 1920, 1080),
+            Rect::new(0, 0, 1080, 1920),
+            Rect::new(0, 0, 5120, 1440),
+            Rect::new(0, 0, 333, 777),
+        ];
+        for rect in rects {
+            for ratio in [16.0 / 9.0, 1.0, 0.5, 4.0] {
+                for amount in 1..50 {
+                    let tiles = aspect_ratio(&rect, amount, ratio, Remainder::First);
+                    let deviation = tiles
+                        .iter()
+                        .map(|t| (t.w as f32 / t.h as f32 / ratio).ln().abs())
+                        .fold(0.0, f32::max);
+                    assert!(
+                        deviation <= best(&rect, amount, ratio) + 1e-4,
+                        "{rect:?} into {amount} with {ratio}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn aspect_ratio_satisfies_tiling_invariants() {
+        for amount in 0..20 {
+            let tiles = aspect_ratio(&CONTAINER, amount, 16.0 / 9.0, Remainder::First);
+            assert_eq!(amount, tiles.len());
+            assert!(check_tiling(&tiles, &CONTAINER, false).is_empty());
+        }
+        assert_eq!(
+            vec![CONTAINER],
+            aspect_ratio(&CONTAINER, 1, 0.0, Remainder::First)
+        );
+    }
+
+    #[test]
+    fn fixed_grid_fills_the_cells_in_order() {
+        let grid = FixedGrid {
+            rows: 2,
+            columns: 2,
+            ..Default::default()
+        };
+        let cells = [
+            Rect::new(0, 0, 200, 100),
+            Rect::new(200, 0, 200, 100),
+            Rect::new(0, 100, 200, 100),
+            Rect::new(200, 100, 200, 100),
+        ];
+        assert_eq!(
+            cells[..3].to_vec(),
+            fixed_grid(&CONTAINER, 3, grid, Remainder::First)
+        );
+        let by_column = FixedGrid {
+            fill: GridFill::ColumnByColumn,
+            ..grid
+        };
+        assert_eq!(
+            vec![cells[0], cells[2], cells[1]],
+            fixed_grid(&CONTAINER, 3, by_column, Remainder::First)
+        );
+    }
+
+    #[test]
+    fn fixed_grid_overflow_shares_the_last_cell_or_hides() {
+        let grid = FixedGrid {
+            rows: 1,
+            columns: 2,
+            ..Default::default()
+        };
+        let shared = fixed_grid(&CONTAINER, 4, grid, Remainder::First);
+        assert_eq!(4, shared.len());
+        assert!(shared[1..]
+            .iter()
+            .all(|tile| *tile == Rect::new(200, 0, 200, 200)));
+        assert!(Split::FixedGrid(grid).may_overlap());
+
+        let hide = FixedGrid {
+            overflow: GridOverflow::Hide,
+            ..grid
+        };
+        assert_eq!(2, fixed_grid(&CONTAINER, 4, hide, Remainder::First).len());
+        assert!(!Split::FixedGrid(hide).may_overlap());
+        assert!(fixed_grid(&CONTAINER, 0, grid, Remainder::First).is_empty());
+    }
+
+    #[test]
+    fn fixed_grid_parses_its_cells_and_options() {
+        assert_eq!(
+            Ok(Split::FixedGrid(FixedGrid {
+                rows: 2,
+                columns: 3,
+                fill: GridFill::ColumnByColumn,
+                overflow: GridOverflow::Hide,
+            })),
+            "fixedgrid(2x3, columnbycolumn, hide)".parse()
+        );
+        assert_eq!(
+            Ok(Split::FixedGrid(FixedGrid::default())),
+            "FixedGrid(2x2)".parse()
+        );
+        assert_eq!(Err(()), "FixedGrid(2)".parse::<Split>());
+        assert_eq!(Err(()), "FixedGrid(2x2, diagonal)".parse::<Split>());
+    }
 }
@@ -0,0 +1,187 @@
+use std::vec;
+
+use super::{divrem, split, Axis, Rect, Split};
+
+/// Same as [`split`], but returns an iterator over the tiles instead of a [`Vec`].
+///
+/// The tiles of [`Split::Vertical`], [`Split::Horizontal`], and [`Split::Grid`] are
+/// calculated one by one without allocating, which helps in hot paths like
+/// recalculating a layout on every frame of a resize. The tiles of all other
+/// splits are calculated by [`split`] up front.
+///
+/// ## Example
+/// ```rust
+/// use leftwm_layouts::geometry::{split, split_iter, Rect, Split};
+///
+/// let rect = Rect::new(0, 0, 400, 200);
+/// let widths: u32 = split_iter(&rect, 3, Some(Split::Vertical)).map(|tile| tile.w).sum();
+/// assert_eq!(400, widths);
+/// assert!(split_iter(&rect, 5, Some(Split::Grid)).eq(split(&rect, 5, Some(Split::Grid))));
+/// ```
+pub fn split_iter(rect: &Rect, amount: usize, axis: Option<Split>) -> SplitIter {
+    let tiles = match (amount, axis) {
+        (0, _) => Tiles::Even(Even::new(*rect, 0, Axis::X)),
+        (_, None) => Tiles::Even(Even::new(*rect, 1, Axis::X)),
+        (_, Some(Split::Vertical)) => Tiles::Even(Even::new(*rect, amount, Axis::X)),
+        (_, Some(Split::Horizontal)) => Tiles::Even(Even::new(*rect, amount, Axis::Y)),
+        (_, Some(Split::Grid)) => Tiles::Grid(Grid::new(*rect, amount, Axis::X)),
+        (_, split_axis) => Tiles::Collected(split(rect, amount, split_axis).into_iter()),
+    };
+    SplitIter { tiles }
+}
+
+/// Iterator over the tiles of a split [`Rect`], see [`split_iter`].
+#[derive(Debug, Clone)]
+pub struct SplitIter {
+    tiles: Tiles,
+}
+
+#[derive(Debug, Clone)]
+enum Tiles {
+    Even(Even),
+    Grid(Grid),
+    Collected(vec::IntoIter<Rect>),
+}
+
+impl Iterator for SplitIter {
+    type Item = Rect;
+
+    fn next(&mut self) -> Option<Rect> {
+        match &mut self.tiles {
+            Tiles::Even(even) => even.next(),
+            Tiles::Grid(grid) => grid.next(),
+            Tiles::Collected(tiles) => tiles.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = match &self.tiles {
+            Tiles::Even(even) => even.amount - even.index,
+            Tiles::Grid(grid) => grid.remaining,
+            Tiles::Collected(tiles) => tiles.len(),
+        };
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for SplitIter {}
+
+/// The tiles of a [`Rect`] divided into equal parts along an [`Axis`],
+/// the first parts get the remaining pixels (like [`Split::Vertical`]).
+#[derive(Debug, Clone)]
+struct Even {
+    rect: Rect,
+    axis: Axis,
+    amount: usize,
+    index: usize,
+    length: usize,
+    remainder: usize,
+}
+
+impl Even {
+    fn new(rect: Rect, amount: usize, axis: Axis) -> Self {
+        let (length, remainder) = match amount {
+            0 => (0, 0),
+            _ => divrem(axis.length(&rect) as usize, amount),
+        };
+        Self {
+            rect,
+            axis,
+            amount,
+            index: 0,
+            length,
+            remainder,
+        }
+    }
+}
+
+impl Iterator for Even {
+    type Item = Rect;
+
+    fn next(&mut self) -> Option<Rect> {
+        if self.index >= self.amount {
+            return None;
+        }
+        let i = self.index;
+        self.index += 1;
+        let start = i * self.length + usize::min(i, self.remainder);
+        let length = self.length + usize::from(i < self.remainder);
+        let from = self.axis.start(&self.rect) + start as i32;
+        Some(self.axis.with_span(&self.rect, from, length as u32))
+    }
+}
+
+/// The tiles of a [`Split::Grid`], line by line.
+#[derive(Debug, Clone)]
+struct Grid {
+    lines: Even,
+    line: Option<Even>,
+    min_tiles: usize,
+    min_tile_amount: usize,
+    remaining: usize,
+}
+
+impl Grid {
+    fn new(rect: Rect, amount: usize, axis: Axis) -> Self {
+        // the square root, rounded up
+        let lines = match amount.isqrt() {
+            root if root * root < amount => root + 1,
+            root => root,
+        };
+        let (min_tiles, remainder) = divrem(amount, lines);
+        Self {
+            lines: Even::new(rect, lines, axis),
+            line: None,
+            min_tiles,
+            min_tile_amount: lines - remainder,
+            remaining: amount,
+        }
+    }
+}
+
+impl Iterator for Grid {
+    type Item = Rect;
+
+    fn next(&mut self) -> Option<Rect> {
+        loop {
+            if let Some(tile) = self.line.as_mut().and_then(Iterator::next) {
+                self.remaining -= 1;
+                return Some(tile);
+            }
+            let index = self.lines.index;
+            let line = self.lines.next()?;
+            let tiles = self.min_tiles + usize::from(index >= self.min_tile_amount);
+            self.line = Some(Even::new(line, tiles, self.lines.axis.other()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::{split, split_iter, Rect, Split};
+
+    #[test]
+    fn split_iter_matches_split() {
+        let rects = [Rect::new(0, 0, 1920, 1080), Rect::new(13, -7, 401, 99)];
+        let splits = [
+            None,
+            Some(Split::Vertical),
+            Some(Split::Horizontal),
+            Some(Split::Grid),
+            Some(Split::Dwindle),
+        ];
+        for rect in rects {
+            for axis in splits {
+                for amount in 0..40 {
+                    let tiles = split_iter(&rect, amount, axis);
+                    assert_eq!(split(&rect, amount, axis).len(), tiles.len());
+                    assert_eq!(
+                        split(&rect, amount, axis),
+                        tiles.collect::<Vec<Rect>>(),
+                        "{axis:?} into {amount}"
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use std::cmp;
+
+/// Per-call adjustments to a layout, see [`crate::apply_with_hints`].
+///
+/// Other than the [`crate::Layout`] itself, hints are not meant to be persisted.
+/// They allow a window manager to temporarily alter the result of a layout,
+/// for example while the user is dragging a border.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Hints {
+    /// How the space and the windows shared by the two stacks of a layout with
+    /// a [`crate::layouts::SecondStack`] are divided between them, as the share
+    /// of the [`crate::layouts::Stack`] (from `0.0` to `1.0`, values outside are clamped).
+    ///
+    /// The windows are only divided accordingly if the stacks are balanced
+    /// (see [`crate::layouts::Columns::balance_stacks`]), otherwise the
+    /// [`crate::layouts::Stack`] holds a single window as usual.
+    /// If [`None`], the layout decides as usual (default: [`None`]).
+    pub stack_balance: Option<f32>,
+
+    /// The maximum amount of windows to calculate tiles for, the windows beyond it
+    /// get no tile and are meant to be hidden, like the windows a `Monocle` can't display.
+    /// This protects against pathological window counts, eg. an application
+    /// opening thousands of windows. If [`None`], there is no limit (default: [`None`]).
+    ///
+    /// ```rust
+    /// use leftwm_layouts::{apply_with_hints, geometry::Rect, layouts::Layouts, Hints};
+    ///
+    /// let layout = Layouts::default().get("MainAndVertStack").unwrap().clone();
+    /// let hints = Hints {
+    ///     hard_cap: Some(64),
+    ///     ..Default::default()
+    /// };
+    /// let rects = apply_with_hints(&layout, 10_000, &Rect::new(0, 0, 1920, 1080), &hints);
+    /// assert_eq!(64, rects.len());
+    /// ```
+    pub hard_cap: Option<usize>,
+}
+
+impl Hints {
+    /// The [`Hints::stack_balance`], clamped to a valid share.
+    pub(crate) fn stack_share(&self) -> Option<f32> {
+        self.stack_balance
+            .filter(|balance| balance.is_finite())
+            .map(|balance| balance.clamp(0.0, 1.0))
+    }
+
+    /// The provided amount of windows, limited to the [`Hints::hard_cap`].
+    pub(crate) fn capped(&self, window_count: usize) -> usize {
+        self.hard_cap
+            .map_or(window_count, |cap| cmp::min(cap, window_count))
+    }
+}
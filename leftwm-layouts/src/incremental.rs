@@ -0,0 +1,274 @@
+use std::ops::Range;
+
+use crate::{
+    apply,
+    geometry::{self, Flip, Gaps, Margin, Rect, Rotation, Split, SplitStep},
+    Layout,
+};
+
+/// Keeps the result of a previous [`apply`] around and updates it
+/// when a single window is added or removed, instead of calculating
+/// the whole layout from scratch.
+///
+/// For layouts whose stack is split by [`Split::Fibonacci`] or [`Split::Dwindle`]
+/// (without a second stack or second main column, a main size curve, a reversed
+/// stack, title bars, or any flip or rotation), an update only touches the last
+/// two tiles of the stack and therefore takes constant time, no matter how many
+/// windows there are. Every other layout, or a change to the amount of windows
+/// in the main column, falls back to a full recalculation.
+///
+/// ## Example
+/// ```rust
+/// use leftwm_layouts::{apply, geometry::Rect, layouts::Layouts, IncrementalApply};
+///
+/// let container = Rect::new(0, 0, 1920, 1080);
+/// let layout = Layouts::default().get("Fibonacci").unwrap().clone();
+/// let mut incremental = IncrementalApply::new(layout.clone(), 300, container);
+///
+/// // a window got added at index 42
+/// let changed = incremental.insert(42);
+/// assert_eq!(42..301, changed);
+/// assert_eq!(apply(&layout, 301, &container), incremental.tiles());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct IncrementalApply {
+    definition: Layout,
+    container: Rect,
+    window_count: usize,
+    tiles: Vec<Rect>,
+}
+
+impl IncrementalApply {
+    /// Create a new [`IncrementalApply`] by calculating the
+    /// layout for the provided `window_count` once.
+    pub fn new(definition: Layout, window_count: usize, container: Rect) -> Self {
+        let tiles = apply(&definition, window_count, &container);
+        Self {
+            definition,
+            container,
+            window_count,
+            tiles,
+        }
+    }
+
+    /// The tiles of the current window count, same as [`apply`] would return them.
+    pub fn tiles(&self) -> &[Rect] {
+        &self.tiles
+    }
+
+    /// The amount of windows the current tiles are calculated for.
+    pub fn window_count(&self) -> usize {
+        self.window_count
+    }
+
+    /// Update the tiles for a window that got added at `index`.
+    ///
+    /// Returns the range of window indices whose tile changed,
+    /// those windows need to be moved or resized.
+    /// An `index` past the end is treated like appending a window.
+    pub fn insert(&mut self, index: usize) -> Range<usize> {
+        let index = index.min(self.window_count);
+        self.window_count += 1;
+        let changed_from = match self.step() {
+            // the stack already exists, split its last tile
+            Some((step, main_tiles)) if self.tiles.len() > main_tiles => {
+                if let Some(remaining) = self.tiles.pop() {
+                    let spiral = self.definition.columns.stack.spiral;
+                    let (tile, rest) = step(&remaining, self.tiles.len() - main_tiles, spiral);
+                    self.tiles.push(tile);
+                    self.tiles.push(rest);
+                }
+                self.tiles.len() - 2
+            }
+            _ => self.recalculate(),
+        };
+        index.min(changed_from)..self.tiles.len()
+    }
+
+    /// Update the tiles for a window that got removed from `index`.
+    ///
+    /// Returns the range of window indices whose tile changed,
+    /// those windows need to be moved or resized.
+    /// Removing from an empty layout does nothing.
+    pub fn remove(&mut self, index: usize) -> Range<usize> {
+        if self.window_count == 0 {
+            return 0..0;
+        }
+        self.window_count -= 1;
+        let changed_from = match self.step() {
+            // the stack keeps existing, merge its last two tiles
+            Some((_, main_tiles)) if self.tiles.len() > main_tiles + 1 => {
+                if let (Some(last), Some(second_last)) = (self.tiles.pop(), self.tiles.pop()) {
+                    self.tiles.push(bounding_box(&second_last, &last));
+                }
+                self.tiles.len() - 1
+            }
+            _ => self.recalculate(),
+        };
+        index.min(changed_from)..self.tiles.len()
+    }
+
+    /// The step function to split off a single stack window
+    /// and the amount of main tiles in front of the stack,
+    /// if the layout can be updated incrementally.
+    fn step(&self) -> Option<(SplitStep, usize)> {
+        let definition = &self.definition;
+        let stack = &definition.columns.stack;
+        if !definition.columns.list.is_empty()
+            || definition.columns.effective_second_stack().is_some()
+            || definition.columns.second_main.is_some()
+            || definition.title_bar_height > 0
+            || definition.gaps.is_some_and(|gaps| gaps != Gaps::default())
+            || definition
+                .margin
+                .is_some_and(|margin| margin != Margin::default())
+            || definition.flip != Flip::None
+            || definition.rotate != Rotation::North
+            || stack.flip != Flip::None
+            || stack.rotate != Rotation::North
+            || stack.reverse
+            || stack.min_size.is_some()
+        {
+            return None;
+        }
+        // the stack column changes its size or its position with the window count
+        if let Some(main) = &definition.columns.main {
+            if !main.size_curve.is_empty() || main.count_ratio.is_some() {
+                return None;
+            }
+        }
+        // the main column holds fewer tiles than windows if it isn't split,
+        // the windows without a tile don't take any space of the stack
+        let main_tiles = definition.columns.main.as_ref().map_or(0, |main| {
+            let options = main.split_options(definition.remainder);
+            geometry::split_with(&self.container, main.count, main.split, &options).len()
+        });
+        match stack.split {
+            Some(Split::Fibonacci) => Some((geometry::fibonacci_step, main_tiles)),
+            Some(Split::Dwindle) => Some((geometry::dwindle_step, main_tiles)),
+            _ => None,
+        }
+    }
+
+    /// Calculate the whole layout from scratch and return
+    /// the index of the first tile that changed.
+    fn recalculate(&mut self) -> usize {
+        let tiles = apply(&self.definition, self.window_count, &self.container);
+        let changed_from = tiles
+            .iter()
+            .zip(&self.tiles)
+            .position(|(new, old)| new != old)
+            .unwrap_or_else(|| usize::min(tiles.len(), self.tiles.len()));
+        self.tiles = tiles;
+        changed_from
+    }
+}
+
+/// The smallest [`Rect`] containing both of the provided [`Rect`]s.
+fn bounding_box(a: &Rect, b: &Rect) -> Rect {
+    let x = i32::min(a.x, b.x);
+    let y = i32::min(a.y, b.y);
+    let right = i32::max(a.right_edge(), b.right_edge());
+    let bottom = i32::max(a.bottom_edge(), b.bottom_edge());
+    Rect::new(x, y, (right - x) as u32, (bottom - y) as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        apply,
+        geometry::{Rect, Reserve, Size},
+        layouts::Layouts,
+        IncrementalApply,
+    };
+
+    const CONTAINER: Rect = Rect {
+        x: 0,
+        y: 0,
+        w: 1920,
+        h: 1080,
+    };
+
+    #[test]
+    fn insert_and_remove_match_apply_for_all_default_layouts() {
+        for layout in Layouts::default() {
+            let mut incremental = IncrementalApply::new(layout.clone(), 0, CONTAINER);
+            for count in 1..=40 {
+                incremental.insert(count / 2);
+                assert_eq!(
+                    apply(&layout, count, &CONTAINER),
+                    incremental.tiles(),
+                    "{} after insert",
+                    layout.name
+                );
+            }
+            for count in (0..40).rev() {
+                incremental.remove(0);
+                assert_eq!(
+                    apply(&layout, count, &CONTAINER),
+                    incremental.tiles(),
+                    "{} after remove",
+                    layout.name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn stack_only_layouts_change_only_the_tail() {
+        let layout = Layouts::default().get("Dwindle").unwrap().clone();
+        let mut incremental = IncrementalApply::new(layout.clone(), 500, CONTAINER);
+        assert_eq!(499..501, incremental.insert(501));
+        assert_eq!(499..500, incremental.remove(500));
+        assert_eq!(10..499, incremental.remove(10));
+        assert_eq!(apply(&layout, 499, &CONTAINER), incremental.tiles());
+    }
+
+    #[test]
+    fn main_size_curve_is_respected() {
+        let mut layout = Layouts::default().get("Fibonacci").unwrap().clone();
+        if let Some(main) = layout.columns.main.as_mut() {
+            main.size_curve = vec![(3, Size::Ratio(0.7))];
+        }
+        let mut incremental = IncrementalApply::new(layout.clone(), 2, CONTAINER);
+        incremental.insert(2);
+        assert_eq!(apply(&layout, 3, &CONTAINER), incremental.tiles());
+    }
+
+    #[test]
+    fn main_without_split_holds_a_single_tile() {
+        for reserve in [Reserve::None, Reserve::Reserve, Reserve::ReserveAndCenter] {
+            let mut layout = Layouts::default().get("Fibonacci").unwrap().clone();
+            layout.reserve = reserve;
+            if let Some(main) = layout.columns.main.as_mut() {
+                main.count = 3;
+                main.split = None;
+            }
+            let mut incremental = IncrementalApply::new(layout.clone(), 0, CONTAINER);
+            for count in 1..=8 {
+                incremental.insert(count);
+                assert_eq!(
+                    apply(&layout, count, &CONTAINER),
+                    incremental.tiles(),
+                    "{reserve:?} after insert"
+                );
+            }
+            for count in (0..8).rev() {
+                incremental.remove(count);
+                assert_eq!(
+                    apply(&layout, count, &CONTAINER),
+                    incremental.tiles(),
+                    "{reserve:?} after remove"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn remove_from_empty_layout_does_nothing() {
+        let layout = Layouts::default().get("Fibonacci").unwrap().clone();
+        let mut incremental = IncrementalApply::new(layout, 0, CONTAINER);
+        assert_eq!(0..0, incremental.remove(0));
+        assert!(incremental.tiles().is_empty());
+    }
+}
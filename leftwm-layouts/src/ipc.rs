@@ -0,0 +1,172 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{apply_detailed, geometry::Rect, layouts::ColumnKind, Layout};
+
+/// Version of the [`Snapshot`] schema.
+/// It is increased on every change that isn't backwards compatible.
+///
+/// * `2`: Windows may have the [`ColumnKind::SecondMain`] role.
+pub const VERSION: u32 = 2;
+
+/// A compact, versioned snapshot of the result of a layout, meant to be
+/// serialized and sent to external tools (eg. bars or scripts rendering
+/// miniatures of a workspace).
+///
+/// Other than the rest of this crate, the schema of the snapshot is stable
+/// for a given [`VERSION`].
+///
+/// ## Example
+/// ```rust
+/// use leftwm_layouts::{geometry::Rect, ipc::{Snapshot, VERSION}, layouts::Layouts};
+///
+/// let layout = Layouts::default().get("Monocle").unwrap().clone();
+/// let snapshot = Snapshot::new(&layout, 2, &Rect::new(0, 0, 400, 200));
+/// assert_eq!(VERSION, snapshot.version);
+/// assert!(snapshot.windows[0].visible);
+/// assert!(!snapshot.windows[1].visible);
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    /// The [`VERSION`] of the schema this snapshot was created with.
+    pub version: u32,
+
+    /// The area the windows are arranged in.
+    pub container: Area,
+
+    /// One entry per window, in the same order as the windows.
+    pub windows: Vec<Window>,
+}
+
+impl Snapshot {
+    /// Apply the provided layout definition and take a snapshot of the result.
+    pub fn new(definition: &Layout, window_count: usize, container: &Rect) -> Self {
+        let tiling = apply_detailed(definition, window_count, container);
+        // windows without a tile are hidden behind the last tile
+        let hidden_role = tiling.roles.last().copied().unwrap_or(ColumnKind::Stack);
+        let windows = (0..window_count)
+            .map(|i| match (tiling.tiles.get(i), tiling.roles.get(i)) {
+                (Some(tile), Some(role)) => Window {
+                    area: Area::from(tile),
+                    role: *role,
+                    visible: true,
+                },
+                _ => Window {
+                    area: Area::default(),
+                    role: hidden_role,
+                    visible: false,
+                },
+            })
+            .collect();
+        Self {
+            version: VERSION,
+            container: Area::from(container),
+            windows,
+        }
+    }
+
+    /// Returns `true` if the snapshot was created with the schema [`VERSION`] of this crate.
+    pub fn is_supported(&self) -> bool {
+        self.version == VERSION
+    }
+}
+
+/// A single window of a [`Snapshot`].
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+pub struct Window {
+    /// The area of the window, all zero if the window is not visible.
+    pub area: Area,
+
+    /// The column the window belongs to.
+    /// Hidden windows share the column of the last visible window.
+    pub role: ColumnKind,
+
+    /// Whether the window is visible. For example, only the first
+    /// window is visible in a `Monocle` layout.
+    pub visible: bool,
+}
+
+/// A rectangular area of a [`Snapshot`], see [`Rect`].
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+pub struct Area {
+    pub x: i32,
+    pub y: i32,
+    pub w: u32,
+    pub h: u32,
+}
+
+impl From<&Rect> for Area {
+    fn from(rect: &Rect) -> Self {
+        Self {
+            x: rect.x,
+            y: rect.y,
+            w: rect.w,
+            h: rect.h,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        geometry::Rect,
+        ipc::{Area, Snapshot},
+        layouts::{ColumnKind, Layouts},
+    };
+
+    const CONTAINER: Rect = Rect {
+        x: 0,
+        y: 0,
+        w: 400,
+        h: 200,
+    };
+
+    #[test]
+    fn snapshot_contains_roles_and_visibility() {
+        let layout = Layouts::default().get("MainAndDeck").unwrap().clone();
+        let snapshot = Snapshot::new(&layout, 3, &CONTAINER);
+        let roles: Vec<ColumnKind> = snapshot.windows.iter().map(|w| w.role).collect();
+        let visible: Vec<bool> = snapshot.windows.iter().map(|w| w.visible).collect();
+        assert_eq!(
+            vec![ColumnKind::Main, ColumnKind::Stack, ColumnKind::Stack],
+            roles
+        );
+        assert_eq!(vec![true, true, false], visible);
+        assert_eq!(Area::default(), snapshot.windows[2].area);
+    }
+
+    #[test]
+    fn snapshot_schema_is_stable() {
+        let layout = Layouts::default().get("MainAndVertStack").unwrap().clone();
+        let snapshot = Snapshot::new(&layout, 2, &CONTAINER);
+        let json = serde_json::to_string(&snapshot).unwrap();
+        assert_eq!(
+            concat!(
+                r#"{"version":2,"container":{"x":0,"y":0,"w":400,"h":200},"windows":["#,
+                r#"{"area":{"x":0,"y":0,"w":200,"h":200},"role":"Main","visible":true},"#,
+                r#"{"area":{"x":200,"y":0,"w":200,"h":200},"role":"Stack","visible":true}]}"#
+            ),
+            json
+        );
+        let deserialized: Snapshot = serde_json::from_str(&json).unwrap();
+        assert!(deserialized.is_supported());
+        assert_eq!(snapshot, deserialized);
+    }
+
+    #[test]
+    fn snapshot_schema_contains_the_second_main_role() {
+        let layout = Layouts::default().get("DualMain").unwrap().clone();
+        let snapshot = Snapshot::new(&layout, 2, &CONTAINER);
+        let json = serde_json::to_string(&snapshot).unwrap();
+        assert_eq!(
+            concat!(
+                r#"{"version":2,"container":{"x":0,"y":0,"w":400,"h":200},"windows":["#,
+                r#"{"area":{"x":0,"y":0,"w":200,"h":200},"role":"Main","visible":true},"#,
+                r#"{"area":{"x":200,"y":0,"w":200,"h":200},"role":"SecondMain","visible":true}]}"#
+            ),
+            json
+        );
+        assert_eq!(snapshot, serde_json::from_str(&json).unwrap());
+    }
+}
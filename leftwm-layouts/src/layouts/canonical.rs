@@ -0,0 +1,202 @@
+use serde::{ser::SerializeStruct, Serialize, Serializer};
+
+use super::{Column, Columns, Layout, Main, SecondStack, Stack};
+
+/// Serializes the wrapped value with only the fields that differ from their default,
+/// in the order they are declared in. Omitting the defaults keeps configs short and
+/// makes the output stable, it only changes if the layouts do.
+pub(super) struct Canonical<'a, T: ?Sized>(pub &'a T);
+
+/// Serialize the provided fields of the value that differ from the ones of the default,
+/// the fields marked as `canonical` are serialized [`Canonical`] too.
+macro_rules! serialize_changed {
+    (
+        $serializer:expr, $name:literal, $value:expr, $default:expr,
+        $($field:ident $($canonical:ident)?),+ $(,)?
+    ) => {{
+        let (value, default) = ($value, $default);
+        let len = [$(value.$field != default.$field),+]
+            .iter()
+            .filter(|changed| **changed)
+            .count();
+        let mut state = $serializer.serialize_struct($name, len)?;
+        $(
+            if value.$field == default.$field {
+                state.skip_field(stringify!($field))?;
+            } else {
+                state.serialize_field(stringify!($field), field_value!($($canonical)? value.$field))?;
+            }
+        )+
+        state.end()
+    }};
+}
+
+macro_rules! field_value {
+    (canonical $value:expr) => {
+        &Canonical(&$value)
+    };
+    ($value:expr) => {
+        &$value
+    };
+}
+
+impl Serialize for Canonical<'_, [Layout]> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.iter().map(Canonical))
+    }
+}
+
+impl Serialize for Canonical<'_, Layout> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let layout = self.0;
+        let default = Layout {
+            // the name is always written
+            name: String::new(),
+            ..Layout::default()
+        };
+        serialize_changed!(
+            serializer,
+            "Layout",
+            layout,
+            default,
+            name,
+            tags,
+            flip,
+            rotate,
+            reserve,
+            reserved_gaps,
+            takeover_order,
+            stable_main,
+            remainder,
+            title_bar_height,
+            gaps,
+            margin,
+            columns canonical,
+        )
+    }
+}
+
+impl Serialize for Canonical<'_, Columns> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_changed!(
+            serializer,
+            "Columns",
+            self.0,
+            Columns::default(),
+            flip,
+            rotate,
+            orientation,
+            main_position,
+            adapt_to_portrait,
+            main canonical,
+            stack canonical,
+            second_stack canonical,
+            balance_stacks,
+            stack_distribution,
+            stack_fill_order,
+            second_main canonical,
+            list canonical,
+        )
+    }
+}
+
+impl Serialize for Canonical<'_, Option<Main>> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            Some(main) => serializer.serialize_some(&Canonical(main)),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
+impl Serialize for Canonical<'_, Main> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_changed!(
+            serializer,
+            "Main",
+            self.0,
+            Main::default(),
+            count,
+            max_count,
+            count_ratio,
+            size,
+            size_curve,
+            max_size,
+            flip,
+            rotate,
+            split,
+            spiral,
+            reverse,
+        )
+    }
+}
+
+impl Serialize for Canonical<'_, Stack> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_changed!(
+            serializer,
+            "Stack",
+            self.0,
+            Stack::default(),
+            flip,
+            rotate,
+            split,
+            spiral,
+            min_size,
+            reverse,
+        )
+    }
+}
+
+impl Serialize for Canonical<'_, Option<SecondStack>> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            Some(second_stack) => serializer.serialize_some(&Canonical(second_stack)),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
+impl Serialize for Canonical<'_, SecondStack> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_changed!(
+            serializer,
+            "SecondStack",
+            self.0,
+            SecondStack::default(),
+            size,
+            flip,
+            rotate,
+            split,
+            spiral,
+            min_size,
+            reverse,
+        )
+    }
+}
+
+impl Serialize for Canonical<'_, Vec<Column>> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.iter().map(Canonical))
+    }
+}
+
+impl Serialize for Canonical<'_, Column> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_changed!(
+            serializer,
+            "Column",
+            self.0,
+            Column::default(),
+            kind,
+            size,
+            capacity,
+            flip,
+            rotate,
+            split,
+            spiral,
+            min_size,
+            reverse,
+        )
+    }
+}
@@ -0,0 +1,144 @@
+use std::cmp;
+
+use crate::geometry::{remainderless_division, Rect, Reserve, Size};
+
+/// Calculate a layout with an arbitrary amount of columns next to each other
+/// (ie. a layout configured by [`crate::layouts::Columns::list`])
+/// based on the provided parameters.
+///
+/// * `window_counts` - Amount of windows in each of the columns
+/// * `container` - Container [`Rect`] in which the windows shall be displayed
+/// * `sizes` - Size of each of the columns, [`None`] to share the space left over
+///   by the columns with a size evenly with the other columns without a size
+/// * `reserve_column_space` - How to handle unused column space
+///
+/// If every column has a size, the last column takes the space left over.
+/// Returns a [`Rect`] for every column containing windows, in the same order as the columns.
+pub fn column_list(
+    window_counts: &[usize],
+    container: &Rect,
+    sizes: &[Option<Size>],
+    reserve_column_space: Reserve,
+) -> Vec<Option<Rect>> {
+    let has_windows: Vec<bool> = window_counts.iter().map(|&count| count > 0).collect();
+    let reserved: Vec<bool> = has_windows
+        .iter()
+        .map(|&has| has || reserve_column_space.is_reserved())
+        .collect();
+    let size = |i: usize| sizes.get(i).copied().flatten();
+
+    let mut widths = vec![0; window_counts.len()];
+    let mut remaining = container.w as usize;
+    for (i, width) in widths.iter_mut().enumerate() {
+        if let (true, Some(size)) = (reserved[i], size(i)) {
+            *width = cmp::min(size.into_absolute(container.w).max(0) as usize, remaining);
+            remaining -= *width;
+        }
+    }
+    let unsized_columns: Vec<usize> = (0..widths.len())
+        .filter(|&i| reserved[i] && size(i).is_none())
+        .collect();
+    if unsized_columns.is_empty() {
+        if let Some(last) = (0..widths.len()).rev().find(|&i| reserved[i]) {
+            widths[last] += remaining;
+        }
+    } else {
+        let shares = remainderless_division(remaining, unsized_columns.len());
+        for (&i, share) in unsized_columns.iter().zip(shares) {
+            widths[i] = share;
+        }
+    }
+
+    // the occupied columns are moved together, if the empty ones are centered around them
+    let mut offset = match reserve_column_space {
+        Reserve::ReserveAndCenter => {
+            let occupied: usize = (0..widths.len())
+                .filter(|&i| has_windows[i])
+                .map(|i| widths[i])
+                .sum();
+            (container.w as usize - occupied) / 2
+        }
+        _ => 0,
+    };
+    (0..widths.len())
+        .map(|i| {
+            let column_offset = offset;
+            if has_windows[i] || reserve_column_space != Reserve::ReserveAndCenter {
+                offset += widths[i];
+            }
+            has_windows[i].then(|| Rect {
+                x: container.x + column_offset as i32,
+                w: widths[i] as u32,
+                ..*container
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::{Rect, Reserve, Size};
+
+    use super::column_list;
+
+    const CONTAINER: Rect = Rect {
+        x: 0,
+        y: 0,
+        w: 1000,
+        h: 500,
+    };
+
+    const SIZES: [Option<Size>; 4] = [Some(Size::Ratio(0.4)), None, None, Some(Size::Pixel(100))];
+
+    #[test]
+    fn unsized_columns_share_the_space_left_over() {
+        let columns = column_list(&[1, 2, 1, 3], &CONTAINER, &SIZES, Reserve::None);
+        assert_eq!(
+            vec![
+                Some(Rect::new(0, 0, 400, 500)),
+                Some(Rect::new(400, 0, 250, 500)),
+                Some(Rect::new(650, 0, 250, 500)),
+                Some(Rect::new(900, 0, 100, 500)),
+            ],
+            columns
+        );
+    }
+
+    #[test]
+    fn empty_columns_are_taken_over() {
+        let columns = column_list(&[1, 0, 1, 0], &CONTAINER, &SIZES, Reserve::None);
+        assert_eq!(
+            vec![
+                Some(Rect::new(0, 0, 400, 500)),
+                None,
+                Some(Rect::new(400, 0, 600, 500)),
+                None,
+            ],
+            columns
+        );
+
+        // the last sized column takes the space left over
+        let columns = column_list(&[1, 0, 0, 1], &CONTAINER, &SIZES, Reserve::None);
+        assert_eq!(Some(Rect::new(400, 0, 600, 500)), columns[3]);
+    }
+
+    #[test]
+    fn reserved_columns_keep_their_space() {
+        let columns = column_list(&[1, 0, 1, 0], &CONTAINER, &SIZES, Reserve::Reserve);
+        assert_eq!(Some(Rect::new(0, 0, 400, 500)), columns[0]);
+        assert_eq!(Some(Rect::new(650, 0, 250, 500)), columns[2]);
+
+        let columns = column_list(&[1, 0, 1, 0], &CONTAINER, &SIZES, Reserve::ReserveAndCenter);
+        assert_eq!(Some(Rect::new(175, 0, 400, 500)), columns[0]);
+        assert_eq!(Some(Rect::new(575, 0, 250, 500)), columns[2]);
+    }
+
+    #[test]
+    fn no_columns_or_windows() {
+        assert!(column_list(&[], &CONTAINER, &[], Reserve::None).is_empty());
+        assert_eq!(
+            vec![None, None],
+            column_list(&[0, 0], &CONTAINER, &SIZES, Reserve::Reserve)
+        );
+    }
+}
@@ -0,0 +1,174 @@
+use std::cmp;
+
+use crate::geometry::{Rect, Reserve, Size};
+
+/// Calculate a dual main layout (ie. layout with a main column on each side of the stack
+/// like `DualMain`) based on the provided parameters.
+///
+/// The windows fill the main column first, then the second main column, then the stack.
+///
+/// * `window_count` - Amount of windows to account for
+/// * `container` - Container [`Rect`] in which the windows shall be displayed
+/// * `main_window_count` - How many of the windows shall be in the main column
+/// * `main_size` - Size of the main column
+/// * `second_main_window_count` - How many of the windows shall be in the second main column
+/// * `second_main_size` - Size of the second main column
+/// * `reserve_column_space` - How to handle unused column space
+///
+/// If the stack is neither occupied nor reserved, the main columns share the whole width
+/// in proportion to their sizes. With [`Reserve::ReserveAndCenter`], the occupied columns
+/// are moved next to each other into the center of the container.
+pub fn dual_main(
+    window_count: usize,
+    container: &Rect,
+    main_window_count: usize,
+    main_size: Size,
+    second_main_window_count: usize,
+    second_main_size: Size,
+    reserve_column_space: Reserve,
+) -> (Option<Rect>, Option<Rect>, Option<Rect>) {
+    let main_window_count = cmp::min(main_window_count, window_count);
+    let second_main_window_count = cmp::min(
+        second_main_window_count,
+        window_count.saturating_sub(main_window_count),
+    );
+    let stack_window_count = window_count
+        .saturating_sub(main_window_count)
+        .saturating_sub(second_main_window_count);
+
+    let has_windows = [
+        main_window_count > 0,
+        stack_window_count > 0,
+        second_main_window_count > 0,
+    ];
+    let reserved = has_windows.map(|has| has || reserve_column_space.is_reserved());
+    let [main_reserve, stack_reserve, second_main_reserve] = reserved;
+
+    let width = container.w as usize;
+    let main_absolute = main_size.into_absolute(container.w).max(0) as usize;
+    let second_main_absolute = second_main_size.into_absolute(container.w).max(0) as usize;
+    let (main_width, second_main_width) = match (main_reserve, stack_reserve, second_main_reserve) {
+        (true, true, true) => {
+            let main_width = cmp::min(main_absolute, width);
+            (
+                main_width,
+                cmp::min(second_main_absolute, width - main_width),
+            )
+        }
+        (true, true, false) => (cmp::min(main_absolute, width), 0),
+        (true, false, true) => match main_absolute + second_main_absolute {
+            0 => (width / 2, width - width / 2),
+            sum => {
+                let main_width = width * main_absolute / sum;
+                (main_width, width - main_width)
+            }
+        },
+        (true, false, false) => (width, 0),
+        _ => (0, 0),
+    };
+    let stack_width = if stack_reserve {
+        width - main_width - second_main_width
+    } else {
+        0
+    };
+    let widths = [main_width, stack_width, second_main_width];
+
+    // the occupied columns are moved together, if the empty ones are centered around them
+    let offsets = if reserve_column_space == Reserve::ReserveAndCenter {
+        let occupied: usize = (0..3).filter(|&i| has_windows[i]).map(|i| widths[i]).sum();
+        let mut offset = (width - occupied) / 2;
+        [0, 1, 2].map(|i| {
+            let column_offset = offset;
+            if has_windows[i] {
+                offset += widths[i];
+            }
+            column_offset
+        })
+    } else {
+        [0, widths[0], widths[0] + widths[1]]
+    };
+
+    let [main, stack, second_main] = [0, 1, 2].map(|i| {
+        has_windows[i].then(|| Rect {
+            x: container.x + offsets[i] as i32,
+            w: widths[i] as u32,
+            ..*container
+        })
+    });
+    (main, stack, second_main)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::{Rect, Reserve, Size};
+
+    use super::dual_main;
+
+    const CONTAINER: Rect = Rect {
+        x: 0,
+        y: 0,
+        w: 1000,
+        h: 500,
+    };
+
+    fn columns(
+        window_count: usize,
+        reserve: Reserve,
+    ) -> (Option<Rect>, Option<Rect>, Option<Rect>) {
+        dual_main(
+            window_count,
+            &CONTAINER,
+            1,
+            Size::Ratio(0.4),
+            1,
+            Size::Ratio(0.2),
+            reserve,
+        )
+    }
+
+    #[test]
+    fn all_columns_occupied() {
+        let (main, stack, second_main) = columns(3, Reserve::None);
+        assert_eq!(Some(Rect::new(0, 0, 400, 500)), main);
+        assert_eq!(Some(Rect::new(400, 0, 400, 500)), stack);
+        assert_eq!(Some(Rect::new(800, 0, 200, 500)), second_main);
+    }
+
+    #[test]
+    fn main_columns_share_the_space_of_an_empty_stack() {
+        let (main, stack, second_main) = columns(2, Reserve::None);
+        assert_eq!(Some(Rect::new(0, 0, 666, 500)), main);
+        assert_eq!(None, stack);
+        assert_eq!(Some(Rect::new(666, 0, 334, 500)), second_main);
+    }
+
+    #[test]
+    fn single_main_window_takes_the_container() {
+        let (main, stack, second_main) = columns(1, Reserve::None);
+        assert_eq!(Some(CONTAINER), main);
+        assert_eq!((None, None), (stack, second_main));
+    }
+
+    #[test]
+    fn reserved_columns_keep_their_space() {
+        let (main, stack, second_main) = columns(1, Reserve::Reserve);
+        assert_eq!(Some(Rect::new(0, 0, 400, 500)), main);
+        assert_eq!((None, None), (stack, second_main));
+
+        let (main, stack, second_main) = columns(2, Reserve::Reserve);
+        assert_eq!(Some(Rect::new(0, 0, 400, 500)), main);
+        assert_eq!(None, stack);
+        assert_eq!(Some(Rect::new(800, 0, 200, 500)), second_main);
+    }
+
+    #[test]
+    fn reserve_and_center_moves_occupied_columns_together() {
+        let (main, _, _) = columns(1, Reserve::ReserveAndCenter);
+        assert_eq!(Some(Rect::new(300, 0, 400, 500)), main);
+
+        let (main, stack, second_main) = columns(2, Reserve::ReserveAndCenter);
+        assert_eq!(Some(Rect::new(200, 0, 400, 500)), main);
+        assert_eq!(None, stack);
+        assert_eq!(Some(Rect::new(600, 0, 200, 500)), second_main);
+    }
+}
@@ -1,5 +1,10 @@
+mod column_list;
+mod dual_main;
 mod three_column;
 mod two_column;
 
-pub use three_column::three_column;
-pub use two_column::two_column;
+pub use column_list::column_list;
+pub use dual_main::dual_main;
+pub(crate) use three_column::rebalance_stacks;
+pub use three_column::{three_column, three_column_along};
+pub use two_column::{two_column, two_column_along};
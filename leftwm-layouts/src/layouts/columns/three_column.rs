@@ -1,6 +1,6 @@
 use std::cmp;
 
-use crate::geometry::{remainderless_division, Rect, Reserve, Size};
+use crate::geometry::{remainderless_division, scale, Axis, Rect, Reserve, Size, TakeoverOrder};
 
 /// Calculate a three column layout (ie. layout with one main and two stacks like `CenterMain`)
 /// based on the provided parameters.
@@ -10,15 +10,49 @@ use crate::geometry::{remainderless_division, Rect, Reserve, Size};
 /// * `main_window_count` - How many of the windows shall be in the main column
 /// * `main_size` - Size of the main column
 /// * `reserve_column_space` - How to handle unused column space
+/// * `takeover_order` - Which column takes over the space of an empty, unreserved right stack
 /// * `balance_stacks` - Whether stack windows shall be distributed evenly across both stacks.
-///    If false, puts one window in the first stack and the rest in the second stack
+///   If false, puts one window in the first stack and the rest in the second stack
+/// * `right_stack_size` - Size of the right stack, if [`None`] both stacks share
+///   the space next to the main column equally
+#[allow(clippy::too_many_arguments)]
 pub fn three_column(
     window_count: usize,
     container: &Rect,
     main_window_count: usize,
     main_size: Size,
     reserve_column_space: Reserve,
+    takeover_order: TakeoverOrder,
     balance_stacks: bool,
+    right_stack_size: Option<Size>,
+) -> (Option<Rect>, Option<Rect>, Option<Rect>) {
+    three_column_along(
+        Axis::X,
+        window_count,
+        container,
+        main_window_count,
+        main_size,
+        reserve_column_space,
+        takeover_order,
+        balance_stacks,
+        right_stack_size,
+    )
+}
+
+/// Same as [`three_column`], but the columns are arranged along the provided [`Axis`],
+/// ie. on top of each other for [`Axis::Y`]. The `main_size` applies to the length
+/// of the `container` on that [`Axis`], the other [`Axis`] of the `container` is kept as is.
+#[allow(clippy::too_many_arguments)]
+pub fn three_column_along(
+    axis: Axis,
+    window_count: usize,
+    container: &Rect,
+    main_window_count: usize,
+    main_size: Size,
+    reserve_column_space: Reserve,
+    takeover_order: TakeoverOrder,
+    balance_stacks: bool,
+    right_stack_size: Option<Size>,
 ) -> (Option<Rect>, Option<Rect>, Option<Rect>) {
     let main_window_count = cmp::min(main_window_count, window_count);
     let stack_window_count = window_count.saturating_sub(main_window_count);
@@ -50,15 +84,21 @@ pub fn three_column(
     let right_stack_empty =
         left_stack_empty || !right_stack_has_windows && reserve_column_space.is_reserved();
 
+    let length = axis.length(container);
     let main_width = match (main_reserve, left_stack_reserve) {
-        (true, true) => main_size.into_absolute(container.w) as usize,
-        (true, false) => container.w as usize,
+        (true, true) => main_size.into_absolute(length) as usize,
+        (true, false) => length as usize,
         _ => 0,
     };
-    let stack_width = container.w as usize - main_width;
+    let stack_width = length as usize - main_width;
+    // the width of the right stack, if both stacks share the space next to the main column
+    let right_stack_share = match right_stack_size {
+        Some(size) => cmp::min(size.into_absolute(length).max(0) as usize, stack_width),
+        None => stack_width - stack_width / 2,
+    };
     let left_stack_width = match (left_stack_reserve, right_stack_reserve) {
         (true, false) => stack_width,
-        (true, true) => stack_width / 2,
+        (true, true) => stack_width - right_stack_share,
         _ => 0,
     };
     let right_stack_width = if right_stack_reserve {
@@ -67,6 +107,19 @@ pub fn three_column(
         0
     };
 
+    // the space of the empty right stack is taken over by the main column and the
+    // left stack, in the order they would share it if the right stack wasn't empty
+    let (main_width, left_stack_width) =
+        if main_reserve && left_stack_reserve && !right_stack_reserve {
+            takeover_order.take_over(
+                main_width,
+                stack_width - right_stack_share,
+                right_stack_share,
+            )
+        } else {
+            (main_width, left_stack_width)
+        };
+
     let main_offset = match (reserve_column_space, left_stack_empty, right_stack_empty) {
         (Reserve::ReserveAndCenter, false, true) => left_stack_width + (right_stack_width / 2),
         (Reserve::ReserveAndCenter, true, _) => stack_width / 2,
@@ -83,44 +136,52 @@ pub fn three_column(
         _ => left_stack_width + main_width,
     };
 
-    let main = if main_has_windows {
-        Some(Rect {
-            x: container.x + main_offset as i32,
-            w: main_width as u32,
-            ..*container
-        })
-    } else {
-        None
-    };
-
-    let left_stack = if left_stack_has_windows {
-        Some(Rect {
-            x: container.x + left_stack_offset as i32,
-            w: left_stack_width as u32,
-            ..*container
-        })
-    } else {
-        None
-    };
-
-    let right_stack = if right_stack_has_windows {
-        Some(Rect {
-            x: container.x + right_stack_offset as i32,
-            w: right_stack_width as u32,
-            ..*container
-        })
-    } else {
-        None
+    let start = axis.start(container);
+    let column = |offset: usize, width: usize| {
+        axis.with_span(container, start + offset as i32, width as u32)
     };
+    let main = main_has_windows.then(|| column(main_offset, main_width));
+    let left_stack = left_stack_has_windows.then(|| column(left_stack_offset, left_stack_width));
+    let right_stack =
+        right_stack_has_windows.then(|| column(right_stack_offset, right_stack_width));
 
     (left_stack, main, right_stack)
 }
 
+/// Move the border between the two stacks of a [`three_column`] result, so that
+/// the left stack gets the provided `share` of the combined width of both stacks.
+/// The main column in between moves along, keeping its width.
+///
+/// Both stacks keep a width of at least one pixel. The columns are
+/// expected to be arranged along the provided [`Axis`].
+pub(crate) fn rebalance_stacks(
+    axis: Axis,
+    left_stack: &mut Rect,
+    main: &mut Rect,
+    right_stack: &mut Rect,
+    share: f32,
+) {
+    let left_length = axis.length(left_stack);
+    let combined = left_length + axis.length(right_stack);
+    if combined < 2 {
+        return;
+    }
+    let left_width = (scale(combined, share) as u32).clamp(1, combined - 1);
+    let offset = left_width as i32 - left_length as i32;
+    *left_stack = axis.with_span(left_stack, axis.start(left_stack), left_width);
+    *main = axis.with_span(main, axis.start(main) + offset, axis.length(main));
+    *right_stack = axis.with_span(
+        right_stack,
+        axis.start(right_stack) + offset,
+        combined - left_width,
+    );
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::geometry::{Rect, Size};
+    use crate::geometry::{Axis, Orientation, Rect, Reserve, Size, TakeoverOrder};
 
-    use super::three_column;
+    use super::{rebalance_stacks, three_column, three_column_along};
 
     const CONTAINER: Rect = Rect {
         x: 0,
@@ -137,7 +198,9 @@ mod tests {
             1,
             Size::Ratio(0.65),
             crate::geometry::Reserve::None,
+            TakeoverOrder::StacksFirst,
             false,
+            None,
         );
         assert_eq!(
             left_stack,
@@ -176,7 +239,9 @@ mod tests {
             1,
             Size::Ratio(0.65),
             crate::geometry::Reserve::Reserve,
+            TakeoverOrder::StacksFirst,
             false,
+            None,
         );
         assert_eq!(
             left_stack,
@@ -215,7 +280,9 @@ mod tests {
             1,
             Size::Ratio(0.65),
             crate::geometry::Reserve::ReserveAndCenter,
+            TakeoverOrder::StacksFirst,
             false,
+            None,
         );
         assert_eq!(
             left_stack,
@@ -254,7 +321,9 @@ mod tests {
             1,
             Size::Ratio(0.65),
             crate::geometry::Reserve::None,
+            TakeoverOrder::StacksFirst,
             false,
+            None,
         );
         assert_eq!(
             left_stack,
@@ -285,7 +354,9 @@ mod tests {
             1,
             Size::Ratio(0.65),
             crate::geometry::Reserve::Reserve,
+            TakeoverOrder::StacksFirst,
             false,
+            None,
         );
         assert_eq!(
             left_stack,
@@ -316,7 +387,9 @@ mod tests {
             1,
             Size::Ratio(0.65),
             crate::geometry::Reserve::ReserveAndCenter,
+            TakeoverOrder::StacksFirst,
             false,
+            None,
         );
         assert_eq!(
             left_stack,
@@ -347,7 +420,9 @@ mod tests {
             1,
             Size::Ratio(0.65),
             crate::geometry::Reserve::None,
+            TakeoverOrder::StacksFirst,
             false,
+            None,
         );
         assert_eq!(left_stack, None);
         assert_eq!(
@@ -370,7 +445,9 @@ mod tests {
             1,
             Size::Ratio(0.65),
             crate::geometry::Reserve::Reserve,
+            TakeoverOrder::StacksFirst,
             false,
+            None,
         );
         assert_eq!(left_stack, None);
         assert_eq!(
@@ -393,7 +470,9 @@ mod tests {
             1,
             Size::Ratio(0.65),
             crate::geometry::Reserve::ReserveAndCenter,
+            TakeoverOrder::StacksFirst,
             false,
+            None,
         );
         assert_eq!(left_stack, None);
         assert_eq!(
@@ -416,7 +495,9 @@ mod tests {
             0,
             Size::Ratio(0.65),
             crate::geometry::Reserve::None,
+            TakeoverOrder::StacksFirst,
             false,
+            None,
         );
         assert_eq!(
             left_stack,
@@ -447,7 +528,9 @@ mod tests {
             0,
             Size::Ratio(0.65),
             crate::geometry::Reserve::Reserve,
+            TakeoverOrder::StacksFirst,
             false,
+            None,
         );
         assert_eq!(
             left_stack,
@@ -478,7 +561,9 @@ mod tests {
             0,
             Size::Ratio(0.65),
             crate::geometry::Reserve::ReserveAndCenter,
+            TakeoverOrder::StacksFirst,
             false,
+            None,
         );
         assert_eq!(
             left_stack,
@@ -509,7 +594,9 @@ mod tests {
             0,
             Size::Ratio(0.65),
             crate::geometry::Reserve::None,
+            TakeoverOrder::StacksFirst,
             false,
+            None,
         );
         assert_eq!(
             left_stack,
@@ -532,7 +619,9 @@ mod tests {
             0,
             Size::Ratio(0.65),
             crate::geometry::Reserve::Reserve,
+            TakeoverOrder::StacksFirst,
             false,
+            None,
         );
         assert_eq!(
             left_stack,
@@ -555,7 +644,9 @@ mod tests {
             0,
             Size::Ratio(0.65),
             crate::geometry::Reserve::ReserveAndCenter,
+            TakeoverOrder::StacksFirst,
             false,
+            None,
         );
         assert_eq!(
             left_stack,
@@ -578,7 +669,9 @@ mod tests {
             1,
             Size::Ratio(0.65),
             crate::geometry::Reserve::None,
+            TakeoverOrder::StacksFirst,
             false,
+            None,
         );
         assert_eq!(left_stack, None);
         assert_eq!(main, None);
@@ -594,10 +687,177 @@ mod tests {
             1,
             Size::Ratio(0.5),
             crate::geometry::Reserve::None,
+            TakeoverOrder::StacksFirst,
             true,
+            None,
         );
         assert_eq!(Some(Rect::new(2560, 1440, 640, 1440)), left_stack);
         assert_eq!(Some(Rect::new(3200, 1440, 1280, 1440)), main);
         assert_eq!(Some(Rect::new(4480, 1440, 640, 1440)), right_stack);
     }
+
+    #[test]
+    fn rebalance_stacks_moves_main_along() {
+        let (left_stack, main, right_stack) = three_column(
+            3,
+            &CONTAINER,
+            1,
+            Size::Ratio(0.5),
+            crate::geometry::Reserve::None,
+            TakeoverOrder::StacksFirst,
+            true,
+            None,
+        );
+        let (mut left_stack, mut main, mut right_stack) =
+            (left_stack.unwrap(), main.unwrap(), right_stack.unwrap());
+        rebalance_stacks(Axis::X, &mut left_stack, &mut main, &mut right_stack, 0.75);
+        assert_eq!(Rect::new(0, 0, 1920, 1440), left_stack);
+        assert_eq!(Rect::new(1920, 0, 2560, 1440), main);
+        assert_eq!(Rect::new(4480, 0, 640, 1440), right_stack);
+    }
+
+    fn three_column_with_no_right_stack(takeover_order: TakeoverOrder) -> (Rect, Rect) {
+        let (left_stack, main, right_stack) = three_column(
+            2,
+            &CONTAINER,
+            1,
+            Size::Ratio(0.65),
+            Reserve::None,
+            takeover_order,
+            false,
+            None,
+        );
+        assert_eq!(right_stack, None);
+        (left_stack.unwrap(), main.unwrap())
+    }
+
+    #[test]
+    fn left_stack_takes_over_first() {
+        let (left_stack, main) = three_column_with_no_right_stack(TakeoverOrder::StacksFirst);
+        assert_eq!(Rect::new(0, 0, 1792, 1440), left_stack);
+        assert_eq!(Rect::new(1792, 0, 3328, 1440), main);
+    }
+
+    #[test]
+    fn main_takes_over_first() {
+        let (left_stack, main) = three_column_with_no_right_stack(TakeoverOrder::MainFirst);
+        assert_eq!(Rect::new(0, 0, 896, 1440), left_stack);
+        assert_eq!(Rect::new(896, 0, 4224, 1440), main);
+    }
+
+    #[test]
+    fn main_and_left_stack_take_over_proportionally() {
+        let (left_stack, main) = three_column_with_no_right_stack(TakeoverOrder::Proportional);
+        assert_eq!(Rect::new(0, 0, 1087, 1440), left_stack);
+        assert_eq!(Rect::new(1087, 0, 4033, 1440), main);
+    }
+
+    #[test]
+    fn takeover_order_does_not_apply_to_reserved_space() {
+        for takeover_order in [TakeoverOrder::MainFirst, TakeoverOrder::Proportional] {
+            let (left_stack, main, _) = three_column(
+                2,
+                &CONTAINER,
+                1,
+                Size::Ratio(0.65),
+                Reserve::Reserve,
+                takeover_order,
+                false,
+                None,
+            );
+            assert_eq!(Some(Rect::new(0, 0, 896, 1440)), left_stack);
+            assert_eq!(Some(Rect::new(896, 0, 3328, 1440)), main);
+        }
+    }
+
+    #[test]
+    fn right_stack_size_narrows_the_right_stack() {
+        let columns = |window_count, reserve| {
+            three_column(
+                window_count,
+                &CONTAINER,
+                1,
+                Size::Ratio(0.65),
+                reserve,
+                TakeoverOrder::StacksFirst,
+                false,
+                Some(Size::Pixel(400)),
+            )
+        };
+        let (left_stack, main, right_stack) = columns(3, Reserve::None);
+        assert_eq!(Some(Rect::new(0, 0, 1392, 1440)), left_stack);
+        assert_eq!(Some(Rect::new(1392, 0, 3328, 1440)), main);
+        assert_eq!(Some(Rect::new(4720, 0, 400, 1440)), right_stack);
+
+        // an empty right stack still reserves its own size
+        let (left_stack, main, right_stack) = columns(2, Reserve::Reserve);
+        assert_eq!(Some(Rect::new(0, 0, 1392, 1440)), left_stack);
+        assert_eq!(Some(Rect::new(1392, 0, 3328, 1440)), main);
+        assert_eq!(None, right_stack);
+
+        // the space of an unreserved right stack is taken over as usual
+        let (left_stack, main, _) = columns(2, Reserve::None);
+        assert_eq!(Some(Rect::new(0, 0, 1792, 1440)), left_stack);
+        assert_eq!(Some(Rect::new(1792, 0, 3328, 1440)), main);
+    }
+
+    #[test]
+    fn three_column_along_y_is_three_column_transposed() {
+        let transpose = |rect| Orientation::Vertical.orient(rect);
+        let container = Rect::new(30, 200, 1440, 5120);
+        let reserves = [Reserve::None, Reserve::Reserve, Reserve::ReserveAndCenter];
+        let takeover_orders = [
+            TakeoverOrder::StacksFirst,
+            TakeoverOrder::MainFirst,
+            TakeoverOrder::Proportional,
+        ];
+        for reserve in reserves {
+            for takeover_order in takeover_orders {
+                for window_count in 0..6 {
+                    for right_stack_size in [None, Some(Size::Pixel(400))] {
+                        let (left, main, right) = three_column(
+                            window_count,
+                            &transpose(container),
+                            1,
+                            Size::Ratio(0.5),
+                            reserve,
+                            takeover_order,
+                            true,
+                            right_stack_size,
+                        );
+                        assert_eq!(
+                            (
+                                left.map(transpose),
+                                main.map(transpose),
+                                right.map(transpose)
+                            ),
+                            three_column_along(
+                                Axis::Y,
+                                window_count,
+                                &container,
+                                1,
+                                Size::Ratio(0.5),
+                                reserve,
+                                takeover_order,
+                                true,
+                                right_stack_size,
+                            ),
+                            "{window_count} windows with {reserve:?} and {takeover_order:?}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rebalance_stacks_along_y() {
+        let mut left_stack = Rect::new(0, 100, 400, 100);
+        let mut main = Rect::new(0, 200, 400, 200);
+        let mut right_stack = Rect::new(0, 400, 400, 100);
+        rebalance_stacks(Axis::Y, &mut left_stack, &mut main, &mut right_stack, 0.75);
+        assert_eq!(Rect::new(0, 100, 400, 150), left_stack);
+        assert_eq!(Rect::new(0, 250, 400, 200), main);
+        assert_eq!(Rect::new(0, 450, 400, 50), right_stack);
+    }
 }
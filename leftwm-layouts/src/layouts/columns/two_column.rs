@@ -1,6 +1,6 @@
 use std::cmp;
 
-use crate::geometry::{Rect, Reserve, Size};
+use crate::geometry::{Axis, Rect, Reserve, Size};
 
 /// Calculate a two column layout (ie. layout with a main and stack part)
 /// based on the provided parameters.
@@ -16,6 +16,27 @@ pub fn two_column(
     main_window_count: usize,
     main_size: Size,
     reserve_column_space: Reserve,
+) -> (Option<Rect>, Option<Rect>) {
+    two_column_along(
+        Axis::X,
+        window_count,
+        container,
+        main_window_count,
+        main_size,
+        reserve_column_space,
+    )
+}
+
+/// Same as [`two_column`], but the columns are arranged along the provided [`Axis`],
+/// ie. on top of each other for [`Axis::Y`]. The `main_size` applies to the length
+/// of the `container` on that [`Axis`], the other [`Axis`] of the `container` is kept as is.
+pub fn two_column_along(
+    axis: Axis,
+    window_count: usize,
+    container: &Rect,
+    main_window_count: usize,
+    main_size: Size,
+    reserve_column_space: Reserve,
 ) -> (Option<Rect>, Option<Rect>) {
     let main_window_count = cmp::min(main_window_count, window_count);
     let stack_window_count = window_count.saturating_sub(main_window_count);
@@ -29,12 +50,13 @@ pub fn two_column(
     let main_empty = !main_has_windows && reserve_column_space.is_reserved();
     let stack_empty = !stack_has_windows && reserve_column_space.is_reserved();
 
+    let length = axis.length(container);
     let main_width = match (main_reserve, stack_reserve) {
-        (true, true) => main_size.into_absolute(container.w) as usize,
-        (true, false) => container.w as usize,
+        (true, true) => main_size.into_absolute(length) as usize,
+        (true, false) => length as usize,
         _ => 0,
     };
-    let stack_width = container.w as usize - main_width;
+    let stack_width = length as usize - main_width;
 
     let main_offset = match (reserve_column_space, stack_empty) {
         (Reserve::ReserveAndCenter, true) => stack_width / 2,
@@ -45,36 +67,20 @@ pub fn two_column(
         _ => main_width,
     };
 
-    let main = if main_has_windows {
-        Some(Rect {
-            x: container.x + main_offset as i32,
-            y: container.y,
-            w: main_width as u32,
-            h: container.h,
-        })
-    } else {
-        None
-    };
-
-    let stack = if stack_has_windows {
-        Some(Rect {
-            x: container.x + stack_offset as i32,
-            y: container.y,
-            w: stack_width as u32,
-            h: container.h,
-        })
-    } else {
-        None
-    };
+    let start = axis.start(container);
+    let main = main_has_windows
+        .then(|| axis.with_span(container, start + main_offset as i32, main_width as u32));
+    let stack = stack_has_windows
+        .then(|| axis.with_span(container, start + stack_offset as i32, stack_width as u32));
 
     (main, stack)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::geometry::{Rect, Size};
+    use crate::geometry::{Axis, Orientation, Rect, Reserve, Size};
 
-    use super::two_column;
+    use super::{two_column, two_column_along};
 
     const CONTAINER: Rect = Rect {
         x: 0,
@@ -309,4 +315,44 @@ mod tests {
         assert_eq!(Some(Rect::new(2560, 1440, 1280, 1440)), main);
         assert_eq!(Some(Rect::new(3840, 1440, 1280, 1440)), stack);
     }
+
+    #[test]
+    fn two_column_along_y_is_two_column_transposed() {
+        let transpose = |rect| Orientation::Vertical.orient(rect);
+        let container = Rect::new(30, 200, 1440, 5120);
+        for reserve in [Reserve::None, Reserve::Reserve, Reserve::ReserveAndCenter] {
+            for window_count in 0..4 {
+                for main_window_count in 0..3 {
+                    let (main, stack) = two_column(
+                        window_count,
+                        &transpose(container),
+                        main_window_count,
+                        Size::Ratio(0.65),
+                        reserve,
+                    );
+                    assert_eq!(
+                        (main.map(transpose), stack.map(transpose)),
+                        two_column_along(
+                            Axis::Y,
+                            window_count,
+                            &container,
+                            main_window_count,
+                            Size::Ratio(0.65),
+                            reserve,
+                        ),
+                        "{window_count} windows with {reserve:?}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn two_column_along_y_honors_the_container_position() {
+        let container = Rect::new(30, 200, 1440, 5120);
+        let (main, stack) =
+            two_column_along(Axis::Y, 3, &container, 1, Size::Ratio(0.65), Reserve::None);
+        assert_eq!(Some(Rect::new(30, 200, 1440, 3328)), main);
+        assert_eq!(Some(Rect::new(30, 3528, 1440, 1792)), stack);
+    }
 }
@@ -0,0 +1,236 @@
+use serde::{Deserialize, Serialize};
+
+use crate::geometry::{Size, Split};
+
+use super::{Column, ColumnKind, Layout};
+
+/// A change to a [`Layout`] at runtime, see [`Layout::apply_command`].
+///
+/// Each command corresponds to one of the mutating methods of [`Layout`],
+/// so that keybindings and IPC messages can be dispatched through a single
+/// method instead of wiring up each mutation by hand. Commands can be
+/// serialized, eg. to send them to a running window manager.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayoutCommand {
+    /// See [`Layout::set_main_size`].
+    SetMainSize(Size),
+
+    /// See [`Layout::increase_main_size`], with the upper bound.
+    IncreaseMainSize(i32),
+
+    /// See [`Layout::decrease_main_size`].
+    DecreaseMainSize,
+
+    /// See [`Layout::change_main_size`], with the delta and the upper bound.
+    ChangeMainSize(i32, i32),
+
+    /// See [`Layout::increase_stack_size`], with the upper bound.
+    IncreaseStackSize(i32),
+
+    /// See [`Layout::decrease_stack_size`].
+    DecreaseStackSize,
+
+    /// See [`Layout::change_stack_size`], with the delta and the upper bound.
+    ChangeStackSize(i32, i32),
+
+    /// See [`Layout::set_gaps`].
+    SetGaps(u32),
+
+    /// See [`Layout::increase_gaps`].
+    IncreaseGaps,
+
+    /// See [`Layout::decrease_gaps`].
+    DecreaseGaps,
+
+    /// See [`Layout::change_gaps`], with the delta and the upper bound.
+    ChangeGaps(i32, u32),
+
+    /// See [`Layout::set_main_window_count`].
+    SetMainCount(usize),
+
+    /// See [`Layout::increase_main_window_count`].
+    IncreaseMainCount,
+
+    /// See [`Layout::decrease_main_window_count`].
+    DecreaseMainCount,
+
+    /// See [`Layout::rotate`].
+    RotateClockwise,
+
+    /// See [`Layout::rotate`].
+    RotateCounterClockwise,
+
+    /// See [`Layout::rotate_column`].
+    RotateColumn(ColumnKind, bool),
+
+    /// See [`Layout::toggle_flip_horizontal`].
+    ToggleFlipHorizontal,
+
+    /// See [`Layout::toggle_flip_vertical`].
+    ToggleFlipVertical,
+
+    /// See [`Layout::toggle_columns_flip_horizontal`].
+    ToggleColumnsFlipHorizontal,
+
+    /// See [`Layout::toggle_columns_flip_vertical`].
+    ToggleColumnsFlipVertical,
+
+    /// See [`Layout::swap_main_and_stack`].
+    SwapMainAndStack,
+
+    /// See [`Layout::toggle_second_stack`].
+    ToggleSecondStack,
+
+    /// See [`Layout::add_column`], with the position and the column.
+    AddColumn(usize, Column),
+
+    /// See [`Layout::remove_column`].
+    RemoveColumn(usize),
+
+    /// Set the [`Split`] of the provided column, if the layout has it.
+    ///
+    /// *Note: The `second_stack` column always has a split,
+    /// so setting it to [`None`] leaves it unchanged*
+    SetSplit(ColumnKind, Option<Split>),
+}
+
+pub(super) fn apply(layout: &mut Layout, command: LayoutCommand) {
+    match command {
+        LayoutCommand::SetMainSize(size) => layout.set_main_size(size),
+        LayoutCommand::IncreaseMainSize(upper_bound) => layout.increase_main_size(upper_bound),
+        LayoutCommand::DecreaseMainSize => layout.decrease_main_size(),
+        LayoutCommand::ChangeMainSize(delta, upper_bound) => {
+            layout.change_main_size(delta, upper_bound);
+        }
+        LayoutCommand::IncreaseStackSize(upper_bound) => layout.increase_stack_size(upper_bound),
+        LayoutCommand::DecreaseStackSize => layout.decrease_stack_size(),
+        LayoutCommand::ChangeStackSize(delta, upper_bound) => {
+            layout.change_stack_size(delta, upper_bound);
+        }
+        LayoutCommand::SetGaps(inner) => layout.set_gaps(inner),
+        LayoutCommand::IncreaseGaps => layout.increase_gaps(),
+        LayoutCommand::DecreaseGaps => layout.decrease_gaps(),
+        LayoutCommand::ChangeGaps(delta, upper_bound) => layout.change_gaps(delta, upper_bound),
+        LayoutCommand::SetMainCount(count) => layout.set_main_window_count(count),
+        LayoutCommand::IncreaseMainCount => layout.increase_main_window_count(),
+        LayoutCommand::DecreaseMainCount => layout.decrease_main_window_count(),
+        LayoutCommand::RotateClockwise => layout.rotate(true),
+        LayoutCommand::RotateCounterClockwise => layout.rotate(false),
+        LayoutCommand::RotateColumn(kind, clockwise) => layout.rotate_column(kind, clockwise),
+        LayoutCommand::ToggleFlipHorizontal => layout.toggle_flip_horizontal(),
+        LayoutCommand::ToggleFlipVertical => layout.toggle_flip_vertical(),
+        LayoutCommand::ToggleColumnsFlipHorizontal => layout.toggle_columns_flip_horizontal(),
+        LayoutCommand::ToggleColumnsFlipVertical => layout.toggle_columns_flip_vertical(),
+        LayoutCommand::SwapMainAndStack => layout.swap_main_and_stack(),
+        LayoutCommand::ToggleSecondStack => layout.toggle_second_stack(),
+        LayoutCommand::AddColumn(at, column) => layout.add_column(at, column),
+        LayoutCommand::RemoveColumn(at) => {
+            layout.remove_column(at);
+        }
+        LayoutCommand::SetSplit(kind, split) => match kind {
+            ColumnKind::Main => layout.set_main_split(split),
+            ColumnKind::SecondMain => {
+                if let Some(second_main) = layout.columns.second_main.as_mut() {
+                    second_main.split = split;
+                }
+            }
+            ColumnKind::Stack => layout.set_stack_split(split),
+            ColumnKind::SecondStack => {
+                if let Some(split) = split {
+                    layout.set_second_stack_split(split);
+                }
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LayoutCommand;
+    use crate::{
+        geometry::{Flip, Rotation, Size, Split},
+        layouts::{Column, ColumnKind, Layouts},
+        Layout,
+    };
+
+    #[test]
+    fn commands_match_the_methods() {
+        let mut by_command = Layout::default();
+        let mut by_method = Layout::default();
+        for command in [
+            LayoutCommand::IncreaseMainSize(500),
+            LayoutCommand::IncreaseMainCount,
+            LayoutCommand::RotateClockwise,
+            LayoutCommand::ToggleFlipHorizontal,
+            LayoutCommand::ToggleColumnsFlipVertical,
+        ] {
+            by_command.apply_command(command);
+        }
+        by_method.increase_main_size(500);
+        by_method.increase_main_window_count();
+        by_method.rotate(true);
+        by_method.toggle_flip_horizontal();
+        by_method.toggle_columns_flip_vertical();
+        assert_eq!(by_method, by_command);
+        assert_eq!(Rotation::East, by_command.rotate);
+        assert_eq!(Flip::Horizontal, by_command.flip);
+    }
+
+    #[test]
+    fn set_split_only_changes_existing_columns() {
+        let mut layout = Layouts::default().get("CenterMain").unwrap().clone();
+        layout.apply_command(LayoutCommand::SetSplit(ColumnKind::Stack, None));
+        layout.apply_command(LayoutCommand::SetSplit(
+            ColumnKind::SecondStack,
+            Some(Split::Grid),
+        ));
+        assert_eq!(None, layout.columns.stack.split);
+        assert_eq!(
+            Split::Grid,
+            layout.columns.second_stack.as_ref().unwrap().split
+        );
+
+        let before = layout.clone();
+        layout.apply_command(LayoutCommand::SetSplit(ColumnKind::SecondStack, None));
+        layout.apply_command(LayoutCommand::SetSplit(
+            ColumnKind::SecondMain,
+            Some(Split::Grid),
+        ));
+        assert_eq!(before, layout);
+    }
+
+    #[test]
+    fn commands_survive_a_serde_round_trip() {
+        let command = LayoutCommand::SetMainSize(Size::Ratio(0.6));
+        let json = serde_json::to_string(&command).unwrap();
+        assert_eq!(r#"{"SetMainSize":0.6}"#, json);
+        assert_eq!(command, serde_json::from_str(&json).unwrap());
+    }
+
+    #[test]
+    fn column_commands_survive_a_serde_round_trip() {
+        let command = LayoutCommand::AddColumn(
+            1,
+            Column {
+                kind: ColumnKind::Main,
+                ..Default::default()
+            },
+        );
+        let json = serde_json::to_string(&command).unwrap();
+        assert_eq!(command, serde_json::from_str(&json).unwrap());
+
+        let mut layout = Layout::default();
+        layout.apply_command(command);
+        layout.apply_command(LayoutCommand::AddColumn(0, Column::default()));
+        assert_eq!(ColumnKind::Main, layout.columns.list[1].kind);
+        layout.apply_command(LayoutCommand::RemoveColumn(1));
+        let main = Column {
+            kind: ColumnKind::Main,
+            ..Default::default()
+        };
+        assert_eq!(
+            vec![Column::default(), main, Column::default()],
+            layout.columns.list
+        );
+    }
+}
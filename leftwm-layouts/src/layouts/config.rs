@@ -0,0 +1,424 @@
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::{canonical::Canonical, layout::SerializedLayouts, LayoutIssue, Layouts};
+
+/// The reasons loading or saving [`Layouts`] can fail,
+/// see [`Layouts::from_config`], [`Layouts::from_json`] and [`Layouts::from_yaml`].
+#[derive(Debug)]
+pub enum ConfigError {
+    /// The config file can't be read.
+    Io(PathBuf, io::Error),
+
+    /// The config is not a valid list of layouts. The position of the error
+    /// is reported as line and column, both starting at `1`, if it is known.
+    Parse {
+        position: Option<(usize, usize)>,
+        message: String,
+    },
+
+    /// The layouts can't be written in the requested format.
+    Serialize(String),
+
+    /// The config contains invalid layouts, each issue comes with
+    /// the name of the affected layout, see [`Layout::validate`].
+    Invalid(Vec<(String, LayoutIssue)>),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Io(path, error) => {
+                write!(f, "can't read the layouts at {}: {error}", path.display())
+            }
+            ConfigError::Parse {
+                position: Some((line, column)),
+                message,
+            } => write!(
+                f,
+                "can't parse the layouts at line {line}, column {column}: {message}"
+            ),
+            ConfigError::Parse {
+                position: None,
+                message,
+            } => write!(f, "can't parse the layouts: {message}"),
+            ConfigError::Serialize(message) => write!(f, "can't write the layouts: {message}"),
+            ConfigError::Invalid(issues) => {
+                write!(f, "invalid layouts:")?;
+                for (name, issue) in issues {
+                    write!(f, "\n  {name}: {issue}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ConfigError::Io(_, error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// The formats [`Layouts`] can be loaded from and saved as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Format {
+    #[cfg(feature = "ron")]
+    Ron,
+    #[cfg(feature = "json")]
+    Json,
+    #[cfg(feature = "yaml")]
+    Yaml,
+}
+
+pub(super) fn load(
+    path: &Path,
+    format: Format,
+    include_defaults: bool,
+) -> Result<Layouts, ConfigError> {
+    let config =
+        fs::read_to_string(path).map_err(|error| ConfigError::Io(path.to_path_buf(), error))?;
+    parse(&config, format, include_defaults)
+}
+
+pub(super) fn parse(
+    config: &str,
+    format: Format,
+    include_defaults: bool,
+) -> Result<Layouts, ConfigError> {
+    // a config is either a plain list of layouts, or the layouts along
+    // with their sets, suggestions and the defaults they inherit
+    let serialized = if is_list(config, format) {
+        SerializedLayouts {
+            layouts: from_str(config, format)?,
+            ..Default::default()
+        }
+    } else {
+        from_str(config, format)?
+    };
+    // a layout named like a default layout only needs to contain what it changes
+    let custom = serialized.build(Some(&Layouts::default()));
+    let issues: Vec<(String, LayoutIssue)> = custom
+        .validate()
+        .into_iter()
+        .map(|(index, issue)| (custom[index].name.clone(), issue))
+        .collect();
+    if !issues.is_empty() {
+        return Err(ConfigError::Invalid(issues));
+    }
+
+    if include_defaults {
+        // a custom layout replaces the default layout of the same name
+        let mut layouts = Layouts::default();
+        // the default layouts inherit the defaults of the config as well
+        layouts.apply_defaults(custom.defaults.clone());
+        layouts.suggestions = custom.suggestions.clone();
+        layouts.merge(custom);
+        Ok(layouts)
+    } else {
+        Ok(custom)
+    }
+}
+
+fn from_str<T: DeserializeOwned>(config: &str, format: Format) -> Result<T, ConfigError> {
+    match format {
+        #[cfg(feature = "ron")]
+        Format::Ron => ron::from_str(config).map_err(|error| ConfigError::Parse {
+            position: Some((error.position.line, error.position.col)),
+            message: error.code.to_string(),
+        }),
+        #[cfg(feature = "json")]
+        Format::Json => serde_json::from_str(config).map_err(|error| ConfigError::Parse {
+            position: Some((error.line(), error.column())),
+            message: error.to_string(),
+        }),
+        #[cfg(feature = "yaml")]
+        Format::Yaml => serde_norway::from_str(config).map_err(|error| ConfigError::Parse {
+            position: error
+                .location()
+                .map(|location| (location.line(), location.column())),
+            message: error.to_string(),
+        }),
+    }
+}
+
+/// Whether the config is a plain list of layouts, judging by
+/// the first line that is neither empty nor a comment.
+fn is_list(config: &str, format: Format) -> bool {
+    let comment = match format {
+        #[cfg(feature = "ron")]
+        Format::Ron => "//",
+        #[cfg(feature = "json")]
+        Format::Json => "//",
+        #[cfg(feature = "yaml")]
+        Format::Yaml => "#",
+    };
+    config
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty() && !line.starts_with(comment))
+        .is_some_and(|line| line.starts_with('[') || line.starts_with('-'))
+}
+
+/// Write the layouts as a list in the provided format, which can be parsed again.
+/// A canonical list only contains the fields that differ from their default, see [`Canonical`].
+pub(super) fn serialize(
+    layouts: &Layouts,
+    format: Format,
+    canonical: bool,
+) -> Result<String, ConfigError> {
+    if canonical {
+        write(&Canonical(layouts.layouts()), format)
+    } else {
+        write(layouts.layouts(), format)
+    }
+}
+
+fn write<T: Serialize + ?Sized>(layouts: &T, format: Format) -> Result<String, ConfigError> {
+    match format {
+        #[cfg(feature = "ron")]
+        Format::Ron => ron::ser::to_string_pretty(layouts, ron::ser::PrettyConfig::default())
+            .map_err(|error| ConfigError::Serialize(error.to_string())),
+        #[cfg(feature = "json")]
+        Format::Json => serde_json::to_string_pretty(layouts)
+            .map_err(|error| ConfigError::Serialize(error.to_string())),
+        #[cfg(feature = "yaml")]
+        Format::Yaml => serde_norway::to_string(layouts)
+            .map_err(|error| ConfigError::Serialize(error.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ConfigError;
+    #[cfg(any(feature = "json", feature = "yaml"))]
+    use crate::layouts::Layouts;
+
+    #[cfg(feature = "ron")]
+    mod ron {
+        use super::ConfigError;
+        use crate::{
+            geometry::{Reserve, Size, Split},
+            layouts::Layouts,
+        };
+
+        const CONFIG: &str = r#"[
+            (
+                name: "Wide",
+                columns: (
+                    main: Some((size: 0.7)),
+                ),
+            ),
+            (
+                name: "Monocle",
+                columns: (main: None, stack: (split: None)),
+                rotate: East,
+            ),
+        ]"#;
+
+        #[test]
+        fn config_replaces_and_extends_the_defaults() {
+            let layouts = Layouts::from_ron(CONFIG, true).unwrap();
+            assert_eq!(Layouts::default().len() + 1, layouts.len());
+            let wide = layouts.get("Wide").unwrap();
+            assert_eq!(Some(Size::Ratio(0.7)), wide.main_size());
+            let monocle_index = Layouts::default().get_index("Monocle");
+            assert_eq!(monocle_index, layouts.get_index("Monocle"));
+            assert_ne!(Layouts::default().get("Monocle"), layouts.get("Monocle"));
+
+            let custom = Layouts::from_ron(CONFIG, false).unwrap();
+            assert_eq!(vec!["Wide", "Monocle"], custom.names().collect::<Vec<_>>());
+        }
+
+        #[test]
+        fn layouts_inherit_the_defaults_of_the_config() {
+            let config = r#"// shared by all layouts
+            (
+                defaults: (reserve: Some(Reserve), main_size: Some(0.6)),
+                layouts: [
+                    (name: "Wide", columns: (main: Some((size: 0.7)))),
+                    (name: "Plain"),
+                ],
+            )"#;
+            let custom = Layouts::from_ron(config, false).unwrap();
+            assert_eq!(Reserve::Reserve, custom.get("Wide").unwrap().reserve);
+            assert_eq!(
+                Some(Size::Ratio(0.7)),
+                custom.get("Wide").unwrap().main_size()
+            );
+            assert_eq!(
+                Some(Size::Ratio(0.6)),
+                custom.get("Plain").unwrap().main_size()
+            );
+
+            let layouts = Layouts::from_ron(config, true).unwrap();
+            let monocle = layouts.get("Monocle").unwrap();
+            assert_eq!(Reserve::Reserve, monocle.reserve);
+            assert_eq!(None, monocle.main_size());
+            assert_eq!(layouts, Layouts::from_ron(config, true).unwrap());
+        }
+
+        #[test]
+        fn layouts_keep_the_values_they_set_to_the_default() {
+            let config = r#"(
+                defaults: (reserve: Some(Reserve), main_size: Some(0.6), main_count: Some(2)),
+                layouts: [
+                    (name: "Plain", reserve: None, columns: (main: Some((size: 0.5, count: 1)))),
+                    (name: "MainAndVertStack", reserve: None),
+                ],
+            )"#;
+            let layouts = Layouts::from_ron(config, true).unwrap();
+            let plain = layouts.get("Plain").unwrap();
+            assert_eq!(Reserve::None, plain.reserve);
+            assert_eq!(Some(Size::Ratio(0.5)), plain.main_size());
+            assert_eq!(Some(1), plain.main_window_count());
+            let main_and_vert_stack = layouts.get("MainAndVertStack").unwrap();
+            assert_eq!(Reserve::None, main_and_vert_stack.reserve);
+            assert_eq!(Some(Size::Ratio(0.6)), main_and_vert_stack.main_size());
+            assert_eq!(Reserve::Reserve, layouts.get("Grid").unwrap().reserve);
+        }
+
+        #[test]
+        fn layouts_named_like_a_default_patch_it() {
+            let config = r#"[(name: "CenterMain", columns: (main: Some((size: 0.6))))]"#;
+            let layouts = Layouts::from_ron(config, true).unwrap();
+            let mut expected = Layouts::default().get("CenterMain").unwrap().clone();
+            expected.set_main_size(Size::Ratio(0.6));
+            assert_eq!(Some(&expected), layouts.get("CenterMain"));
+
+            let mut custom = Layouts::from_ron(config, false).unwrap();
+            custom.get_mut("CenterMain").unwrap().rotate(true);
+            assert!(custom.reset("CenterMain"));
+            assert_eq!(Some(&expected), custom.get("CenterMain"));
+        }
+
+        #[test]
+        fn patches_can_set_fields_back_to_their_default() {
+            let config = r#"[
+                (name: "CenterMainFluid", reserve: None),
+                (name: "MainAndDeck", columns: (stack: (split: Some(Horizontal)))),
+            ]"#;
+            let layouts = Layouts::from_ron(config, true).unwrap();
+            assert_eq!(
+                Reserve::None,
+                layouts.get("CenterMainFluid").unwrap().reserve
+            );
+            assert_eq!(
+                Some(Split::Horizontal),
+                layouts.get("MainAndDeck").unwrap().columns.stack.split
+            );
+        }
+
+        #[test]
+        fn parse_errors_point_at_the_position() {
+            let error =
+                Layouts::from_ron("[(name: \"Broken\",\n  flip: Sideways)]", false).unwrap_err();
+            assert!(matches!(error, ConfigError::Parse { .. }));
+            assert!(error
+                .to_string()
+                .starts_with("can't parse the layouts at line 2"));
+        }
+
+        #[test]
+        fn invalid_layouts_are_rejected_by_name() {
+            let config = r#"[(name: "Huge", columns: (main: Some((size: 1.5))))]"#;
+            let error = Layouts::from_ron(config, false).unwrap_err();
+            assert_eq!(
+                "invalid layouts:\n  Huge: the size 1.5 of the Main column is not between 0.0 and 1.0",
+                error.to_string()
+            );
+        }
+
+        #[test]
+        fn duplicate_names_in_the_config_are_rejected() {
+            let config = r#"[(name: "Grid"), (name: "Grid", rotate: East)]"#;
+            let error = Layouts::from_ron(config, true).unwrap_err();
+            assert_eq!(
+                "invalid layouts:\n  Grid: there is another layout named Grid",
+                error.to_string()
+            );
+        }
+
+        #[test]
+        fn missing_files_name_the_path() {
+            let error = Layouts::from_config("/nonexistent/layouts.ron").unwrap_err();
+            assert!(matches!(error, ConfigError::Io(..)));
+            assert!(error.to_string().contains("/nonexistent/layouts.ron"));
+        }
+
+        #[test]
+        fn layouts_round_trip_through_ron() {
+            let layouts = Layouts::default();
+            let config = layouts.to_ron().unwrap();
+            assert_eq!(layouts, Layouts::from_ron(&config, false).unwrap());
+        }
+
+        #[test]
+        fn canonical_ron_is_stable() {
+            let mut layouts = Layouts::default();
+            layouts
+                .get_mut("CenterMain")
+                .unwrap()
+                .set_main_size(Size::Ratio(0.6));
+            let mut custom = layouts.get("Grid").unwrap().clone();
+            custom.name = "Custom".to_string();
+            custom.toggle_second_stack();
+            layouts.push(custom);
+
+            let config = layouts.to_canonical_ron().unwrap();
+            let loaded = Layouts::from_ron(&config, false).unwrap();
+            assert_eq!(layouts, loaded);
+            assert_eq!(config, loaded.to_canonical_ron().unwrap());
+            assert!(config.len() < layouts.to_ron().unwrap().len() / 4);
+        }
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn layouts_round_trip_through_json() {
+        let layouts = Layouts::default();
+        let config = layouts.to_json().unwrap();
+        assert_eq!(layouts, Layouts::from_json(&config, false).unwrap());
+        let canonical = layouts.to_canonical_json().unwrap();
+        assert_eq!(layouts, Layouts::from_json(&canonical, false).unwrap());
+        let error = Layouts::from_json("[{\"name\": 3}]", false).unwrap_err();
+        assert!(matches!(
+            error,
+            ConfigError::Parse {
+                position: Some((1, _)),
+                ..
+            }
+        ));
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn layouts_round_trip_through_yaml() {
+        let layouts = Layouts::default();
+        let config = layouts.to_yaml().unwrap();
+        assert_eq!(layouts, Layouts::from_yaml(&config, false).unwrap());
+        let canonical = layouts.to_canonical_yaml().unwrap();
+        assert_eq!(layouts, Layouts::from_yaml(&canonical, false).unwrap());
+        let custom = Layouts::from_yaml(
+            "- name: Wide\n  columns:\n    main:\n      size: 0.7\n",
+            true,
+        );
+        assert_eq!(Layouts::default().len() + 1, custom.unwrap().len());
+    }
+
+    #[test]
+    fn parse_errors_without_position_are_described() {
+        let error = ConfigError::Parse {
+            position: None,
+            message: "unexpected end".to_string(),
+        };
+        assert_eq!("can't parse the layouts: unexpected end", error.to_string());
+    }
+}
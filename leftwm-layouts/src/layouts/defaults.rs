@@ -1,25 +1,85 @@
 use crate::{
-    geometry::{Reserve, Rotation, Split},
+    geometry::{Orientation, Reserve, Rotation, Size, Split},
     Layout,
 };
 
-use super::layout::{Columns, Main, SecondStack, Stack};
+use super::layout::{Column, ColumnKind, Columns, Main, SecondStack, Stack};
 
-const EVEN_HORIZONTAL: &str = "EvenHorizontal";
-const EVEN_VERTICAL: &str = "EvenVertical";
-const MONOCLE: &str = "Monocle";
-const GRID: &str = "Grid";
+/// Name of the [`even_horizontal`] layout.
+pub const EVEN_HORIZONTAL: &str = "EvenHorizontal";
+/// Name of the [`even_vertical`] layout.
+pub const EVEN_VERTICAL: &str = "EvenVertical";
+/// Name of the [`monocle`] layout.
+pub const MONOCLE: &str = "Monocle";
+/// Name of the [`grid`] layout.
+pub const GRID: &str = "Grid";
 
-const MAIN_AND_VERT_STACK: &str = "MainAndVertStack";
-const MAIN_AND_HORIZONTAL_STACK: &str = "MainAndHorizontalStack";
-const RIGHT_MAIN_AND_VERT_STACK: &str = "RightMainAndVertStack";
-const FIBONACCI: &str = "Fibonacci";
-const DWINDLE: &str = "Dwindle";
-const MAIN_AND_DECK: &str = "MainAndDeck";
+/// Name of the [`main_and_vert_stack`] layout.
+pub const MAIN_AND_VERT_STACK: &str = "MainAndVertStack";
+/// Name of the [`main_and_horizontal_stack`] layout.
+pub const MAIN_AND_HORIZONTAL_STACK: &str = "MainAndHorizontalStack";
+/// Name of the [`right_main_and_vert_stack`] layout.
+pub const RIGHT_MAIN_AND_VERT_STACK: &str = "RightMainAndVertStack";
+/// Name of the [`fibonacci`] layout.
+pub const FIBONACCI: &str = "Fibonacci";
+/// Name of the [`dwindle`] layout.
+pub const DWINDLE: &str = "Dwindle";
+/// Name of the [`main_and_deck`] layout.
+pub const MAIN_AND_DECK: &str = "MainAndDeck";
+/// Name of the [`top_main_and_stack`] layout.
+pub const TOP_MAIN_AND_STACK: &str = "TopMainAndStack";
+/// Name of the [`main_and_grid_stack`] layout.
+pub const MAIN_AND_GRID_STACK: &str = "MainAndGridStack";
+/// Name of the [`three_column_equal`] layout.
+pub const THREE_COLUMN_EQUAL: &str = "ThreeColumnEqual";
+/// Name of the [`main_and_dual_stack`] layout.
+pub const MAIN_AND_DUAL_STACK: &str = "MainAndDualStack";
 
-const CENTER_MAIN: &str = "CenterMain";
-const CENTER_MAIN_BALANCED: &str = "CenterMainBalanced";
-const CENTER_MAIN_FLUID: &str = "CenterMainFluid";
+/// Name of the [`center_main`] layout.
+pub const CENTER_MAIN: &str = "CenterMain";
+/// Name of the [`center_main_balanced`] layout.
+pub const CENTER_MAIN_BALANCED: &str = "CenterMainBalanced";
+/// Name of the [`center_main_fluid`] layout.
+pub const CENTER_MAIN_FLUID: &str = "CenterMainFluid";
+
+/// Name of the [`dual_main`] layout.
+pub const DUAL_MAIN: &str = "DualMain";
+
+/// Name of the [`bsp`] layout.
+pub const BSP: &str = "BSP";
+
+/// Names of all the default layouts, in the same order
+/// as they appear in [`crate::layouts::Layouts::default`].
+///
+/// ## Example
+/// ```rust
+/// use leftwm_layouts::layouts::defaults::{all_names, MONOCLE};
+///
+/// assert!(all_names().contains(&MONOCLE));
+/// ```
+pub fn all_names() -> &'static [&'static str] {
+    &[
+        EVEN_HORIZONTAL,
+        EVEN_VERTICAL,
+        MONOCLE,
+        GRID,
+        MAIN_AND_VERT_STACK,
+        MAIN_AND_HORIZONTAL_STACK,
+        RIGHT_MAIN_AND_VERT_STACK,
+        FIBONACCI,
+        DWINDLE,
+        MAIN_AND_DECK,
+        CENTER_MAIN,
+        CENTER_MAIN_BALANCED,
+        CENTER_MAIN_FLUID,
+        DUAL_MAIN,
+        BSP,
+        TOP_MAIN_AND_STACK,
+        MAIN_AND_GRID_STACK,
+        THREE_COLUMN_EQUAL,
+        MAIN_AND_DUAL_STACK,
+    ]
+}
 
 /// Layout which gives each window full height, but splits the workspace width among them all.
 /// This layout has only one stack and no main column.
@@ -333,6 +393,7 @@ pub fn center_main() -> Layout {
                 ..Default::default()
             },
             second_stack: Some(SecondStack::default()),
+            balance_stacks: Some(false),
             ..Default::default()
         },
         ..Default::default()
@@ -372,6 +433,7 @@ pub fn center_main_balanced() -> Layout {
                 split: Split::Dwindle,
                 ..Default::default()
             }),
+            balance_stacks: Some(true),
             ..Default::default()
         },
         ..Default::default()
@@ -423,9 +485,535 @@ pub fn center_main_fluid() -> Layout {
                 ..Default::default()
             },
             second_stack: Some(SecondStack::default()),
+            balance_stacks: Some(false),
             ..Default::default()
         },
         reserve: Reserve::Reserve,
         ..Default::default()
     }
 }
+
+/// Layout which splits the workspace into three columns (main | stack | second main),
+/// meant for ultrawide monitors with two windows to focus on.
+///
+/// * Puts first window into left (main) column
+/// * Puts second window into right (second main) column
+/// * Puts rest of windows into middle (stack) column
+///
+/// ```text
+///                  2nd
+///   main   stack   main
+/// +-------+------+-------+
+/// |       |  3   |       |
+/// |       +------+       |
+/// |   1   |  4   |   2   |
+/// |       +------+       |
+/// |       |  5   |       |
+/// +-------+------+-------+
+///
+/// +-----------+-----------+
+/// |           |           |
+/// |           |           |  unoccupied
+/// |     1     |     2     |  space is
+/// |           |           |  taken over
+/// |           |           |
+/// +-----------+-----------+
+/// ```
+pub fn dual_main() -> Layout {
+    Layout {
+        name: DUAL_MAIN.to_string(),
+        columns: Columns {
+            main: Some(Main {
+                size: Size::Ratio(0.35),
+                ..Default::default()
+            }),
+            second_main: Some(Main {
+                size: Size::Ratio(0.35),
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+/// Layout which splits the workspace like a binary space partition,
+/// similar to the automatic splitting scheme of `bspwm`.
+///
+/// * Has no main column, all windows are in the stack
+/// * Every additional window splits the largest tile in half along its longer side,
+///   see [`Split::BinaryTree`]
+///
+/// ```txt
+/// +-----+-----+   +-----+-----+
+/// |     |     |   |  1  |     |
+/// |     |     |   |     |     |
+/// |  1  |  2  |   +-----+  2  |
+/// |     |     |   |  3  |     |
+/// |     |     |   |     |     |
+/// +-----+-----+   +-----+-----+
+///   2 windows       3 windows
+///
+/// +-----+-----+   +--+--+-----+
+/// |  1  |  2  |   |  |  |  2  |
+/// |     |     |   |1 |5 |     |
+/// +-----+-----+   +--+--+-----+
+/// |  3  |  4  |   |  3  |  4  |
+/// |     |     |   |     |     |
+/// +-----+-----+   +-----+-----+
+///   4 windows       5 windows
+/// ```
+pub fn bsp() -> Layout {
+    Layout {
+        name: BSP.to_string(),
+        columns: Columns {
+            main: None,
+            stack: Stack {
+                split: Some(Split::BinaryTree(0.5)),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+/// Layout which splits the workspace into two rows (main on top of the stack).
+/// Other than rotating [`main_and_horizontal_stack`], the columns are arranged
+/// by the [`Orientation::Vertical`], so the windows of the stack are placed next
+/// to each other instead of becoming thin slices.
+///
+/// * Puts first N (`main_window_count`) windows into the top (main) row
+/// * Puts rest of windows next to each other into the bottom (stack) row
+///
+/// ```txt
+/// +-----------+   +-----------+
+/// |           |   |           |
+/// |     1     |   |     1     |
+/// |           |   |           |
+/// +-----+-----+   +---+---+---+
+/// |     |     |   |   |   |   |
+/// |  2  |  3  |   | 2 | 3 | 4 |
+/// |     |     |   |   |   |   |
+/// +-----+-----+   +---+---+---+
+///   3 windows       4 windows
+/// ```
+pub fn top_main_and_stack() -> Layout {
+    Layout {
+        name: TOP_MAIN_AND_STACK.to_string(),
+        columns: Columns {
+            orientation: Orientation::Vertical,
+            main: Some(Main::default()),
+            stack: Stack {
+                split: Some(Split::Vertical),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+/// Layout which splits the workspace into two columns (main and stack).
+/// The stack is arranged in a [`Split::Grid`], which keeps the secondary windows
+/// readable even when there are many of them, eg. for monitoring dashboards.
+///
+/// * Puts first N (`main_window_count`) windows into the left (main) column
+/// * Puts rest of windows into a grid in the right (stack) column
+///
+/// ```txt
+/// +-----+--+--+
+/// |     |  |  |
+/// |     |  |  |
+/// |  1  +--+--+
+/// |     |  |  |
+/// |     |  |  |
+/// +-----+--+--+
+///   main  stack
+/// ```
+pub fn main_and_grid_stack() -> Layout {
+    Layout {
+        name: MAIN_AND_GRID_STACK.to_string(),
+        columns: Columns {
+            main: Some(Main::default()),
+            stack: Stack {
+                split: Some(Split::Grid),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+/// Layout which splits the workspace into three columns of equal width
+/// (main | stack | second main). Other than [`center_main`] with a `main` size
+/// of a third, the outer columns never balance the stack between them.
+///
+/// * Puts first window into left (main) column
+/// * Puts second window into right (second main) column
+/// * Puts rest of windows into middle (stack) column
+///
+/// ```txt
+/// +-------+-------+-------+
+/// |       |   3   |       |
+/// |       +-------+       |
+/// |   1   |   4   |   2   |
+/// |       +-------+       |
+/// |       |   5   |       |
+/// +-------+-------+-------+
+///
+/// +-----------+-----------+
+/// |           |           |
+/// |           |           |  unoccupied
+/// |     1     |     2     |  space is
+/// |           |           |  taken over
+/// |           |           |
+/// +-----------+-----------+
+/// ```
+pub fn three_column_equal() -> Layout {
+    Layout {
+        name: THREE_COLUMN_EQUAL.to_string(),
+        columns: Columns {
+            main: Some(Main {
+                size: Size::Ratio(1.0 / 3.0),
+                ..Default::default()
+            }),
+            second_main: Some(Main {
+                size: Size::Ratio(1.0 / 3.0),
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+/// Layout with the main column on the left, which splits the rest of the workspace
+/// into two separate stack columns (see [`Columns::list`]), keeping the windows
+/// of the stacks from getting too narrow on 16:9 monitors.
+///
+/// * Puts the first window into the left (main) column
+/// * Puts the next two windows into the first stack column
+/// * Puts the rest of the windows into the second stack column
+///
+/// ```txt
+/// +-------+---+---+   +-------+---+---+
+/// |       |   |   |   |       |   |   |
+/// |       | 2 |   |   |       | 2 | 4 |
+/// |   1   +---+ 4 |   |   1   +---+---+
+/// |       |   |   |   |       |   |   |
+/// |       | 3 |   |   |       | 3 | 5 |
+/// +-------+---+---+   +-------+---+---+
+///     4 windows           5 windows
+/// ```
+pub fn main_and_dual_stack() -> Layout {
+    let stack = |kind, capacity| Column {
+        kind,
+        capacity,
+        split: Some(Split::Horizontal),
+        ..Default::default()
+    };
+    Layout {
+        name: MAIN_AND_DUAL_STACK.to_string(),
+        columns: Columns {
+            main: None,
+            list: vec![
+                Column {
+                    kind: ColumnKind::Main,
+                    size: Some(Size::Ratio(0.5)),
+                    capacity: Some(1),
+                    ..Default::default()
+                },
+                stack(ColumnKind::Stack, Some(2)),
+                stack(ColumnKind::SecondStack, None),
+            ],
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //! Contract tests keeping the diagrams in the documentation of the default
+    //! layouts in sync with the tiles actually returned by [`crate::apply`].
+
+    use crate::{apply_detailed, geometry::Rect, layouts::Layouts};
+
+    /// Layouts whose diagrams only sketch the idea of the layout
+    /// and can't be parsed into proportions (eg. nested tiles drawn as `|--|`).
+    const SCHEMATIC: [&str; 3] = ["EvenVertical", "Fibonacci", "Dwindle"];
+
+    const CONTAINER: Rect = Rect {
+        x: 0,
+        y: 0,
+        w: 1920,
+        h: 1080,
+    };
+
+    /// A rectangular area of a diagram, in proportions of the whole diagram.
+    #[derive(Debug)]
+    struct Region {
+        x0: f32,
+        y0: f32,
+        x1: f32,
+        y1: f32,
+        /// The window number written inside the region, if any.
+        label: Option<usize>,
+        /// Whether the region is filled with `.`, which marks reserved space.
+        reserved: bool,
+    }
+
+    impl Region {
+        fn matches(&self, tile: &Rect, tolerance: (f32, f32)) -> bool {
+            let x0 = (tile.x - CONTAINER.x) as f32 / CONTAINER.w as f32;
+            let y0 = (tile.y - CONTAINER.y) as f32 / CONTAINER.h as f32;
+            let x1 = x0 + tile.w as f32 / CONTAINER.w as f32;
+            let y1 = y0 + tile.h as f32 / CONTAINER.h as f32;
+            (self.x0 - x0).abs() <= tolerance.0
+                && (self.x1 - x1).abs() <= tolerance.0
+                && (self.y0 - y0).abs() <= tolerance.1
+                && (self.y1 - y1).abs() <= tolerance.1
+        }
+    }
+
+    /// A single box drawn in a diagram.
+    #[derive(Debug)]
+    struct Diagram {
+        /// Name of the default layout the diagram documents.
+        layout: String,
+        regions: Vec<Region>,
+        /// Tolerance of one and a half characters, horizontally and vertically,
+        /// as the diagrams are drawn by hand.
+        tolerance: (f32, f32),
+    }
+
+    impl Diagram {
+        /// Whether the regions tile the diagram without holes or overlaps.
+        fn is_proportional(&self) -> bool {
+            let area: f32 = self
+                .regions
+                .iter()
+                .map(|r| (r.x1 - r.x0) * (r.y1 - r.y0))
+                .sum();
+            (area - 1.0).abs() < 0.001
+        }
+    }
+
+    /// Collect the diagrams of all `txt`/`text` code blocks in the provided
+    /// source code, assigned to the layout of the function following them.
+    /// Only doc comments of top-level items are considered.
+    fn parse_diagrams(source: &str) -> Vec<Diagram> {
+        let mut diagrams = vec![];
+        let mut pending = vec![];
+        let mut block: Option<Vec<&str>> = None;
+        for line in source.lines() {
+            if let Some(doc) = line.strip_prefix("///") {
+                let doc = doc.strip_prefix(' ').unwrap_or(doc);
+                match (&mut block, doc) {
+                    (None, "```txt" | "```text") => block = Some(vec![]),
+                    (Some(lines), "```") => {
+                        pending.append(&mut parse_boxes(lines));
+                        block = None;
+                    }
+                    (Some(lines), _) => lines.push(doc),
+                    (None, _) => {}
+                }
+            } else if let Some(signature) = line.strip_prefix("pub fn ") {
+                let fn_name = signature.split('(').next().unwrap_or_default();
+                diagrams.extend(pending.drain(..).map(|(regions, tolerance)| Diagram {
+                    layout: camel_case(fn_name),
+                    regions,
+                    tolerance,
+                }));
+            }
+        }
+        diagrams
+    }
+
+    /// The words of function names which are spelled in capitals in the layout names.
+    const ACRONYMS: [&str; 1] = ["bsp"];
+
+    fn camel_case(snake_case: &str) -> String {
+        snake_case
+            .split('_')
+            .map(|word| {
+                if ACRONYMS.contains(&word) {
+                    return word.to_uppercase();
+                }
+                let mut chars = word.chars();
+                chars
+                    .next()
+                    .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+
+    fn is_wall(c: char) -> bool {
+        matches!(c, '+' | '-' | '|')
+    }
+
+    /// Find all boxes in the provided lines and split them into their regions.
+    fn parse_boxes(lines: &[&str]) -> Vec<(Vec<Region>, (f32, f32))> {
+        let grid: Vec<Vec<char>> = lines.iter().map(|l| l.chars().collect()).collect();
+        let at = |r: usize, c: usize| grid.get(r).and_then(|row| row.get(c)).copied();
+
+        let mut boxes = vec![];
+        for (r0, row) in grid.iter().enumerate() {
+            for (c0, &c) in row.iter().enumerate() {
+                let top_left = c == '+'
+                    && (c0 == 0 || !matches!(at(r0, c0 - 1), Some('-' | '+')))
+                    && (r0 == 0 || !matches!(at(r0 - 1, c0), Some('|' | '+')))
+                    && at(r0, c0 + 1) == Some('-')
+                    && at(r0 + 1, c0) == Some('|');
+                if !top_left {
+                    continue;
+                }
+                let c1 = (c0..)
+                    .take_while(|&c| matches!(at(r0, c), Some('-' | '+')))
+                    .last()
+                    .unwrap_or(c0);
+                let r1 = (r0..)
+                    .take_while(|&r| matches!(at(r, c0), Some('|' | '+')))
+                    .last()
+                    .unwrap_or(r0);
+                let tolerance = (1.5 / (c1 - c0) as f32, 1.5 / (r1 - r0) as f32);
+                boxes.push((regions(&grid, (r0, c0), (r1, c1)), tolerance));
+            }
+        }
+        boxes
+    }
+
+    /// Flood fill the inside of the box between the provided corners.
+    fn regions(
+        grid: &[Vec<char>],
+        top_left: (usize, usize),
+        bottom_right: (usize, usize),
+    ) -> Vec<Region> {
+        let ((r0, c0), (r1, c1)) = (top_left, bottom_right);
+        let cell = |r: usize, c: usize| grid[r].get(c).copied().unwrap_or(' ');
+        let mut visited = vec![vec![false; c1 + 1]; r1 + 1];
+        let mut regions = vec![];
+        for r in r0 + 1..r1 {
+            for c in c0 + 1..c1 {
+                if visited[r][c] || is_wall(cell(r, c)) {
+                    continue;
+                }
+                let (mut min_r, mut min_c, mut max_r, mut max_c) = (r, c, r, c);
+                let mut text = String::new();
+                let mut todo = vec![(r, c)];
+                visited[r][c] = true;
+                while let Some((r, c)) = todo.pop() {
+                    text.push(cell(r, c));
+                    (min_r, min_c) = (min_r.min(r), min_c.min(c));
+                    (max_r, max_c) = (max_r.max(r), max_c.max(c));
+                    for (nr, nc) in [(r - 1, c), (r + 1, c), (r, c - 1), (r, c + 1)] {
+                        let inside = nr > r0 && nr < r1 && nc > c0 && nc < c1;
+                        if inside && !visited[nr][nc] && !is_wall(cell(nr, nc)) {
+                            visited[nr][nc] = true;
+                            todo.push((nr, nc));
+                        }
+                    }
+                }
+                let (w, h) = ((c1 - c0) as f32, (r1 - r0) as f32);
+                let digits: String = text.chars().filter(char::is_ascii_digit).collect();
+                regions.push(Region {
+                    x0: (min_c - 1 - c0) as f32 / w,
+                    y0: (min_r - 1 - r0) as f32 / h,
+                    x1: (max_c + 1 - c0) as f32 / w,
+                    y1: (max_r + 1 - r0) as f32 / h,
+                    label: digits.parse().ok(),
+                    reserved: text.contains('.'),
+                });
+            }
+        }
+        regions
+    }
+
+    #[test]
+    fn parse_diagram_with_labels_and_reserved_space() {
+        let source = [
+            "/// ```txt",
+            "/// +---+---+---+",
+            "/// |...| 1 | 2 |",
+            "/// |...|   +---+",
+            "/// |...|   | 3 |",
+            "/// +---+---+---+",
+            "/// ```",
+            "pub fn some_layout() -> Layout {",
+        ]
+        .join("\n");
+        let diagrams = parse_diagrams(&source);
+        assert_eq!(1, diagrams.len());
+        let diagram = &diagrams[0];
+        assert_eq!("SomeLayout", diagram.layout);
+        assert!(diagram.is_proportional());
+        let reserved = &diagram.regions[0];
+        assert!(reserved.reserved);
+        assert_eq!(
+            (0.0, 0.0, 4.0 / 12.0, 1.0),
+            (reserved.x0, reserved.y0, reserved.x1, reserved.y1)
+        );
+        let labels: Vec<Option<usize>> = diagram.regions.iter().map(|r| r.label).collect();
+        assert_eq!(vec![None, Some(1), Some(2), Some(3)], labels);
+    }
+
+    #[test]
+    fn default_layouts_behave_as_documented() {
+        let layouts = Layouts::default();
+        let diagrams = parse_diagrams(include_str!("defaults.rs"));
+        assert!(!diagrams.is_empty());
+
+        for diagram in diagrams {
+            if !diagram.is_proportional() {
+                assert!(
+                    SCHEMATIC.contains(&diagram.layout.as_str()),
+                    "the diagram of {} can't be parsed",
+                    diagram.layout
+                );
+                continue;
+            }
+
+            let layout = layouts
+                .iter()
+                .find(|layout| layout.name == diagram.layout)
+                .unwrap_or_else(|| panic!("no default layout named {}", diagram.layout));
+            let (reserved, windows): (Vec<&Region>, Vec<&Region>) =
+                diagram.regions.iter().partition(|r| r.reserved);
+            let tiling = apply_detailed(layout, windows.len(), &CONTAINER);
+
+            assert_eq!(windows.len(), tiling.tiles.len(), "{}", diagram.layout);
+            let mut matched = vec![false; tiling.tiles.len()];
+            for region in windows {
+                let index = tiling.tiles.iter().enumerate().position(|(i, tile)| {
+                    !matched[i]
+                        && region.label.is_none_or(|label| label == i + 1)
+                        && region.matches(tile, diagram.tolerance)
+                });
+                let Some(index) = index else {
+                    panic!(
+                        "{}: no tile matches {:?} in {:?}",
+                        diagram.layout, region, tiling.tiles
+                    );
+                };
+                matched[index] = true;
+            }
+
+            assert_eq!(reserved.len(), tiling.reserved.len(), "{}", diagram.layout);
+            for region in reserved {
+                assert!(
+                    tiling
+                        .reserved
+                        .iter()
+                        .any(|area| region.matches(area, diagram.tolerance)),
+                    "{}: no reserved area matches {:?} in {:?}",
+                    diagram.layout,
+                    region,
+                    tiling.reserved
+                );
+            }
+        }
+    }
+}
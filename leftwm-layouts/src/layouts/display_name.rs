@@ -0,0 +1,74 @@
+/// Resolves the user-facing name of a layout, for example
+/// to show a translated name in a status bar.
+///
+/// The [`crate::Layout::name`] is used as identifier in configs and IPC
+/// and should never be translated, a resolver maps it to a display name instead.
+/// Any closure of the form `Fn(&str, &str) -> Option<String>` can be used as resolver.
+///
+/// ## Example
+/// ```rust
+/// use leftwm_layouts::Layout;
+///
+/// let german = |name: &str, locale: &str| match (name, locale) {
+///     ("Monocle", "de") => Some("Vollbild".to_string()),
+///     _ => None,
+/// };
+///
+/// let layout = Layout {
+///     name: "Monocle".to_string(),
+///     ..Default::default()
+/// };
+/// assert_eq!("Vollbild", layout.display_name("de", &german));
+/// assert_eq!("Monocle", layout.display_name("fr", &german));
+/// ```
+pub trait DisplayNameResolver {
+    /// Returns the display name of the layout named `name` for the provided `locale`,
+    /// or [`None`] if there is no display name and the `name` shall be used as is.
+    fn resolve(&self, name: &str, locale: &str) -> Option<String>;
+}
+
+impl<F> DisplayNameResolver for F
+where
+    F: Fn(&str, &str) -> Option<String>,
+{
+    fn resolve(&self, name: &str, locale: &str) -> Option<String> {
+        self(name, locale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::DisplayNameResolver;
+    use crate::Layout;
+
+    struct Translations(HashMap<(&'static str, &'static str), &'static str>);
+
+    impl DisplayNameResolver for Translations {
+        fn resolve(&self, name: &str, locale: &str) -> Option<String> {
+            self.0
+                .iter()
+                .find(|((n, l), _)| *n == name && *l == locale)
+                .map(|(_, display_name)| display_name.to_string())
+        }
+    }
+
+    #[test]
+    fn resolver_provides_display_name() {
+        let mut map = HashMap::new();
+        map.insert(("Default", "es"), "Predeterminado");
+        let layout = Layout::default();
+        assert_eq!(
+            "Predeterminado",
+            layout.display_name("es", &Translations(map))
+        );
+    }
+
+    #[test]
+    fn falls_back_to_name_if_unresolved() {
+        let layout = Layout::default();
+        let resolver = |_: &str, _: &str| None;
+        assert_eq!("Default", layout.display_name("es", &resolver));
+    }
+}
@@ -1,38 +1,241 @@
-use std::cmp;
+use std::{
+    borrow::Cow,
+    cmp,
+    collections::HashMap,
+    ops::{Index, IndexMut},
+    sync::OnceLock,
+};
 
 use serde::{Deserialize, Serialize};
 
-use crate::geometry::{Flip, Reserve, Rotation, Size, Split};
-
-use super::defaults::{
-    center_main, center_main_balanced, center_main_fluid, dwindle, even_horizontal, even_vertical,
-    fibonacci, grid, main_and_deck, main_and_horizontal_stack, main_and_vert_stack, monocle,
-    right_main_and_vert_stack,
+use crate::geometry::{
+    self, Direction, Flip, Gaps, Margin, Orientation, Rect, Remainder, Reserve, ReservedGaps,
+    Rotation, Size, Spiral, Split, SplitOptions, TakeoverOrder,
+};
+use crate::Tiling;
+
+#[cfg(any(feature = "ron", feature = "json", feature = "yaml"))]
+use std::path::Path;
+
+#[cfg(any(feature = "ron", feature = "json", feature = "yaml"))]
+use super::config::{self, ConfigError, Format};
+use super::{
+    command::{self, LayoutCommand},
+    defaults::{
+        bsp, center_main, center_main_balanced, center_main_fluid, dual_main, dwindle,
+        even_horizontal, even_vertical, fibonacci, grid, main_and_deck, main_and_dual_stack,
+        main_and_grid_stack, main_and_horizontal_stack, main_and_vert_stack, monocle,
+        right_main_and_vert_stack, three_column_equal, top_main_and_stack,
+    },
+    lint::{self, LintWarning},
+    patch, resize,
+    validate::{self, LayoutIssue},
+    DisplayNameResolver, LayoutDefaults, LayoutPatch, LayoutSet, LayoutSuggestions,
 };
 
 const DEFAULT_MAIN_SIZE_CHANGE_PIXEL: i32 = 50;
 const DEFAULT_MAIN_SIZE_CHANGE_PERCENTAGE: i32 = 5;
+const DEFAULT_GAPS_CHANGE_PIXEL: i32 = 5;
 
 /// A helper struct that represents a set of layouts and provides
 /// convenience methods
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "SerializedLayouts")]
 pub struct Layouts {
-    pub layouts: Vec<Layout>,
+    /// The layouts, in order, see [`Layouts::layouts`]. They are only changed
+    /// through [`Layouts::layouts_mut`], which keeps the `index` up to date.
+    layouts: Vec<Layout>,
+
+    /// Named subsets of the layouts, see [`LayoutSet`] (default: empty).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub sets: Vec<LayoutSet>,
+
+    /// Which layout to suggest for a container, see [`Layouts::suggest_for`].
+    #[serde(skip_serializing_if = "LayoutSuggestions::is_default")]
+    pub suggestions: LayoutSuggestions,
+
+    /// The values all layouts inherit, see [`Layouts::apply_defaults`].
+    #[serde(skip_serializing_if = "LayoutDefaults::is_default")]
+    pub defaults: LayoutDefaults,
+
+    /// The layouts as they were created or loaded, see [`Layouts::reset`].
+    #[serde(skip)]
+    originals: Vec<Layout>,
+
+    /// The position of the first layout of each name, to look up layouts
+    /// without scanning all of them, see [`Layouts::get_index`]. It is built
+    /// on the first lookup after the layouts have changed.
+    #[serde(skip)]
+    index: OnceLock<HashMap<String, usize>>,
+}
+
+/// The serialized form of [`Layouts`], which doesn't contain the originals,
+/// because the deserialized layouts are the originals.
+#[derive(Deserialize, Default)]
+pub(super) struct SerializedLayouts {
+    pub(super) layouts: Vec<LayoutPatch>,
+    #[serde(default)]
+    pub(super) sets: Vec<LayoutSet>,
+    #[serde(default)]
+    pub(super) suggestions: LayoutSuggestions,
+    #[serde(default)]
+    pub(super) defaults: LayoutDefaults,
+}
+
+impl SerializedLayouts {
+    /// Create the layouts by patching the layout of the same name in the provided
+    /// base, or the [`Layout::default`] if there is none, and let them inherit
+    /// the defaults they don't contain.
+    pub(super) fn build(self, base: Option<&Layouts>) -> Layouts {
+        let patched = self
+            .layouts
+            .into_iter()
+            .map(|patch| {
+                let mut layout = patch
+                    .name
+                    .as_deref()
+                    .and_then(|name| base?.get(name))
+                    .cloned()
+                    .unwrap_or_default();
+                layout.patch(patch.clone());
+                // the values the layout contains are kept, even if they are the default
+                self.defaults.apply_to_patched(&mut layout, &patch);
+                layout
+            })
+            .collect();
+        Layouts {
+            sets: self.sets,
+            suggestions: self.suggestions,
+            defaults: self.defaults,
+            ..Layouts::new(patched)
+        }
+    }
+}
+
+impl From<SerializedLayouts> for Layouts {
+    fn from(serialized: SerializedLayouts) -> Self {
+        serialized.build(None)
+    }
+}
+
+impl From<Vec<Layout>> for Layouts {
+    fn from(layouts: Vec<Layout>) -> Self {
+        Self::new(layouts)
+    }
+}
+
+/// Two sets are equal if their layouts, layout sets, suggestions and defaults are,
+/// no matter what they were created from.
+impl PartialEq for Layouts {
+    fn eq(&self, other: &Self) -> bool {
+        self.layouts == other.layouts
+            && self.sets == other.sets
+            && self.suggestions == other.suggestions
+            && self.defaults == other.defaults
+    }
 }
 
 impl Eq for Layouts {}
 
+impl Index<usize> for Layouts {
+    type Output = Layout;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.layouts[index]
+    }
+}
+
+impl IndexMut<usize> for Layouts {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.layouts_mut()[index]
+    }
+}
+
+impl IntoIterator for Layouts {
+    type Item = Layout;
+    type IntoIter = std::vec::IntoIter<Layout>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.layouts.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Layouts {
+    type Item = &'a Layout;
+    type IntoIter = std::slice::Iter<'a, Layout>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.layouts.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Layouts {
+    type Item = &'a mut Layout;
+    type IntoIter = std::slice::IterMut<'a, Layout>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.layouts_mut().iter_mut()
+    }
+}
+
+impl Extend<Layout> for Layouts {
+    fn extend<T: IntoIterator<Item = Layout>>(&mut self, iter: T) {
+        self.layouts_mut().extend(iter);
+    }
+}
+
 impl Layouts {
+    /// Create a set of the provided layouts, which are remembered
+    /// as the originals to reset them to, see [`Layouts::reset`].
+    pub fn new(layouts: Vec<Layout>) -> Self {
+        Self {
+            originals: layouts.clone(),
+            layouts,
+            sets: vec![],
+            suggestions: LayoutSuggestions::default(),
+            defaults: LayoutDefaults::default(),
+            index: OnceLock::new(),
+        }
+    }
+
+    /// The layouts, in order.
+    pub fn layouts(&self) -> &[Layout] {
+        &self.layouts
+    }
+
+    /// The layouts to change, forgetting the positions of their names,
+    /// which are looked up again by the next [`Layouts::get_index`].
+    fn layouts_mut(&mut self) -> &mut Vec<Layout> {
+        self.index.take();
+        &mut self.layouts
+    }
+
     pub fn get(&self, name: &str) -> Option<&Layout> {
-        self.layouts.iter().find(|&l| l.name.as_str() == name)
+        self.layouts.get(self.get_index(name)?)
     }
 
     pub fn get_mut<'a>(&'a mut self, name: &str) -> Option<&'a mut Layout> {
-        self.layouts.iter_mut().find(|l| l.name.as_str() == name)
+        let index = self.get_index(name)?;
+        self.layouts_mut().get_mut(index)
+    }
+
+    /// Append the provided layout. Other than [`Layouts::merge`], this keeps a
+    /// layout with the same name, which is found by its name instead.
+    pub fn push(&mut self, layout: Layout) {
+        self.layouts_mut().push(layout);
+    }
+
+    /// The names of the layouts, in order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.layouts.iter().map(|x| x.name.as_str())
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Layout> {
+        self.layouts.iter()
     }
 
-    pub fn names(&self) -> Vec<String> {
-        self.layouts.iter().map(|x| x.name.clone()).collect()
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, Layout> {
+        self.layouts_mut().iter_mut()
     }
 
     pub fn len(&self) -> usize {
@@ -43,30 +246,418 @@ impl Layouts {
         self.layouts.is_empty()
     }
 
+    /// The position of the first layout with the provided name.
+    ///
+    /// Lookups by name take constant time. After the layouts have been changed
+    /// (eg. by [`Layouts::get_mut`] or [`Layouts::push`]), the first lookup
+    /// takes linear time to find the positions of all names again.
     pub fn get_index(&self, name: &str) -> Option<usize> {
-        self.layouts.iter().position(|l| l.name.as_str() == name)
+        let index = self.index.get_or_init(|| {
+            let mut index = HashMap::new();
+            for (position, layout) in self.layouts.iter().enumerate() {
+                index.entry(layout.name.clone()).or_insert(position);
+            }
+            index
+        });
+        index.get(name).copied()
+    }
+
+    /// Same as [`Layouts::new`], but the layouts are rejected if some of them
+    /// have the same name, because only the first of them could be found by its name.
+    /// The error contains a [`LayoutIssue::DuplicateName`] for each duplicate.
+    ///
+    /// ```
+    /// use leftwm_layouts::layouts::{defaults, LayoutIssue, Layouts};
+    ///
+    /// let layouts = vec![defaults::grid(), defaults::monocle(), defaults::grid()];
+    /// assert_eq!(
+    ///     Err(vec![LayoutIssue::DuplicateName("Grid".to_string())]),
+    ///     Layouts::try_new(layouts)
+    /// );
+    /// ```
+    pub fn try_new(layouts: Vec<Layout>) -> Result<Self, Vec<LayoutIssue>> {
+        let duplicates: Vec<LayoutIssue> = layouts
+            .iter()
+            .enumerate()
+            .filter(|(index, layout)| layouts[..*index].iter().any(|l| l.name == layout.name))
+            .map(|(_, layout)| LayoutIssue::DuplicateName(layout.name.clone()))
+            .collect();
+        if duplicates.is_empty() {
+            Ok(Self::new(layouts))
+        } else {
+            Err(duplicates)
+        }
+    }
+
+    /// Add the layouts of the provided set to this one. A layout with the name
+    /// of an existing layout replaces it at its position, the others are appended.
+    /// The [`LayoutSet`]s are merged the same way.
+    pub fn merge(&mut self, other: Layouts) {
+        merge_by_name(self.layouts_mut(), other.layouts, |l| &l.name);
+        merge_by_name(&mut self.originals, other.originals, |l| &l.name);
+        merge_by_name(&mut self.sets, other.sets, |set| &set.name);
+    }
+
+    /// Remove the layouts with the same name as another layout, the last one of them wins:
+    /// it replaces the first one at its position. Returns the removed layouts.
+    ///
+    /// ```
+    /// use leftwm_layouts::layouts::{defaults, Layouts};
+    ///
+    /// let mut rotated = defaults::grid();
+    /// rotated.rotate(true);
+    /// let mut layouts = Layouts::new(vec![defaults::grid(), defaults::monocle(), rotated.clone()]);
+    /// assert_eq!(vec![defaults::grid()], layouts.dedup());
+    /// assert_eq!(vec![rotated, defaults::monocle()], layouts.layouts());
+    /// ```
+    pub fn dedup(&mut self) -> Vec<Layout> {
+        let mut removed = vec![];
+        let mut unique: Vec<Layout> = vec![];
+        for layout in std::mem::take(self.layouts_mut()) {
+            match unique.iter_mut().find(|l| l.name == layout.name) {
+                Some(first) => removed.push(std::mem::replace(first, layout)),
+                None => unique.push(layout),
+            }
+        }
+        self.layouts = unique;
+        removed
+    }
+
+    /// Insert the provided layout at the provided index, or at the end
+    /// if the index is beyond the last layout.
+    pub fn insert_at(&mut self, index: usize, layout: Layout) {
+        let layouts = self.layouts_mut();
+        layouts.insert(cmp::min(index, layouts.len()), layout);
+    }
+
+    /// Remove the layout with the provided name and return it,
+    /// or [`None`] if there is no such layout.
+    pub fn remove(&mut self, name: &str) -> Option<Layout> {
+        let index = self.get_index(name)?;
+        Some(self.layouts_mut().remove(index))
+    }
+
+    /// Move the layout with the provided name to the provided index, or to the end
+    /// if the index is beyond the last layout. Returns `false` if there is no such layout.
+    ///
+    /// ```
+    /// use leftwm_layouts::layouts::Layouts;
+    ///
+    /// let mut layouts = Layouts::default();
+    /// assert!(layouts.move_to("Monocle", 0));
+    /// assert_eq!(Some(0), layouts.get_index("Monocle"));
+    /// ```
+    pub fn move_to(&mut self, name: &str, index: usize) -> bool {
+        match self.remove(name) {
+            Some(layout) => {
+                self.insert_at(index, layout);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Replace the layout with the provided name by the provided layout, keeping its
+    /// position, and return the replaced layout. Returns [`None`] and leaves the
+    /// layouts unchanged if there is no such layout.
+    pub fn replace(&mut self, name: &str, layout: Layout) -> Option<Layout> {
+        let existing = self.get_mut(name)?;
+        Some(std::mem::replace(existing, layout))
+    }
+
+    /// The layout after the one with the provided name, or the first one after the last.
+    /// Returns [`None`] if there is no layout with the provided name.
+    ///
+    /// ```
+    /// use leftwm_layouts::layouts::Layouts;
+    ///
+    /// let layouts = Layouts::default();
+    /// let last = layouts.layouts().last().unwrap();
+    /// assert_eq!(layouts.layouts().first(), layouts.next_of(&last.name));
+    /// ```
+    pub fn next_of(&self, name: &str) -> Option<&Layout> {
+        let index = self.get_index(name)?;
+        self.layouts.get((index + 1) % self.layouts.len())
+    }
+
+    /// The layout before the one with the provided name, or the last one before the first.
+    /// Returns [`None`] if there is no layout with the provided name.
+    pub fn previous_of(&self, name: &str) -> Option<&Layout> {
+        let index = self.get_index(name)?;
+        let len = self.layouts.len();
+        self.layouts.get((index + len - 1) % len)
+    }
+
+    /// Let the layouts, and the originals they are reset to, inherit the provided
+    /// values, see [`LayoutDefaults`]. The defaults are kept, so that they are
+    /// written along with the layouts.
+    pub fn apply_defaults(&mut self, defaults: LayoutDefaults) {
+        self.index.take();
+        for layout in self.layouts.iter_mut().chain(self.originals.iter_mut()) {
+            defaults.apply_to(layout);
+        }
+        self.defaults = defaults;
+    }
+
+    /// A sensible layout for the provided container, eg. for a newly attached
+    /// monitor, depending on its aspect ratio, see [`LayoutSuggestions`].
+    /// Falls back to the first layout if there is no layout with the suggested name,
+    /// returns [`None`] if there are no layouts at all.
+    pub fn suggest_for(&self, container: &Rect) -> Option<&Layout> {
+        self.get(self.suggestions.name_for(container))
+            .or_else(|| self.layouts.first())
+    }
+
+    /// The layouts with the provided tag, in the same order, eg. to cycle only
+    /// through the layouts meant for an ultrawide monitor.
+    ///
+    /// ```
+    /// use leftwm_layouts::layouts::Layouts;
+    ///
+    /// let mut layouts = Layouts::default();
+    /// layouts.get_mut("CenterMain").unwrap().tags.push("ultrawide".to_string());
+    /// assert_eq!(vec!["CenterMain"], layouts.filter_by_tag("ultrawide").names().collect::<Vec<_>>());
+    /// ```
+    pub fn filter_by_tag(&self, tag: &str) -> Layouts {
+        let tagged = self
+            .layouts
+            .iter()
+            .filter(|layout| layout.tags.iter().any(|t| t == tag))
+            .cloned()
+            .collect();
+        Layouts::new(tagged)
+    }
+
+    /// The [`LayoutSet`] with the provided name, if there is one.
+    pub fn get_set(&self, name: &str) -> Option<&LayoutSet> {
+        self.sets.iter().find(|set| set.name == name)
+    }
+
+    /// The layouts referenced by the [`LayoutSet`] with the provided name, in its order.
+    /// Names without a layout are left out, see [`Layouts::validate_sets`] to find them.
+    /// Returns [`None`] if there is no such [`LayoutSet`].
+    pub fn resolve_set(&self, name: &str) -> Option<Layouts> {
+        let set = self.get_set(name)?;
+        let layouts = set
+            .layouts
+            .iter()
+            .filter_map(|name| self.get(name))
+            .cloned()
+            .collect();
+        Some(Layouts::new(layouts))
+    }
+
+    /// Check the [`LayoutSet`]s for names without a layout, see [`LayoutIssue::UnknownLayoutInSet`].
+    pub fn validate_sets(&self) -> Vec<LayoutIssue> {
+        self.sets
+            .iter()
+            .flat_map(|set| {
+                set.layouts
+                    .iter()
+                    .filter(|name| self.get(name).is_none())
+                    .map(|name| LayoutIssue::UnknownLayoutInSet(set.name.clone(), name.clone()))
+            })
+            .collect()
+    }
+
+    /// Check every layout of the set for invalid configurations (see [`Layout::validate`]),
+    /// and the set itself for duplicate names. Each issue comes with the index
+    /// of the affected layout in [`Layouts::layouts`].
+    pub fn validate(&self) -> Vec<(usize, LayoutIssue)> {
+        validate::validate_set(self)
+    }
+
+    /// Restore the layout with the provided name to the definition the set was created
+    /// or loaded with, eg. after it was resized and rotated at runtime. A layout that
+    /// was added later is restored to the default layout of the same name, if there is one.
+    /// Returns `false` if the set has no such layout or there is nothing to restore it to.
+    ///
+    /// ```
+    /// use leftwm_layouts::layouts::Layouts;
+    ///
+    /// let mut layouts = Layouts::default();
+    /// let layout = layouts.get_mut("MainAndVertStack").unwrap();
+    /// layout.rotate(true);
+    /// layout.increase_main_size(500);
+    /// assert!(layouts.reset("MainAndVertStack"));
+    /// assert_eq!(Layouts::default(), layouts);
+    /// ```
+    pub fn reset(&mut self, name: &str) -> bool {
+        let original = self
+            .originals
+            .iter()
+            .find(|original| original.name == name)
+            .cloned()
+            .or_else(|| Layouts::default().get(name).cloned());
+        match (self.get_mut(name), original) {
+            (Some(layout), Some(original)) => {
+                *layout = original;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Replace the items of `target` by the `items` of the same name,
+/// and append the `items` without an item of the same name.
+fn merge_by_name<T>(target: &mut Vec<T>, items: Vec<T>, name: impl Fn(&T) -> &String) {
+    for item in items {
+        match target
+            .iter_mut()
+            .find(|existing| name(existing) == name(&item))
+        {
+            Some(existing) => *existing = item,
+            None => target.push(item),
+        }
+    }
+}
+
+#[cfg(feature = "ron")]
+impl Layouts {
+    /// Load the layouts from the RON file at the provided path, which contains
+    /// a list of [`Layout`]s, or a serialized [`Layouts`] whose `defaults` all of its
+    /// layouts inherit (see [`LayoutDefaults`]). Only the layouts of the file are included,
+    /// see [`Layouts::from_config_with_defaults`] to include the default layouts too.
+    /// A layout with the name of a default layout is a patch of it (see [`Layout::patch`]),
+    /// so it only needs to contain the fields it changes.
+    ///
+    /// The layouts are rejected with [`ConfigError::Invalid`] if any of them is invalid
+    /// (see [`Layouts::validate`]), and they are the originals for [`Layouts::reset`].
+    pub fn from_config(path: impl AsRef<Path>) -> Result<Layouts, ConfigError> {
+        config::load(path.as_ref(), Format::Ron, false)
+    }
+
+    /// Same as [`Layouts::from_config`], but the layouts of the file are added to the
+    /// default layouts. A layout with the name of a default layout replaces it.
+    pub fn from_config_with_defaults(path: impl AsRef<Path>) -> Result<Layouts, ConfigError> {
+        config::load(path.as_ref(), Format::Ron, true)
+    }
+
+    /// Same as [`Layouts::from_config`], but the layouts are parsed from the provided
+    /// RON string. The default layouts are included if `include_defaults` is `true`.
+    ///
+    /// ```
+    /// use leftwm_layouts::layouts::Layouts;
+    ///
+    /// let layouts = Layouts::from_ron(r#"[(name: "Wide", columns: (main: Some((size: 0.7))))]"#, true).unwrap();
+    /// assert!(layouts.get("Wide").is_some());
+    /// assert!(layouts.get("Monocle").is_some());
+    /// ```
+    pub fn from_ron(config: &str, include_defaults: bool) -> Result<Layouts, ConfigError> {
+        config::parse(config, Format::Ron, include_defaults)
+    }
+
+    /// Write the layouts as a RON list, which [`Layouts::from_ron`] can load again.
+    pub fn to_ron(&self) -> Result<String, ConfigError> {
+        config::serialize(self, Format::Ron, false)
+    }
+
+    /// Same as [`Layouts::to_ron`], but only the fields that differ from their default
+    /// are written, in the order they are declared in. The output only changes if the
+    /// layouts do, so it is suited for configs under version control or written by editors.
+    ///
+    /// *Note: A layout named like a default layout is loaded as a patch of it
+    /// (see [`Layout::patch`]), so its fields which are set back to their default
+    /// while the default layout sets them differently are not restored*
+    ///
+    /// ```
+    /// use leftwm_layouts::layouts::Layouts;
+    ///
+    /// let mut layouts = Layouts::default();
+    /// layouts.get_mut("Monocle").unwrap().rotate(true);
+    /// let config = layouts.to_canonical_ron().unwrap();
+    /// assert!(config.contains("rotate: East"));
+    /// assert!(!config.contains("title_bar_height"));
+    /// assert_eq!(layouts, Layouts::from_ron(&config, false).unwrap());
+    /// ```
+    pub fn to_canonical_ron(&self) -> Result<String, ConfigError> {
+        config::serialize(self, Format::Ron, true)
+    }
+}
+
+#[cfg(feature = "json")]
+impl Layouts {
+    /// Same as [`Layouts::from_ron`], but the layouts are parsed from a JSON list.
+    ///
+    /// ```
+    /// use leftwm_layouts::layouts::Layouts;
+    ///
+    /// let layouts = Layouts::from_json(r#"[{"name": "Wide", "columns": {"main": {"size": 0.7}}}]"#, false).unwrap();
+    /// assert_eq!(vec!["Wide"], layouts.names().collect::<Vec<_>>());
+    /// ```
+    pub fn from_json(config: &str, include_defaults: bool) -> Result<Layouts, ConfigError> {
+        config::parse(config, Format::Json, include_defaults)
+    }
+
+    /// Same as [`Layouts::from_config`], but the file contains a JSON list.
+    pub fn from_json_file(
+        path: impl AsRef<Path>,
+        include_defaults: bool,
+    ) -> Result<Layouts, ConfigError> {
+        config::load(path.as_ref(), Format::Json, include_defaults)
+    }
+
+    /// Write the layouts as a JSON list, which [`Layouts::from_json`] can load again.
+    pub fn to_json(&self) -> Result<String, ConfigError> {
+        config::serialize(self, Format::Json, false)
+    }
+
+    /// Same as [`Layouts::to_json`], but canonical, see [`Layouts::to_canonical_ron`].
+    pub fn to_canonical_json(&self) -> Result<String, ConfigError> {
+        config::serialize(self, Format::Json, true)
+    }
+}
+
+#[cfg(feature = "yaml")]
+impl Layouts {
+    /// Same as [`Layouts::from_ron`], but the layouts are parsed from a YAML list.
+    pub fn from_yaml(config: &str, include_defaults: bool) -> Result<Layouts, ConfigError> {
+        config::parse(config, Format::Yaml, include_defaults)
+    }
+
+    /// Same as [`Layouts::from_config`], but the file contains a YAML list.
+    pub fn from_yaml_file(
+        path: impl AsRef<Path>,
+        include_defaults: bool,
+    ) -> Result<Layouts, ConfigError> {
+        config::load(path.as_ref(), Format::Yaml, include_defaults)
+    }
+
+    /// Write the layouts as a YAML list, which [`Layouts::from_yaml`] can load again.
+    pub fn to_yaml(&self) -> Result<String, ConfigError> {
+        config::serialize(self, Format::Yaml, false)
+    }
+
+    /// Same as [`Layouts::to_yaml`], but canonical, see [`Layouts::to_canonical_ron`].
+    pub fn to_canonical_yaml(&self) -> Result<String, ConfigError> {
+        config::serialize(self, Format::Yaml, true)
     }
 }
 
 impl Default for Layouts {
     fn default() -> Self {
-        Self {
-            layouts: vec![
-                even_horizontal(),
-                even_vertical(),
-                monocle(),
-                grid(),
-                main_and_vert_stack(),
-                main_and_horizontal_stack(),
-                right_main_and_vert_stack(),
-                fibonacci(),
-                dwindle(),
-                main_and_deck(),
-                center_main(),
-                center_main_balanced(),
-                center_main_fluid(),
-            ],
-        }
+        Self::new(vec![
+            even_horizontal(),
+            even_vertical(),
+            monocle(),
+            grid(),
+            main_and_vert_stack(),
+            main_and_horizontal_stack(),
+            right_main_and_vert_stack(),
+            fibonacci(),
+            dwindle(),
+            main_and_deck(),
+            center_main(),
+            center_main_balanced(),
+            center_main_fluid(),
+            dual_main(),
+            bsp(),
+            top_main_and_stack(),
+            main_and_grid_stack(),
+            three_column_equal(),
+            main_and_dual_stack(),
+        ])
     }
 }
 
@@ -82,6 +673,11 @@ pub struct Layout {
     /// This is user chosen and no two layouts can have the same name.
     pub name: LayoutName,
 
+    /// Tags to group layouts by, eg. `ultrawide` or `coding` (default: empty).
+    /// See [`Layouts::filter_by_tag`].
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+
     /// Flips the entire result of tiles as a whole if specified to be anything other than [`Flip::None`]
     pub flip: Flip,
 
@@ -92,6 +688,43 @@ pub struct Layout {
     /// See [`Reserve`] for more information.
     pub reserve: Reserve,
 
+    /// Defines whether gaps should be rendered around reserved, but empty column space.
+    /// See [`ReservedGaps`] for more information.
+    pub reserved_gaps: ReservedGaps,
+
+    /// Defines which column takes over the space of an empty second stack,
+    /// if it isn't reserved. See [`TakeoverOrder`] for more information.
+    pub takeover_order: TakeoverOrder,
+
+    /// Promise that adding a window never moves or resizes the windows
+    /// in the [`Main`] column (default: `false`).
+    ///
+    /// If enabled, the columns are always arranged as if all of them contained
+    /// windows, leaving the space of empty columns free (regardless of [`Layout::reserve`]),
+    /// the [`Main`] column is always split into [`Main::count`] tiles,
+    /// and the [`Main::size_curve`] is ignored.
+    pub stable_main: bool,
+
+    /// Which tiles of a column get the remaining pixels, if the column
+    /// can not be divided evenly. See [`Remainder`] for more information.
+    pub remainder: Remainder,
+
+    /// Height in pixels of a strip shaved off the top of every tile, for a title bar
+    /// or tab drawn by the window manager (default: `0`). The strips are reported
+    /// separately in [`crate::Tiling::title_bars`]. A tile which is not higher than
+    /// the strip is left without any height.
+    pub title_bar_height: u32,
+
+    /// The spacing between adjacent tiles, the tiles along the edges of the
+    /// container stay flush with it. See [`Gaps`] (default: [`None`]).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gaps: Option<Gaps>,
+
+    /// The space kept free along the edges of the container, the layout
+    /// is arranged in the remaining space. See [`Margin`] (default: [`None`]).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub margin: Option<Margin>,
+
     /// Configuration concerning the [`Main`], [`Stack`], and [`SecondStack`] columns.
     /// See [`Columns`] for more information.
     pub columns: Columns,
@@ -103,8 +736,10 @@ impl Layout {
     /// The `Monocle` layout is a special layout that always consists
     /// of 0 or 1 windows. If there is a window, it is shown full screen.
     pub fn is_monocle(&self) -> bool {
-        self.columns.main.is_none()
+        self.columns.list.is_empty()
+            && self.columns.main.is_none()
             && self.columns.second_stack.is_none()
+            && self.columns.second_main.is_none()
             && self.columns.stack.split.is_none()
     }
 
@@ -115,7 +750,9 @@ impl Layout {
     pub fn is_main_and_deck(&self) -> bool {
         match &self.columns.main {
             Some(main) => {
-                self.columns.second_stack.is_none()
+                self.columns.list.is_empty()
+                    && self.columns.effective_second_stack().is_none()
+                    && self.columns.second_main.is_none()
                     && main.split.is_none()
                     && self.columns.stack.split.is_none()
             }
@@ -123,6 +760,84 @@ impl Layout {
         }
     }
 
+    /// Get the user-facing name of the layout for the provided `locale`.
+    ///
+    /// The `resolver` is asked for a display name first,
+    /// the [`Layout::name`] is returned if it doesn't provide one.
+    /// See [`DisplayNameResolver`] for more information.
+    pub fn display_name<R: DisplayNameResolver + ?Sized>(
+        &self,
+        locale: &str,
+        resolver: &R,
+    ) -> String {
+        resolver
+            .resolve(&self.name, locale)
+            .unwrap_or_else(|| self.name.clone())
+    }
+
+    /// Returns `true` if the tiles of this layout are meant to overlap each other,
+    /// because one of its columns uses a [`Split`] that overlaps on purpose.
+    ///
+    /// See [`Split::may_overlap`] for more information.
+    pub fn may_overlap(&self) -> bool {
+        let main = self.columns.main.as_ref().and_then(|m| m.split);
+        let stack = self.columns.stack.split;
+        let second_stack = self.columns.effective_second_stack().map(|s| s.split);
+        let second_main = self.columns.second_main.as_ref().and_then(|m| m.split);
+        let list = self.columns.list.iter().map(|column| column.split);
+        [main, stack, second_stack, second_main]
+            .into_iter()
+            .chain(list)
+            .flatten()
+            .any(|split| split.may_overlap())
+    }
+
+    /// The amount of windows to arrange the columns for. With [`Layout::stable_main`],
+    /// the columns are arranged as if all of them contained windows.
+    /// `main_count` is the amount of windows of all main columns together.
+    pub(crate) fn column_window_count(&self, main_count: usize, window_count: usize) -> usize {
+        if self.stable_main {
+            // enough windows to fill the main columns and both stacks
+            usize::max(window_count, main_count + 2)
+        } else {
+            window_count
+        }
+    }
+
+    /// The layout with the [`Main::count_ratio`] of its `main` columns resolved into a
+    /// [`Main::count`] for the provided amount of windows. Borrows the layout if there
+    /// is nothing to resolve.
+    pub(crate) fn with_counts_for(&self, window_count: usize) -> Cow<'_, Layout> {
+        let columns = &self.columns;
+        let has_ratio =
+            |main: &Option<Main>| main.as_ref().is_some_and(|m| m.count_ratio.is_some());
+        if !has_ratio(&columns.main) && !has_ratio(&columns.second_main) {
+            return Cow::Borrowed(self);
+        }
+        let mut layout = self.clone();
+        for main in layout
+            .columns
+            .main
+            .iter_mut()
+            .chain(layout.columns.second_main.iter_mut())
+        {
+            main.count = main.count_for(window_count);
+        }
+        Cow::Owned(layout)
+    }
+
+    /// The [`Size`] of the provided [`Main`] column for the provided amount of windows
+    /// in a container of the provided `length`, taking [`Layout::stable_main`] and
+    /// [`Main::max_size`] into account.
+    pub(crate) fn main_size_for(&self, main: &Main, window_count: usize, length: u32) -> Size {
+        let size = if self.stable_main {
+            main.size
+        } else {
+            main.size_for(window_count)
+        };
+        main.bounded_size_in(size, length)
+    }
+
     // Get the size of the [`Main`] column,
     // may return [`None`] if there is no [`Main`] column.
     pub fn main_size(&self) -> Option<Size> {
@@ -135,15 +850,16 @@ impl Layout {
         self.columns.main.as_ref().map(|m| m.count)
     }
 
-    /// Set the [`Size`] of the [`Main`] column to a specific value
+    /// Set the [`Size`] of the [`Main`] column to a specific value,
+    /// but to no larger value than [`Main::max_size`].
     pub fn set_main_size(&mut self, size: Size) {
         if let Some(main) = self.columns.main.as_mut() {
-            main.size = size;
+            main.size = main.bounded_size(size);
         }
     }
 
     /// Increase the [`Size`] of the [`Main`] column, but to no
-    /// larger value than what is set in `upper_bound`.
+    /// larger value than what is set in `upper_bound` and [`Main::max_size`].
     ///
     /// The column is increased by a default amount,
     /// either [`DEFAULT_MAIN_SIZE_CHANGE_PIXEL`] or
@@ -186,7 +902,8 @@ impl Layout {
         };
     }
 
-    /// Change the [`Size`] of the [`Main`] column by a `delta` value.
+    /// Change the [`Size`] of the [`Main`] column by a `delta` value,
+    /// but to no larger value than `upper_bound` and [`Main::max_size`].
     ///
     /// The `delta` value can be positive or negative and is interpreted
     /// as either [`Size::Pixel`] or [`Size::Ratio`] based on the current
@@ -220,12 +937,101 @@ impl Layout {
     /// ```
     pub fn change_main_size(&mut self, delta: i32, upper_bound: i32) {
         if let Some(main) = self.columns.main.as_mut() {
-            main.size = match main.size {
+            let size = match main.size {
                 Size::Pixel(px) => Size::Pixel(cmp::max(0, cmp::min(upper_bound, px + delta))),
+                Size::Ratio(ratio) => Size::Ratio(geometry::grow_ratio(ratio, delta.into(), 100)),
+            };
+            main.size = main.bounded_size(size);
+        }
+    }
+
+    /// Increase the [`Size`] of the stack the same way as [`Layout::increase_main_size`],
+    /// but to no larger value than what is set in `upper_bound`.
+    /// See [`Layout::change_stack_size`] for which column is resized.
+    pub fn increase_stack_size(&mut self, upper_bound: i32) {
+        match self.stack_size() {
+            Some(Size::Pixel(_)) => {
+                self.change_stack_size(DEFAULT_MAIN_SIZE_CHANGE_PIXEL, upper_bound)
+            }
+            Some(Size::Ratio(_)) => {
+                self.change_stack_size(DEFAULT_MAIN_SIZE_CHANGE_PERCENTAGE, upper_bound);
+            }
+            None => {}
+        }
+    }
+
+    /// Decrease the [`Size`] of the stack the same way as [`Layout::decrease_main_size`],
+    /// see [`Layout::change_stack_size`] for which column is resized.
+    pub fn decrease_stack_size(&mut self) {
+        match self.stack_size() {
+            Some(Size::Pixel(_)) => {
+                self.change_stack_size(-DEFAULT_MAIN_SIZE_CHANGE_PIXEL, i32::MAX)
+            }
+            Some(Size::Ratio(_)) => {
+                self.change_stack_size(-DEFAULT_MAIN_SIZE_CHANGE_PERCENTAGE, i32::MAX);
+            }
+            None => {}
+        }
+    }
+
+    /// The [`Size`] of the column resized by [`Layout::change_stack_size`], if any.
+    ///
+    /// If the `second_stack` has no size yet, it shares the space next to the `main` column
+    /// equally with the `stack`, so its share is returned if the `main` column has a
+    /// [`Size::Ratio`], or a quarter of the space otherwise.
+    pub fn stack_size(&self) -> Option<Size> {
+        let columns = &self.columns;
+        match (&columns.main, &columns.second_stack) {
+            (
+                Some(_),
+                Some(SecondStack {
+                    size: Some(size), ..
+                }),
+            ) => Some(*size),
+            (Some(main), Some(_)) => Some(match main.size {
                 Size::Ratio(ratio) => {
-                    Size::Ratio(f32::max(0.0, f32::min(1.0, ratio + (delta as f32 * 0.01))))
+                    // half of the space next to the main column, in basis points of the whole
+                    let rest = 10_000 - geometry::scale(10_000, ratio.clamp(0.0, 1.0));
+                    Size::Ratio(geometry::ratio_of(rest as i64, 20_000))
                 }
+                Size::Pixel(_) => Size::Ratio(0.25),
+            }),
+            (Some(main), None) => Some(main.size),
+            (None, _) => None,
+        }
+    }
+
+    /// Change the [`Size`] of the stack by the provided `delta`, which is interpreted
+    /// like in [`Layout::change_main_size`].
+    ///
+    /// If the layout has a `second_stack`, its [`SecondStack::size`] is changed,
+    /// but to no larger value than `upper_bound`. Otherwise the `stack` takes up
+    /// the space next to the `main` column, so it is resized by changing
+    /// the size of the `main` column in the opposite direction.
+    ///
+    /// ```
+    /// use leftwm_layouts::Layout;
+    /// use leftwm_layouts::geometry::Size;
+    ///
+    /// let mut layout = Layout::default();
+    /// layout.change_stack_size(10, 500);
+    /// assert_eq!(Some(Size::Ratio(0.4)), layout.main_size());
+    /// ```
+    ///
+    /// If the current layout has no [`Main`] column, nothing happens
+    pub fn change_stack_size(&mut self, delta: i32, upper_bound: i32) {
+        let size = self.stack_size();
+        match (self.columns.second_stack.as_mut(), size) {
+            (Some(second_stack), Some(size)) => {
+                second_stack.size = Some(match size {
+                    Size::Pixel(px) => Size::Pixel(cmp::max(0, cmp::min(upper_bound, px + delta))),
+                    Size::Ratio(ratio) => {
+                        Size::Ratio(geometry::grow_ratio(ratio, delta.into(), 100))
+                    }
+                });
             }
+            (None, Some(_)) => self.change_main_size(-delta, upper_bound),
+            _ => {}
         }
     }
 
@@ -243,17 +1049,67 @@ impl Layout {
     //    amount.into_absolute(upper_bound.unsigned_abs());
     //}
 
-    // Set the amount of main windows to a specific amount
+    /// The pixels between adjacent tiles, see [`Gaps::inner`].
+    pub fn gaps(&self) -> u32 {
+        self.gaps.map_or(0, |gaps| gaps.inner)
+    }
+
+    /// Set the pixels between adjacent tiles to a specific value, see [`Gaps::inner`].
+    /// The [`Gaps::gutter`] is kept.
+    pub fn set_gaps(&mut self, inner: u32) {
+        let gaps = Gaps {
+            inner,
+            ..self.gaps.unwrap_or_default()
+        };
+        self.gaps = Some(gaps).filter(|gaps| *gaps != Gaps::default());
+    }
+
+    /// Increase the pixels between adjacent tiles by [`DEFAULT_GAPS_CHANGE_PIXEL`].
+    pub fn increase_gaps(&mut self) {
+        self.increase_gaps_bounded(u32::MAX);
+    }
+
+    /// Same as [`Layout::increase_gaps`], but to no larger value than `upper_bound`.
+    pub fn increase_gaps_bounded(&mut self, upper_bound: u32) {
+        self.change_gaps(DEFAULT_GAPS_CHANGE_PIXEL, upper_bound);
+    }
+
+    /// Decrease the pixels between adjacent tiles by [`DEFAULT_GAPS_CHANGE_PIXEL`],
+    /// but to no smaller value than zero.
+    pub fn decrease_gaps(&mut self) {
+        self.change_gaps(-DEFAULT_GAPS_CHANGE_PIXEL, u32::MAX);
+    }
+
+    /// Change the pixels between adjacent tiles by `delta`, but to no smaller
+    /// value than zero and no larger value than `upper_bound`.
+    ///
+    /// ```
+    /// use leftwm_layouts::Layout;
+    ///
+    /// let mut layout = Layout::default();
+    /// layout.change_gaps(12, 10);
+    /// assert_eq!(10, layout.gaps());
+    /// layout.change_gaps(-15, 10);
+    /// assert_eq!(None, layout.gaps);
+    /// ```
+    pub fn change_gaps(&mut self, delta: i32, upper_bound: u32) {
+        let inner = self.gaps().saturating_add_signed(delta).min(upper_bound);
+        self.set_gaps(inner);
+    }
+
+    // Set the amount of main windows to a specific amount,
+    // but not above the `max_count` of the [`Main`] column
     pub fn set_main_window_count(&mut self, count: usize) {
         if let Some(main) = self.columns.main.as_mut() {
-            main.count = cmp::max(0, count);
+            main.count = main.bounded_count(count);
         }
     }
 
-    // Increase the amount of main windows by 1
+    // Increase the amount of main windows by 1,
+    // but not above the `max_count` of the [`Main`] column
     pub fn increase_main_window_count(&mut self) {
         if let Some(main) = self.columns.main.as_mut() {
-            main.count = main.count.saturating_add(1);
+            main.count = main.bounded_count(main.count.saturating_add(1));
         }
     }
 
@@ -274,12 +1130,324 @@ impl Layout {
         }
     }
 
-    pub fn check(&self) {
-        if self.columns.second_stack.is_some() && self.columns.main.is_none() {
-            // warning -> alternate_stack is ignored -> 1-column
+    /// Add a default [`SecondStack`] column if the layout has none, or remove it
+    /// otherwise, eg. to switch between `MainAndVertStack` and `CenterMain` on the fly
+    /// while keeping the rest of the layout.
+    ///
+    /// If the layout has no [`Main`] column, nothing happens,
+    /// because the [`SecondStack`] would be ignored
+    pub fn toggle_second_stack(&mut self) {
+        let columns = &mut self.columns;
+        if columns.main.is_some() {
+            columns.second_stack = match columns.second_stack {
+                Some(_) => None,
+                None => Some(SecondStack::default()),
+            };
+        }
+    }
+
+    /// Insert the provided column into the [`Columns::list`] at the position `at`,
+    /// or append it if `at` is past the end, eg. to offer an "add a column" command
+    /// for a workflow based on columns.
+    ///
+    /// If the list is empty, the `main`, `stack`, `second_stack`, and `second_main`
+    /// columns are copied into it first, in the order they are arranged in, so that
+    /// the column extends the current arrangement.
+    ///
+    /// *Note: The columns of a list are filled with windows in their order. In a layout
+    /// with a `second_stack` or `second_main` column, the windows are therefore
+    /// distributed differently once a column is added.*
+    ///
+    /// ```
+    /// use leftwm_layouts::{apply, geometry::Rect, layouts::{Column, Layouts}};
+    ///
+    /// let mut layout = Layouts::default().get("MainAndVertStack").unwrap().clone();
+    /// // a column for a single window between the main and the stack column
+    /// layout.add_column(1, Column { capacity: Some(1), ..Default::default() });
+    /// assert_eq!(3, layout.columns.list.len());
+    /// assert_eq!(
+    ///     vec![Rect::new(0, 0, 200, 100), Rect::new(200, 0, 100, 100), Rect::new(300, 0, 100, 100)],
+    ///     apply(&layout, 3, &Rect::new(0, 0, 400, 100))
+    /// );
+    /// ```
+    pub fn add_column(&mut self, at: usize, column: Column) {
+        if self.columns.list.is_empty() {
+            self.columns.list = self.columns.built_in_list();
+        }
+        let list = &mut self.columns.list;
+        list.insert(at.min(list.len()), column);
+    }
+
+    /// Remove the column at the position `at` from the [`Columns::list`] and return it,
+    /// or [`None`] if there is no column at that position. Once the last column is
+    /// removed, the layout is back to its `main`, `stack` and `second_stack` columns.
+    pub fn remove_column(&mut self, at: usize) -> Option<Column> {
+        let list = &mut self.columns.list;
+        (at < list.len()).then(|| list.remove(at))
+    }
+
+    /// Change the layout to reflect dragging the provided edge of a tile by `delta` pixels,
+    /// eg. when resizing a tiled window with the mouse. Returns whether the layout changed.
+    ///
+    /// The `tiling` is the last result of [`crate::apply_detailed`] for the layout in the
+    /// `container`, and `index` is the tile whose edge is dragged. A positive `delta`
+    /// moves the edge to the right or to the bottom. Dragging the edge between the `main`
+    /// column and a stack resizes the `main` column (or the entry of its [`Main::size_curve`]
+    /// in use), dragging the edge of the `second_stack` resizes it. In a [`Columns::list`],
+    /// the column with a size next to the edge is resized. The edges between the tiles
+    /// of a column and the edges of the container can't be dragged.
+    ///
+    /// ```
+    /// use leftwm_layouts::{apply_detailed, geometry::{Direction, Rect, Size}, Layout};
+    ///
+    /// let mut layout = Layout::default();
+    /// let container = Rect::new(0, 0, 400, 200);
+    /// let tiling = apply_detailed(&layout, 2, &container);
+    /// assert!(layout.resize_edge(&tiling, 0, Direction::East, 40, &container));
+    /// assert_eq!(Some(Size::Ratio(0.6)), layout.main_size());
+    /// ```
+    pub fn resize_edge(
+        &mut self,
+        tiling: &Tiling,
+        index: usize,
+        edge: Direction,
+        delta: i32,
+        container: &Rect,
+    ) -> bool {
+        resize::resize_edge(self, tiling, index, edge, delta, container)
+    }
+
+    /// Set how the [`Main`] column is split up into tiles,
+    /// see [`Main::split`]. If the layout has no [`Main`] column, nothing happens
+    pub fn set_main_split(&mut self, split: Option<Split>) {
+        if let Some(main) = self.columns.main.as_mut() {
+            main.split = split;
+        }
+    }
+
+    /// Set how the [`Stack`] column is split up into tiles, see [`Stack::split`].
+    pub fn set_stack_split(&mut self, split: Option<Split>) {
+        self.columns.stack.split = split;
+    }
+
+    /// Set how the [`SecondStack`] column is split up into tiles,
+    /// see [`SecondStack::split`]. If the layout has no [`SecondStack`] column,
+    /// nothing happens
+    pub fn set_second_stack_split(&mut self, split: Split) {
+        if let Some(second_stack) = self.columns.second_stack.as_mut() {
+            second_stack.split = split;
         }
     }
 
+    /// Rotate only the contents of the provided column, eg. to turn a vertical
+    /// stack into a horizontal one, without rotating the layout as a whole.
+    /// Rotates clockwise if `true` and counter-clockwise if `false`.
+    ///
+    /// If the layout has no such column, nothing happens
+    pub fn rotate_column(&mut self, kind: ColumnKind, clockwise: bool) {
+        let columns = &mut self.columns;
+        let rotate = match kind {
+            ColumnKind::Main => columns.main.as_mut().map(|main| &mut main.rotate),
+            ColumnKind::SecondMain => columns.second_main.as_mut().map(|main| &mut main.rotate),
+            ColumnKind::Stack => Some(&mut columns.stack.rotate),
+            ColumnKind::SecondStack => columns.second_stack.as_mut().map(|stack| &mut stack.rotate),
+        };
+        if let Some(rotate) = rotate {
+            *rotate = if clockwise {
+                rotate.clockwise()
+            } else {
+                rotate.counter_clockwise()
+            };
+        }
+    }
+
+    /// Flip the layout as a whole horizontally,
+    /// or back if it is already flipped horizontally.
+    pub fn toggle_flip_horizontal(&mut self) {
+        self.flip = self.flip.toggle_horizontal();
+    }
+
+    /// Flip the layout as a whole vertically,
+    /// or back if it is already flipped vertically.
+    pub fn toggle_flip_vertical(&mut self) {
+        self.flip = self.flip.toggle_vertical();
+    }
+
+    /// Flip the columns horizontally, but not their contents,
+    /// or back if they are already flipped horizontally.
+    pub fn toggle_columns_flip_horizontal(&mut self) {
+        self.columns.flip = self.columns.flip.toggle_horizontal();
+    }
+
+    /// Flip the columns vertically, but not their contents,
+    /// or back if they are already flipped vertically.
+    pub fn toggle_columns_flip_vertical(&mut self) {
+        self.columns.flip = self.columns.flip.toggle_vertical();
+    }
+
+    /// Apply the provided [`LayoutCommand`] to the layout, eg. when a keybinding is pressed.
+    ///
+    /// ```
+    /// use leftwm_layouts::{layouts::LayoutCommand, Layout};
+    ///
+    /// let mut layout = Layout::default();
+    /// layout.apply_command(LayoutCommand::IncreaseMainCount);
+    /// assert_eq!(Some(2), layout.main_window_count());
+    /// ```
+    pub fn apply_command(&mut self, command: LayoutCommand) {
+        command::apply(self, command);
+    }
+
+    /// Change the fields the provided patch contains, and keep the others.
+    /// The columns are patched field by field as well, a column missing
+    /// from the layout is added with the fields of the patch.
+    ///
+    /// ```
+    /// use leftwm_layouts::{
+    ///     geometry::Size,
+    ///     layouts::{defaults, ColumnsPatch, LayoutPatch, MainPatch},
+    /// };
+    ///
+    /// let mut layout = defaults::center_main();
+    /// layout.patch(LayoutPatch {
+    ///     columns: Some(ColumnsPatch {
+    ///         main: Some(Some(MainPatch {
+    ///             size: Some(Size::Ratio(0.6)),
+    ///             ..Default::default()
+    ///         })),
+    ///         ..Default::default()
+    ///     }),
+    ///     ..Default::default()
+    /// });
+    /// assert_eq!(Some(Size::Ratio(0.6)), layout.main_size());
+    /// assert_eq!(defaults::center_main().columns.second_stack, layout.columns.second_stack);
+    /// ```
+    pub fn patch(&mut self, patch: LayoutPatch) {
+        patch::patch(self, patch);
+    }
+
+    /// A copy of the layout with its columns flipped on the vertical axis and
+    /// `(mirrored)` appended to its name, eg. to offer a right-handed
+    /// variant of every layout without defining it twice.
+    ///
+    /// ```
+    /// use leftwm_layouts::layouts::Layouts;
+    ///
+    /// let layout = Layouts::default().get("CenterMain").unwrap().mirrored();
+    /// assert_eq!("CenterMain (mirrored)", layout.name);
+    /// ```
+    pub fn mirrored(&self) -> Layout {
+        let mut layout = self.clone();
+        layout.name = format!("{} (mirrored)", self.name);
+        layout.toggle_columns_flip_vertical();
+        layout
+    }
+
+    /// A copy of the layout rotated as a whole, see [`Layout::rotate`],
+    /// with `(rotated clockwise)` or `(rotated counter-clockwise)` appended to its name.
+    pub fn rotated(&self, clockwise: bool) -> Layout {
+        let mut layout = self.clone();
+        let direction = if clockwise {
+            "clockwise"
+        } else {
+            "counter-clockwise"
+        };
+        layout.name = format!("{} (rotated {direction})", self.name);
+        layout.rotate(clockwise);
+        layout
+    }
+
+    /// Exchange the positions of the `main` column and the stack, eg. to move
+    /// the `main` column from the left to the right. Swapping twice results
+    /// in the original layout.
+    ///
+    /// If a [`Columns::main_position`] is set, it is moved to the opposite side,
+    /// a centered `main` column stays where it is. Otherwise the columns are
+    /// flipped along the axis they are arranged on.
+    ///
+    /// ```
+    /// use leftwm_layouts::layouts::{Layout, MainPosition};
+    ///
+    /// let mut layout = Layout::default();
+    /// layout.columns.main_position = Some(MainPosition::Right);
+    /// layout.swap_main_and_stack();
+    /// assert_eq!(Some(MainPosition::Left), layout.columns.main_position);
+    /// ```
+    pub fn swap_main_and_stack(&mut self) {
+        let columns = &mut self.columns;
+        match columns.main_position {
+            Some(position) => {
+                columns.main_position = Some(match position {
+                    MainPosition::Left => MainPosition::Right,
+                    MainPosition::Right => MainPosition::Left,
+                    MainPosition::Top => MainPosition::Bottom,
+                    MainPosition::Bottom => MainPosition::Top,
+                    MainPosition::Center => MainPosition::Center,
+                });
+            }
+            None => {
+                columns.flip = match columns.orientation {
+                    Orientation::Horizontal => columns.flip.toggle_vertical(),
+                    Orientation::Vertical => columns.flip.toggle_horizontal(),
+                };
+            }
+        }
+    }
+
+    /// Check the layout for invalid configurations, eg. to reject them when loading a config.
+    /// See [`LayoutIssue`] for the kinds of issues, and [`Layout::lint`] for suspicious,
+    /// but valid configurations.
+    ///
+    /// ```
+    /// use leftwm_layouts::{geometry::Size, layouts::{ColumnKind, LayoutIssue, Layouts}};
+    ///
+    /// let mut layout = Layouts::default().get("MainAndVertStack").unwrap().clone();
+    /// assert!(layout.validate().is_empty());
+    /// layout.set_main_size(Size::Ratio(1.5));
+    /// assert_eq!(
+    ///     vec![LayoutIssue::RatioOutOfRange(ColumnKind::Main, 1.5)],
+    ///     layout.validate()
+    /// );
+    /// ```
+    pub fn validate(&self) -> Vec<LayoutIssue> {
+        validate::validate(self)
+    }
+
+    /// Fix the invalid configurations reported by [`Layout::validate`] by changing the layout
+    /// to the nearest valid one, and return the issues that were fixed. For example,
+    /// a `second_stack` without `main` column is dropped and ratio sizes are clamped.
+    ///
+    /// ```
+    /// use leftwm_layouts::{geometry::Size, layouts::{ColumnKind, LayoutIssue, Layouts}};
+    ///
+    /// let mut layout = Layouts::default().get("MainAndVertStack").unwrap().clone();
+    /// layout.set_main_size(Size::Ratio(1.5));
+    /// assert_eq!(
+    ///     vec![LayoutIssue::RatioOutOfRange(ColumnKind::Main, 1.5)],
+    ///     layout.normalize()
+    /// );
+    /// assert_eq!(Some(Size::Ratio(1.0)), layout.main_size());
+    /// ```
+    pub fn normalize(&mut self) -> Vec<LayoutIssue> {
+        validate::normalize(self)
+    }
+
+    /// Check the layout for suspicious, but valid configurations,
+    /// eg. for config tools to show them as warnings.
+    /// See [`LintWarning`] for the kinds of warnings.
+    ///
+    /// ```
+    /// use leftwm_layouts::{geometry::Size, layouts::{Layouts, LintWarning}};
+    ///
+    /// let mut layout = Layouts::default().get("MainAndVertStack").unwrap().clone();
+    /// assert!(layout.lint().is_empty());
+    /// layout.set_main_size(Size::Ratio(0.95));
+    /// assert_eq!(vec![LintWarning::LargeMain(0.95)], layout.lint());
+    /// ```
+    pub fn lint(&self) -> Vec<LintWarning> {
+        lint::lint(self)
+    }
+
     pub fn update_defaults(custom: &Vec<Layout>) -> Vec<Layout> {
         let mut layouts = Layouts::default().layouts;
         for custom_layout in custom {
@@ -293,15 +1461,23 @@ impl Default for Layout {
     fn default() -> Self {
         Self {
             name: String::from("Default"),
+            tags: vec![],
             flip: Flip::None,
             rotate: Rotation::North,
             reserve: Reserve::None,
+            reserved_gaps: ReservedGaps::Keep,
+            takeover_order: TakeoverOrder::StacksFirst,
+            stable_main: false,
+            remainder: Remainder::First,
+            title_bar_height: 0,
+            gaps: None,
+            margin: None,
             columns: Columns::default(),
         }
     }
 }
 
-/// Describes the columns of a layout. There are only 3 columns which are a fixed part of
+/// Describes the columns of a layout. There are 3 columns which are a fixed part of
 /// `leftwm_layouts`, those are `main`, `stack`, and `second_stack`.
 ///
 /// ```txt
@@ -314,6 +1490,9 @@ impl Default for Layout {
 ///                stack
 /// ```
 ///
+/// Alternatively, an arbitrary amount of columns can be configured in [`Columns::list`],
+/// eg. for four or more columns on ultrawide monitors.
+///
 /// ## Modifiers
 /// Modifiers like [`Flip`] and [`Rotation`] are applied only to the columns themselves and not their contents.
 ///
@@ -329,6 +1508,20 @@ pub struct Columns {
     /// How the columns should be rotated, does not apply to their contents
     pub rotate: Rotation,
 
+    /// Whether the columns are arranged next to each other or on top of each other,
+    /// does not apply to their contents. See [`Orientation`] for more information.
+    pub orientation: Orientation,
+
+    /// Where to put the `main` column in relation to the stack, see [`MainPosition`].
+    /// This overrides the [`Columns::orientation`] and, other than [`Columns::rotate`],
+    /// never changes the contents of the columns (default: [`None`], the columns are
+    /// arranged as configured by the other properties).
+    pub main_position: Option<MainPosition>,
+
+    /// Switch the [`Columns::orientation`] when the container is taller than it is wide,
+    /// eg. to put the `main` column on top of the stack on a portrait monitor (default: `false`).
+    pub adapt_to_portrait: bool,
+
     /// Configurations concerning the `main` column.
     /// This can be set to [`None`], in which case the layout
     /// will not have a main column. For example, in single-column
@@ -350,18 +1543,315 @@ pub struct Columns {
     /// `main` is [`None`]*
     /// See [`SecondStack`] for more information.
     pub second_stack: Option<SecondStack>,
+
+    /// Whether the windows of a layout with a `second_stack` are distributed evenly across
+    /// both stacks. If not, the `stack` holds a single window and the rest goes to the
+    /// `second_stack`. If [`None`], the stacks are balanced if the `stack` has a split
+    /// (default: [`None`]).
+    ///
+    /// *Note: Without a split, the `stack` still displays only a single window.*
+    pub balance_stacks: Option<bool>,
+
+    /// The share of the windows of balanced stacks (see [`Columns::balance_stacks`])
+    /// that goes to the `stack`, the rest goes to the `second_stack` (from `0.0` to `1.0`,
+    /// values outside are clamped). Each stack keeps at least one window, as long as there
+    /// are two. If [`None`], the windows are distributed evenly (default: [`None`]).
+    ///
+    /// *Note: This doesn't change the width of the stacks, see [`SecondStack::size`].*
+    pub stack_distribution: Option<f32>,
+
+    /// Which of the balanced stacks gets the windows that can't be
+    /// distributed evenly, see [`StackFillOrder`] (default: [`StackFillOrder::StackFirst`]).
+    pub stack_fill_order: StackFillOrder,
+
+    /// Configurations concerning the `second_main` column.
+    /// If present, the layout has a second main column on the other side of the
+    /// `stack`, eg. for `DualMain`. The windows fill the `main` column first,
+    /// then the `second_main` column, and the rest goes to the `stack`.
+    ///
+    /// *Note: Like the `second_stack`, this is ignored if `main` is [`None`].
+    /// A layout can't have both, the `second_stack` is ignored if this is present.*
+    /// See [`Main`] for more information.
+    pub second_main: Option<Main>,
+
+    /// An arbitrary amount of columns next to each other, filled with windows
+    /// in the order of the list (default: empty). See [`Column`] for more information.
+    ///
+    /// *Note: If this is not empty, the `main`, `stack`, `second_stack`, and `second_main`
+    /// columns are ignored. They remain the way to configure the built-in three columns.*
+    pub list: Vec<Column>,
+}
+
+impl Default for Columns {
+    fn default() -> Self {
+        Self {
+            flip: Flip::default(),
+            rotate: Rotation::default(),
+            orientation: Orientation::default(),
+            main_position: None,
+            adapt_to_portrait: false,
+            main: Some(Main::default()),
+            stack: Stack::default(),
+            second_stack: None,
+            balance_stacks: None,
+            stack_distribution: None,
+            stack_fill_order: StackFillOrder::default(),
+            second_main: None,
+            list: vec![],
+        }
+    }
 }
 
-impl Default for Columns {
-    fn default() -> Self {
-        Self {
-            flip: Flip::default(),
-            rotate: Rotation::default(),
-            main: Some(Main::default()),
-            stack: Stack::default(),
-            second_stack: None,
-        }
-    }
+impl Columns {
+    /// The `main`, `stack`, `second_stack`, and `second_main` columns as a [`Columns::list`],
+    /// in the order they are arranged in, see [`Layout::add_column`].
+    pub(crate) fn built_in_list(&self) -> Vec<Column> {
+        let main_column = |kind, main: &Main| Column {
+            kind,
+            size: Some(main.size),
+            capacity: Some(main.count),
+            flip: main.flip,
+            rotate: main.rotate,
+            split: main.split,
+            spiral: main.spiral,
+            min_size: None,
+            reverse: main.reverse,
+        };
+        let stack = Column {
+            kind: ColumnKind::Stack,
+            size: None,
+            capacity: None,
+            flip: self.stack.flip,
+            rotate: self.stack.rotate,
+            split: self.stack.split,
+            spiral: self.stack.spiral,
+            min_size: self.stack.min_size,
+            reverse: self.stack.reverse,
+        };
+        let Some(main) = &self.main else {
+            return vec![stack];
+        };
+        let main = main_column(ColumnKind::Main, main);
+        match (&self.second_main, &self.second_stack) {
+            (Some(second_main), _) => vec![
+                main,
+                stack,
+                main_column(ColumnKind::SecondMain, second_main),
+            ],
+            (None, Some(second_stack)) => vec![
+                // without balanced stacks, the stack holds a single window
+                Column {
+                    capacity: Some(1),
+                    ..stack
+                },
+                main,
+                Column {
+                    kind: ColumnKind::SecondStack,
+                    size: second_stack.size,
+                    capacity: None,
+                    flip: second_stack.flip,
+                    rotate: second_stack.rotate,
+                    split: Some(second_stack.split),
+                    spiral: second_stack.spiral,
+                    min_size: second_stack.min_size,
+                    reverse: second_stack.reverse,
+                },
+            ],
+            (None, None) => vec![main, stack],
+        }
+    }
+
+    /// The orientation of the columns in the provided container,
+    /// taking [`Columns::adapt_to_portrait`] into account.
+    pub(crate) fn orientation_in(&self, container: &Rect) -> Orientation {
+        let orientation = match self.main_position {
+            Some(MainPosition::Left | MainPosition::Right) => Orientation::Horizontal,
+            Some(MainPosition::Top | MainPosition::Bottom) => Orientation::Vertical,
+            Some(MainPosition::Center) | None => self.orientation,
+        };
+        match (
+            self.adapt_to_portrait && container.h > container.w,
+            orientation,
+        ) {
+            (true, Orientation::Horizontal) => Orientation::Vertical,
+            (true, Orientation::Vertical) => Orientation::Horizontal,
+            (false, orientation) => orientation,
+        }
+    }
+
+    /// The flip of the columns in the provided container, taking
+    /// [`Columns::main_position`] into account. The columns are flipped along the
+    /// axis they are arranged on if the `main` column shall be at the end of it.
+    pub(crate) fn flip_in(&self, container: &Rect) -> Flip {
+        match (self.main_position, self.orientation_in(container)) {
+            (Some(MainPosition::Right | MainPosition::Bottom), Orientation::Horizontal) => {
+                self.flip.toggle_vertical()
+            }
+            (Some(MainPosition::Right | MainPosition::Bottom), Orientation::Vertical) => {
+                self.flip.toggle_horizontal()
+            }
+            _ => self.flip,
+        }
+    }
+
+    /// Whether the windows are distributed evenly across both stacks,
+    /// see [`Columns::balance_stacks`].
+    pub(crate) fn balances_stacks(&self) -> bool {
+        self.balance_stacks
+            .unwrap_or_else(|| self.stack.split.is_some())
+    }
+
+    /// The [`Columns::stack_distribution`], clamped to a valid share.
+    pub(crate) fn stack_share(&self) -> Option<f32> {
+        self.stack_distribution
+            .filter(|share| share.is_finite())
+            .map(|share| share.clamp(0.0, 1.0))
+    }
+
+    /// The `second_stack` column, or one made up from the `stack` column
+    /// if the `main` column shall be centered between two stacks.
+    pub(crate) fn effective_second_stack(&self) -> Option<SecondStack> {
+        match (&self.second_stack, self.main_position) {
+            (Some(second_stack), _) => Some(second_stack.clone()),
+            (None, Some(MainPosition::Center)) if self.main.is_some() => Some(SecondStack {
+                size: None,
+                flip: self.stack.flip,
+                rotate: self.stack.rotate,
+                split: self.stack.split.unwrap_or(Split::Horizontal),
+                spiral: self.stack.spiral,
+                min_size: self.stack.min_size,
+                reverse: self.stack.reverse,
+            }),
+            (None, _) => None,
+        }
+    }
+}
+
+/// The order in which the windows of balanced stacks (see [`Columns::balance_stacks`])
+/// fill the `stack` and the `second_stack`, see [`Columns::stack_fill_order`].
+///
+/// The `stack` always holds the first of the stack windows. Other than the default,
+/// the orders only apply if the windows are distributed evenly
+/// (ie. no [`Columns::stack_distribution`] is set).
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum StackFillOrder {
+    /// The `stack` is filled first, it gets the odd window.
+    ///
+    /// ```txt
+    /// +---+-------+---+
+    /// | 2 |       | 4 |
+    /// +---+   1   |   |
+    /// | 3 |       |   |
+    /// +---+-------+---+
+    /// ```
+    #[default]
+    StackFirst,
+
+    /// The `second_stack` is filled first, it gets the odd window.
+    ///
+    /// ```txt
+    /// +---+-------+---+
+    /// |   |       | 3 |
+    /// | 2 |   1   +---+
+    /// |   |       | 4 |
+    /// +---+-------+---+
+    /// ```
+    SecondStackFirst,
+
+    /// The windows alternate between the `stack` and the `second_stack`.
+    ///
+    /// ```txt
+    /// +---+-------+---+
+    /// | 2 |       |   |
+    /// +---+   1   | 3 |
+    /// | 4 |       |   |
+    /// +---+-------+---+
+    /// ```
+    Alternate,
+}
+
+/// Where to put the `main` column of a layout in relation to the stack,
+/// see [`Columns::main_position`].
+///
+/// In layouts with a `second_stack`, the `main` column always stays between
+/// the stacks, [`MainPosition::Top`] and [`MainPosition::Bottom`] only arrange
+/// the columns on top of each other.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum MainPosition {
+    /// The `main` column is left of the stack.
+    ///
+    /// ```txt
+    /// +--------+-----+
+    /// |        |     |
+    /// |  MAIN  +-----+
+    /// |        |     |
+    /// +--------+-----+
+    /// ```
+    Left,
+
+    /// The `main` column is centered between two stacks, like in `CenterMain`.
+    /// A layout without a `second_stack` distributes its stack on both sides.
+    ///
+    /// ```txt
+    /// +-----+--------+-----+
+    /// |     |        |     |
+    /// +-----+  MAIN  +-----+
+    /// |     |        |     |
+    /// +-----+--------+-----+
+    /// ```
+    Center,
+
+    /// The `main` column is right of the stack.
+    ///
+    /// ```txt
+    /// +-----+--------+
+    /// |     |        |
+    /// +-----+  MAIN  |
+    /// |     |        |
+    /// +-----+--------+
+    /// ```
+    Right,
+
+    /// The `main` column is on top of the stack.
+    ///
+    /// ```txt
+    /// +--------------+
+    /// |     MAIN     |
+    /// |              |
+    /// +-------+------+
+    /// |       |      |
+    /// +-------+------+
+    /// ```
+    Top,
+
+    /// The `main` column is below the stack.
+    ///
+    /// ```txt
+    /// +-------+------+
+    /// |       |      |
+    /// +-------+------+
+    /// |     MAIN     |
+    /// |              |
+    /// +--------------+
+    /// ```
+    Bottom,
+}
+
+/// The kind of column a tile belongs to.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[repr(u8)]
+pub enum ColumnKind {
+    /// The `main` column, see [`Main`].
+    Main = 0,
+
+    /// The `stack` column, see [`Stack`].
+    Stack = 1,
+
+    /// The `second_stack` column, see [`SecondStack`].
+    SecondStack = 2,
+
+    /// The `second_main` column, see [`Columns::second_main`].
+    SecondMain = 3,
 }
 
 /// Configurations concerning the `main` column
@@ -371,9 +1861,43 @@ pub struct Main {
     /// The default amount of windows to occupy the `main` column (default: `1`)
     pub count: usize,
 
+    /// The largest amount of windows the `main` column can be changed to hold by
+    /// [`Layout::set_main_window_count`] and [`Layout::increase_main_window_count`]
+    /// (default: none).
+    pub max_count: Option<usize>,
+
+    /// The share of all windows to occupy the `main` column instead of
+    /// a fixed [`Main::count`], between `0.0` and `1.0` (default: none).
+    ///
+    /// The amount is rounded like a [`Size::Ratio`] (ie. half up), but the `main` column
+    /// always holds at least one window and at most [`Main::max_count`]. For example, `0.5`
+    /// puts half of all windows into the `main` column, which adapts the layout to large
+    /// monitors with many windows.
+    pub count_ratio: Option<f32>,
+
     /// The default size of the `main` column (default: `50%`)
     pub size: Size,
 
+    /// Breakpoints to change the size of the `main` column depending on the
+    /// amount of windows, as a list of `(window_count, size)` pairs (default: empty).
+    ///
+    /// The size of the breakpoint with the highest `window_count` that is
+    /// still less than or equal to the actual amount of windows is used.
+    /// If no breakpoint applies, [`Main::size`] is used.
+    ///
+    /// For example, `[(2, 0.7), (5, 0.55)]` makes the `main` column take 70%
+    /// of the space with 2 to 4 windows and 55% with 5 or more windows.
+    pub size_curve: Vec<(usize, Size)>,
+
+    /// The largest size of the `main` column (default: none), which limits the
+    /// [`Main::size`] and the [`Main::size_curve`] when the layout is applied.
+    ///
+    /// Resizing the column, eg. by [`Layout::increase_main_size`], doesn't go beyond the
+    /// bound either. A bound of the other kind ([`Size::Pixel`] or [`Size::Ratio`])
+    /// than the size can only be compared within a container, so it only applies
+    /// when the layout is applied or resized by [`Layout::resize_edge`].
+    pub max_size: Option<Size>,
+
     /// Flip modifier to apply only to the `main` columns' contents
     pub flip: Flip,
 
@@ -386,16 +1910,87 @@ pub struct Main {
     /// *Note: This can be set to [`None`], in which case the `main` column can't
     /// contain more than one window (eg. `MainAndDeck`)*
     pub split: Option<Split>,
+
+    /// The spiral of the tiles, if they are split by [`Split::Fibonacci`] or [`Split::Dwindle`].
+    /// See [`Spiral`] for more information.
+    pub spiral: Spiral,
+
+    /// Assign the tiles of the `main` column to its windows in reverse order,
+    /// eg. the first window gets the last tile (default: `false`).
+    /// Other than [`Flip`], this doesn't change the tiles themselves.
+    pub reverse: bool,
 }
 
 impl Default for Main {
     fn default() -> Self {
         Self {
             count: 1,
+            max_count: None,
+            count_ratio: None,
             size: Size::Ratio(0.5),
+            size_curve: vec![],
+            max_size: None,
             flip: Flip::default(),
             rotate: Rotation::default(),
             split: Some(Split::Vertical),
+            spiral: Spiral::default(),
+            reverse: false,
+        }
+    }
+}
+
+impl Main {
+    /// Get the size of the `main` column for the provided amount of windows,
+    /// taking the [`Main::size_curve`] into account.
+    pub fn size_for(&self, window_count: usize) -> Size {
+        self.size_curve
+            .iter()
+            .filter(|(breakpoint, _)| *breakpoint <= window_count)
+            .max_by_key(|(breakpoint, _)| *breakpoint)
+            .map_or(self.size, |(_, size)| *size)
+    }
+
+    /// Limit the provided size to the [`Main::max_size`], if both are of the same kind.
+    pub(crate) fn bounded_size(&self, size: Size) -> Size {
+        match (size, self.max_size) {
+            (Size::Pixel(px), Some(Size::Pixel(max))) => Size::Pixel(px.min(max)),
+            (Size::Ratio(ratio), Some(Size::Ratio(max))) => Size::Ratio(ratio.min(max)),
+            (size, _) => size,
+        }
+    }
+
+    /// Limit the provided size to the [`Main::max_size`], if there is one,
+    /// comparing them in a container of the provided `length`.
+    pub(crate) fn bounded_size_in(&self, size: Size, length: u32) -> Size {
+        match self.max_size {
+            Some(max) if max.into_absolute(length) < size.into_absolute(length) => max,
+            _ => size,
+        }
+    }
+
+    /// Limit the provided amount of windows to the [`Main::max_count`], if there is one.
+    pub fn bounded_count(&self, count: usize) -> usize {
+        self.max_count.map_or(count, |max| cmp::min(count, max))
+    }
+
+    /// Get the amount of windows to occupy the `main` column for the provided
+    /// amount of windows, taking the [`Main::count_ratio`] into account.
+    pub fn count_for(&self, window_count: usize) -> usize {
+        match self.count_ratio {
+            Some(ratio) => {
+                let window_count = u32::try_from(window_count).unwrap_or(u32::MAX);
+                let count = geometry::scale(window_count, ratio.clamp(0.0, 1.0));
+                self.bounded_count(cmp::max(1, count as usize))
+            }
+            None => self.count,
+        }
+    }
+
+    pub(crate) fn split_options(&self, remainder: Remainder) -> SplitOptions {
+        SplitOptions {
+            spiral: self.spiral,
+            remainder,
+            ..Default::default()
         }
     }
 }
@@ -416,6 +2011,21 @@ pub struct Stack {
     /// *Note: This can be set to [`None`], in which case the `stack` column can't
     /// contain more than one window (eg. `Monocle`, `MainAndDeck`)*
     pub split: Option<Split>,
+
+    /// The spiral of the tiles, if they are split by [`Split::Fibonacci`] or [`Split::Dwindle`].
+    /// See [`Spiral`] for more information.
+    pub spiral: Spiral,
+
+    /// The minimum `(width, height)` of the tiles in the `stack` column (default: [`None`]).
+    /// The windows that don't fit are stacked onto the last tile instead,
+    /// see [`SplitOptions::min_size`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_size: Option<(u32, u32)>,
+
+    /// Assign the tiles of the `stack` column to its windows in reverse order,
+    /// eg. the first window gets the last tile (default: `false`).
+    /// Other than [`Flip`], this doesn't change the tiles themselves.
+    pub reverse: bool,
 }
 
 impl Default for Stack {
@@ -424,6 +2034,94 @@ impl Default for Stack {
             flip: Flip::default(),
             rotate: Rotation::default(),
             split: Some(Split::Horizontal),
+            spiral: Spiral::default(),
+            min_size: None,
+            reverse: false,
+        }
+    }
+}
+
+impl Stack {
+    pub(crate) fn split_options(&self, remainder: Remainder) -> SplitOptions {
+        SplitOptions {
+            spiral: self.spiral,
+            min_size: self.min_size,
+            remainder,
+            ..Default::default()
+        }
+    }
+}
+
+/// Configurations concerning a single column of [`Columns::list`]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+#[serde(default)]
+pub struct Column {
+    /// The role of the windows in this column, as reported in [`crate::Tiling::roles`]
+    /// (default: [`ColumnKind::Stack`]). The first [`ColumnKind::Main`] column
+    /// is the one returned by [`crate::main_rect`].
+    pub kind: ColumnKind,
+
+    /// The size of the column (default: [`None`]). The columns without a size share
+    /// the space left over by the columns with a size evenly. If every column has a size,
+    /// the last column takes the space left over.
+    pub size: Option<Size>,
+
+    /// The maximum amount of windows in this column, [`None`] for no limit (default: [`None`]).
+    /// The windows not fitting into any column are put into the last column.
+    pub capacity: Option<usize>,
+
+    /// Flip modifier to apply only to the columns' contents
+    pub flip: Flip,
+
+    /// Rotation modifier to apply only to the columns' contents
+    pub rotate: Rotation,
+
+    /// How tiles (windows) inside the column should be split up,
+    /// when there is more than one.
+    ///
+    /// *Note: This can be set to [`None`], in which case the column
+    /// only shows a single window (like a deck)*
+    pub split: Option<Split>,
+
+    /// The spiral of the tiles, if they are split by [`Split::Fibonacci`] or [`Split::Dwindle`].
+    /// See [`Spiral`] for more information.
+    pub spiral: Spiral,
+
+    /// The minimum `(width, height)` of the tiles in the column (default: [`None`]).
+    /// The windows that don't fit are stacked onto the last tile instead,
+    /// see [`SplitOptions::min_size`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_size: Option<(u32, u32)>,
+
+    /// Assign the tiles of the column to its windows in reverse order,
+    /// eg. the first window gets the last tile (default: `false`).
+    /// Other than [`Flip`], this doesn't change the tiles themselves.
+    pub reverse: bool,
+}
+
+impl Default for Column {
+    fn default() -> Self {
+        Self {
+            kind: ColumnKind::Stack,
+            size: None,
+            capacity: None,
+            flip: Flip::default(),
+            rotate: Rotation::default(),
+            split: Some(Split::Horizontal),
+            spiral: Spiral::default(),
+            min_size: None,
+            reverse: false,
+        }
+    }
+}
+
+impl Column {
+    pub(crate) fn split_options(&self, remainder: Remainder) -> SplitOptions {
+        SplitOptions {
+            spiral: self.spiral,
+            min_size: self.min_size,
+            remainder,
+            ..Default::default()
         }
     }
 }
@@ -432,6 +2130,11 @@ impl Default for Stack {
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
 #[serde(default)]
 pub struct SecondStack {
+    /// Size of the `second_stack` column, eg. to make it a thin sidebar.
+    /// If [`None`], the `stack` and `second_stack` columns share
+    /// the space next to the `main` column equally (default: [`None`]).
+    pub size: Option<Size>,
+
     /// Flip modifier to apply only to the `second_stack` columns' contents
     pub flip: Flip,
 
@@ -441,14 +2144,44 @@ pub struct SecondStack {
     /// How tiles (windows) inside the `second_stack` column should be split up,
     /// when there is more than one.
     pub split: Split,
+
+    /// The spiral of the tiles, if they are split by [`Split::Fibonacci`] or [`Split::Dwindle`].
+    /// See [`Spiral`] for more information.
+    pub spiral: Spiral,
+
+    /// The minimum `(width, height)` of the tiles in the `second_stack` column (default: [`None`]).
+    /// The windows that don't fit are stacked onto the last tile instead,
+    /// see [`SplitOptions::min_size`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_size: Option<(u32, u32)>,
+
+    /// Assign the tiles of the `second_stack` column to its windows in reverse order,
+    /// eg. the first window gets the last tile (default: `false`).
+    /// Other than [`Flip`], this doesn't change the tiles themselves.
+    pub reverse: bool,
 }
 
 impl Default for SecondStack {
     fn default() -> Self {
         Self {
+            size: None,
             flip: Flip::default(),
             rotate: Rotation::default(),
             split: Split::Horizontal,
+            spiral: Spiral::default(),
+            min_size: None,
+            reverse: false,
+        }
+    }
+}
+
+impl SecondStack {
+    pub(crate) fn split_options(&self, remainder: Remainder) -> SplitOptions {
+        SplitOptions {
+            spiral: self.spiral,
+            min_size: self.min_size,
+            remainder,
+            ..Default::default()
         }
     }
 }
@@ -456,14 +2189,122 @@ impl Default for SecondStack {
 #[cfg(test)]
 mod tests {
     use crate::{
-        geometry::Size,
+        geometry::{Flip, Gaps, Orientation, Rect, Rotation, Size, Split},
         layouts::{
-            layout::{DEFAULT_MAIN_SIZE_CHANGE_PERCENTAGE, DEFAULT_MAIN_SIZE_CHANGE_PIXEL},
-            Layouts,
+            defaults,
+            layout::{
+                DEFAULT_GAPS_CHANGE_PIXEL, DEFAULT_MAIN_SIZE_CHANGE_PERCENTAGE,
+                DEFAULT_MAIN_SIZE_CHANGE_PIXEL,
+            },
+            Column, ColumnKind, LayoutIssue, LayoutSet, Layouts, Main, SecondStack,
         },
-        Layout,
+        main_rect, Layout,
     };
 
+    #[test]
+    fn default_layouts_are_named_like_all_names() {
+        assert_eq!(
+            defaults::all_names(),
+            Layouts::default().names().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn lookups_follow_changes_to_the_layouts() {
+        let mut layouts = Layouts::default();
+        assert!(layouts.move_to(defaults::MONOCLE, 0));
+        assert_eq!(Some(0), layouts.get_index(defaults::MONOCLE));
+        assert_eq!(Some(1), layouts.get_index(defaults::EVEN_HORIZONTAL));
+
+        // renaming the layouts in place doesn't break the lookups
+        layouts[2].name = "Renamed".to_string();
+        assert_eq!(Some(2), layouts.get_index("Renamed"));
+        assert_eq!(None, layouts.get_index(defaults::EVEN_VERTICAL));
+        layouts.get_mut("Renamed").unwrap().name = "Renamed again".to_string();
+        assert_eq!(None, layouts.get_index("Renamed"));
+        assert_eq!(Some(2), layouts.get_index("Renamed again"));
+        for layout in &mut layouts {
+            layout.name.insert_str(0, "My ");
+        }
+        assert_eq!(Some(0), layouts.get_index("My Monocle"));
+        assert_eq!(None, layouts.get_index(defaults::MONOCLE));
+
+        layouts.push(Layout {
+            name: "Pushed".to_string(),
+            ..Default::default()
+        });
+        layouts.extend([Layout {
+            name: "Extended".to_string(),
+            ..Default::default()
+        }]);
+        let len = layouts.len();
+        assert_eq!(Some(len - 2), layouts.get_index("Pushed"));
+        assert_eq!(Some(len - 1), layouts.get_index("Extended"));
+        assert_eq!(Some(1), layouts.get_index("My EvenHorizontal"));
+    }
+
+    #[test]
+    fn lookups_find_the_first_of_duplicate_names() {
+        let mut layouts = Layouts::default();
+        let mut duplicate = defaults::monocle();
+        duplicate.rotate = Rotation::East;
+        layouts.push(duplicate.clone());
+        assert_eq!(Some(&defaults::monocle()), layouts.get(defaults::MONOCLE));
+
+        // the duplicate is found once the first one is gone
+        assert_eq!(Some(defaults::monocle()), layouts.remove(defaults::MONOCLE));
+        assert_eq!(Some(&duplicate), layouts.get(defaults::MONOCLE));
+        assert_eq!(
+            Some(layouts.len() - 1),
+            layouts.get_index(defaults::MONOCLE)
+        );
+    }
+
+    #[test]
+    fn layouts_can_be_indexed_and_iterated() {
+        let mut layouts = Layouts::default();
+        assert_eq!(defaults::EVEN_HORIZONTAL, layouts[0].name);
+        layouts[0].rotate = Rotation::East;
+        assert_eq!(
+            Rotation::East,
+            layouts.get(defaults::EVEN_HORIZONTAL).unwrap().rotate
+        );
+
+        for layout in &mut layouts {
+            layout.flip = Flip::Horizontal;
+        }
+        assert!(layouts.iter().all(|layout| layout.flip == Flip::Horizontal));
+        let names: Vec<String> = layouts.into_iter().map(|layout| layout.name).collect();
+        assert_eq!(defaults::all_names(), names);
+    }
+
+    #[test]
+    fn main_size_follows_size_curve() {
+        let main = Main {
+            size: Size::Ratio(0.5),
+            size_curve: vec![(5, Size::Ratio(0.55)), (2, Size::Ratio(0.7))],
+            ..Default::default()
+        };
+        assert_eq!(Size::Ratio(0.5), main.size_for(1));
+        assert_eq!(Size::Ratio(0.7), main.size_for(2));
+        assert_eq!(Size::Ratio(0.7), main.size_for(4));
+        assert_eq!(Size::Ratio(0.55), main.size_for(5));
+        assert_eq!(Size::Ratio(0.55), main.size_for(42));
+    }
+
+    #[test]
+    fn exported_names_find_the_default_layouts() {
+        let layouts = Layouts::default();
+        assert!(layouts.get(defaults::MONOCLE).unwrap().is_monocle());
+        assert!(layouts
+            .get(defaults::MAIN_AND_DECK)
+            .unwrap()
+            .is_main_and_deck());
+        for name in defaults::all_names() {
+            assert_eq!(*name, layouts.get(name).unwrap().name);
+        }
+    }
+
     #[test]
     fn monocle_layout_is_monocle() {
         let layouts = Layouts::default();
@@ -511,6 +2352,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn changing_main_size_ratio_does_not_accumulate_float_noise() {
+        let mut layout = Layout::default();
+        layout.set_main_size(Size::Ratio(0.5));
+        for _ in 0..7 {
+            layout.change_main_size(1, 500);
+        }
+        for _ in 0..7 {
+            layout.change_main_size(-1, 500);
+        }
+        let Some(Size::Ratio(ratio)) = layout.main_size() else {
+            panic!("the main size must still be a ratio");
+        };
+        assert_eq!(0.5, ratio);
+    }
+
     #[test]
     fn increase_main_size_pixel_works() {
         let mut layout = Layout::default();
@@ -585,6 +2442,68 @@ mod tests {
         assert_eq!(Some(Size::Pixel(500)), layout.main_size());
     }
 
+    #[test]
+    fn increase_main_size_does_not_go_above_max_size() {
+        let mut layout = Layout::default();
+        layout.set_main_size(Size::Pixel(200));
+        layout.columns.main.as_mut().unwrap().max_size = Some(Size::Pixel(240));
+        layout.increase_main_size(i32::MAX);
+        assert_eq!(Some(Size::Pixel(240)), layout.main_size());
+        layout.set_main_size(Size::Pixel(300));
+        assert_eq!(Some(Size::Pixel(240)), layout.main_size());
+        layout.set_main_size(Size::Pixel(200));
+        layout.increase_main_size(220);
+        assert_eq!(Some(Size::Pixel(220)), layout.main_size());
+
+        layout.columns.main.as_mut().unwrap().max_size = Some(Size::Ratio(0.6));
+        layout.set_main_size(Size::Ratio(0.5));
+        layout.change_main_size(20, i32::MAX);
+        assert_eq!(Some(Size::Ratio(0.6)), layout.main_size());
+
+        // a bound of the other kind only applies within a container
+        layout.columns.main.as_mut().unwrap().max_size = Some(Size::Pixel(240));
+        layout.change_main_size(20, i32::MAX);
+        assert_eq!(Some(Size::Ratio(0.8)), layout.main_size());
+        let tiles = crate::apply(&layout, 2, &Rect::new(0, 0, 400, 100));
+        assert_eq!(Rect::new(0, 0, 240, 100), tiles[0]);
+        assert_eq!(Rect::new(240, 0, 160, 100), tiles[1]);
+    }
+
+    #[test]
+    fn max_size_limits_the_size_curve_when_applied() {
+        let mut layout = Layout::default();
+        let main = layout.columns.main.as_mut().unwrap();
+        main.size_curve = vec![(3, Size::Ratio(0.8))];
+        main.max_size = Some(Size::Ratio(0.6));
+        let container = Rect::new(0, 0, 400, 100);
+        assert_eq!(
+            Rect::new(0, 0, 200, 100),
+            crate::apply(&layout, 2, &container)[0]
+        );
+        assert_eq!(
+            Rect::new(0, 0, 240, 100),
+            crate::apply(&layout, 3, &container)[0]
+        );
+    }
+
+    #[test]
+    fn toggle_flip_works() {
+        let mut layout = Layout::default();
+        layout.toggle_flip_horizontal();
+        assert_eq!(Flip::Horizontal, layout.flip);
+        layout.toggle_flip_vertical();
+        assert_eq!(Flip::Both, layout.flip);
+        layout.toggle_flip_horizontal();
+        layout.toggle_flip_vertical();
+        assert_eq!(Flip::None, layout.flip);
+
+        layout.toggle_columns_flip_vertical();
+        assert_eq!(Flip::Vertical, layout.columns.flip);
+        layout.toggle_columns_flip_horizontal();
+        assert_eq!(Flip::Both, layout.columns.flip);
+        assert_eq!(Flip::None, layout.flip);
+    }
+
     #[test]
     fn set_main_window_count_works() {
         let mut layout = Layout::default();
@@ -608,6 +2527,391 @@ mod tests {
         assert_eq!(Some(4), layout.main_window_count());
     }
 
+    #[test]
+    fn main_window_count_does_not_go_above_max_count() {
+        let mut layout = Layout::default();
+        layout.columns.main.as_mut().unwrap().max_count = Some(2);
+        layout.set_main_window_count(5);
+        assert_eq!(Some(2), layout.main_window_count());
+        layout.set_main_window_count(1);
+        layout.increase_main_window_count();
+        layout.increase_main_window_count();
+        assert_eq!(Some(2), layout.main_window_count());
+        layout.decrease_main_window_count();
+        assert_eq!(Some(1), layout.main_window_count());
+    }
+
+    #[test]
+    fn count_ratio_resolves_the_main_count_per_window_count() {
+        let main = Main {
+            count_ratio: Some(0.5),
+            max_count: Some(3),
+            ..Default::default()
+        };
+        let counts: Vec<usize> = [0, 1, 2, 5, 6, 10].map(|n| main.count_for(n)).to_vec();
+        // 2.5 windows are rounded up, like a `Size::Ratio`
+        assert_eq!(vec![1, 1, 1, 3, 3, 3], counts);
+        assert_eq!(1, Main::default().count_for(10));
+    }
+
+    #[test]
+    fn mirrored_and_rotated_copies_keep_the_original() {
+        let layout = Layout::default();
+        let mirrored = layout.mirrored();
+        assert_eq!(format!("{} (mirrored)", layout.name), mirrored.name);
+        assert_eq!(Flip::Vertical, mirrored.columns.flip);
+        assert_eq!(layout.columns.flip, mirrored.mirrored().columns.flip);
+
+        let rotated = layout.rotated(true);
+        assert_eq!(format!("{} (rotated clockwise)", layout.name), rotated.name);
+        assert_eq!(Rotation::East, rotated.rotate);
+        assert_eq!(Rotation::West, layout.rotated(false).rotate);
+        assert_eq!(Rotation::North, layout.rotate);
+    }
+
+    #[test]
+    fn mirrored_copies_move_the_main_column_to_the_other_side() {
+        let container = Rect::new(0, 0, 400, 200);
+        let layout = Layouts::default()
+            .get("MainAndVertStack")
+            .unwrap()
+            .mirrored();
+        let tiles = crate::apply(&layout, 3, &container);
+        assert_eq!(Rect::new(200, 0, 200, 200), tiles[0]);
+        assert_eq!(Rect::new(0, 0, 200, 100), tiles[1]);
+        assert_eq!(Rect::new(0, 100, 200, 100), tiles[2]);
+    }
+
+    #[test]
+    fn gaps_are_changed_within_bounds() {
+        let mut layout = Layout {
+            gaps: Some(Gaps {
+                inner: 0,
+                gutter: 20,
+            }),
+            ..Default::default()
+        };
+        layout.increase_gaps();
+        assert_eq!(
+            Some(Gaps {
+                inner: DEFAULT_GAPS_CHANGE_PIXEL as u32,
+                gutter: 20,
+            }),
+            layout.gaps
+        );
+        layout.increase_gaps_bounded(8);
+        assert_eq!(8, layout.gaps());
+        layout.decrease_gaps();
+        layout.decrease_gaps();
+        assert_eq!(0, layout.gaps());
+        assert_eq!(Some(20), layout.gaps.map(|gaps| gaps.gutter));
+
+        let mut layout = Layout::default();
+        layout.set_gaps(12);
+        layout.set_gaps(0);
+        assert_eq!(Layout::default(), layout);
+    }
+
+    #[test]
+    fn stack_size_resizes_the_second_stack_or_main() {
+        let mut layout = Layout::default();
+        layout.increase_stack_size(i32::MAX);
+        assert_eq!(Some(Size::Ratio(0.45)), layout.main_size());
+        layout.decrease_stack_size();
+        assert_eq!(Some(Size::Ratio(0.5)), layout.main_size());
+
+        let mut center_main = defaults::center_main();
+        assert_eq!(Some(Size::Ratio(0.25)), center_main.stack_size());
+        center_main.increase_stack_size(i32::MAX);
+        let second_stack = center_main.columns.second_stack.as_ref().unwrap();
+        assert_eq!(Some(Size::Ratio(0.3)), second_stack.size);
+        assert_eq!(Some(Size::Ratio(0.5)), center_main.main_size());
+
+        center_main.columns.second_stack.as_mut().unwrap().size = Some(Size::Pixel(200));
+        center_main.increase_stack_size(220);
+        assert_eq!(Some(Size::Pixel(220)), center_main.stack_size());
+        center_main.decrease_stack_size();
+        assert_eq!(Some(Size::Pixel(170)), center_main.stack_size());
+    }
+
+    #[test]
+    fn rotate_column_only_rotates_its_contents() {
+        let mut layout = Layout::default();
+        layout.rotate_column(ColumnKind::Stack, true);
+        layout.rotate_column(ColumnKind::Main, false);
+        layout.rotate_column(ColumnKind::SecondStack, true);
+        assert_eq!(Rotation::East, layout.columns.stack.rotate);
+        assert_eq!(Rotation::West, layout.columns.main.as_ref().unwrap().rotate);
+        assert_eq!(None, layout.columns.second_stack);
+        assert_eq!(Rotation::North, layout.rotate);
+    }
+
+    #[test]
+    fn swapping_main_and_stack_twice_results_in_the_original() {
+        let container = Rect::new(0, 0, 400, 200);
+        for layout in Layouts::default().layouts {
+            let mut swapped = layout.clone();
+            swapped.swap_main_and_stack();
+            if layout.columns.main.is_some() && layout.columns.second_stack.is_none() {
+                assert_ne!(
+                    main_rect(&layout, 3, &container),
+                    main_rect(&swapped, 3, &container),
+                    "{}",
+                    layout.name
+                );
+            }
+            swapped.swap_main_and_stack();
+            assert_eq!(layout, swapped);
+        }
+
+        let mut vertical = Layout::default();
+        vertical.columns.orientation = Orientation::Vertical;
+        vertical.swap_main_and_stack();
+        assert_eq!(
+            Some(Rect::new(0, 100, 400, 100)),
+            main_rect(&vertical, 3, &container)
+        );
+    }
+
+    #[test]
+    fn split_setters_change_existing_columns() {
+        let mut layout = defaults::center_main();
+        layout.set_main_split(Some(Split::Grid));
+        layout.set_stack_split(None);
+        layout.set_second_stack_split(Split::Dwindle);
+        assert_eq!(
+            Some(Split::Grid),
+            layout.columns.main.as_ref().unwrap().split
+        );
+        assert_eq!(None, layout.columns.stack.split);
+        assert_eq!(
+            Split::Dwindle,
+            layout.columns.second_stack.as_ref().unwrap().split
+        );
+
+        let mut monocle = defaults::monocle();
+        let before = monocle.clone();
+        monocle.set_main_split(Some(Split::Grid));
+        monocle.set_second_stack_split(Split::Grid);
+        assert_eq!(before, monocle);
+    }
+
+    #[test]
+    fn toggle_second_stack_switches_between_two_and_three_columns() {
+        let mut layout = defaults::main_and_vert_stack();
+        layout.set_main_size(Size::Ratio(0.6));
+        layout.toggle_second_stack();
+        assert_eq!(Some(SecondStack::default()), layout.columns.second_stack);
+        assert_eq!(Some(Size::Ratio(0.6)), layout.main_size());
+        layout.toggle_second_stack();
+        assert_eq!(None, layout.columns.second_stack);
+
+        let mut even_vertical = defaults::even_vertical();
+        even_vertical.toggle_second_stack();
+        assert_eq!(None, even_vertical.columns.second_stack);
+    }
+
+    #[test]
+    fn columns_are_added_and_removed_at_runtime() {
+        let mut layout = Layout::default();
+        let stack = Column {
+            capacity: Some(1),
+            ..Default::default()
+        };
+        // the column goes between the copied built-in main and stack columns
+        layout.add_column(1, stack);
+        layout.add_column(0, stack);
+        assert_eq!(4, layout.columns.list.len());
+        assert_eq!(ColumnKind::Main, layout.columns.list[1].kind);
+
+        // the main column keeps its size, the others share the remaining space
+        let container = Rect::new(0, 0, 600, 100);
+        assert_eq!(
+            vec![
+                Rect::new(0, 0, 100, 100),
+                Rect::new(100, 0, 300, 100),
+                Rect::new(400, 0, 100, 100),
+                Rect::new(500, 0, 100, 100),
+            ],
+            crate::apply(&layout, 4, &container)
+        );
+
+        assert_eq!(None, layout.remove_column(4));
+        assert_eq!(Some(stack), layout.remove_column(0));
+        assert_eq!(Some(stack), layout.remove_column(1));
+        // the copied built-in columns arrange the windows the same way
+        let built_in = Layout::default();
+        for window_count in 0..5 {
+            assert_eq!(
+                crate::apply(&built_in, window_count, &container),
+                crate::apply(&layout, window_count, &container)
+            );
+        }
+    }
+
+    #[test]
+    fn reset_restores_the_loaded_or_default_definition() {
+        let mut custom = defaults::main_and_vert_stack();
+        custom.name = "Custom".to_string();
+        custom.set_main_size(Size::Ratio(0.7));
+        let mut layouts = Layouts::new(vec![custom.clone(), defaults::monocle()]);
+        layouts.get_mut("Custom").unwrap().rotate(true);
+        assert!(layouts.reset("Custom"));
+        assert_eq!(Some(&custom), layouts.get("Custom"));
+
+        let mut grid = defaults::grid();
+        grid.rotate(true);
+        layouts.push(grid);
+        assert!(layouts.reset("Grid"));
+        assert_eq!(Some(&defaults::grid()), layouts.get("Grid"));
+        assert!(!layouts.reset("Unknown"));
+    }
+
+    #[test]
+    fn deserialized_layouts_reset_to_their_loaded_definition() {
+        let mut layout = defaults::fibonacci();
+        layout.rotate(false);
+        let json = serde_json::to_string(&Layouts::new(vec![layout.clone()])).unwrap();
+        let mut layouts: Layouts = serde_json::from_str(&json).unwrap();
+        layouts
+            .get_mut("Fibonacci")
+            .unwrap()
+            .toggle_flip_horizontal();
+        assert!(layouts.reset("Fibonacci"));
+        assert_eq!(Some(&layout), layouts.get("Fibonacci"));
+    }
+
+    #[test]
+    fn next_and_previous_layouts_wrap_around() {
+        let layouts = Layouts::new(vec![
+            defaults::monocle(),
+            defaults::grid(),
+            defaults::dwindle(),
+        ]);
+        let next = |name| layouts.next_of(name).map(|layout| layout.name.as_str());
+        let previous = |name| layouts.previous_of(name).map(|layout| layout.name.as_str());
+        assert_eq!(Some("Grid"), next("Monocle"));
+        assert_eq!(Some("Monocle"), next("Dwindle"));
+        assert_eq!(Some("Dwindle"), previous("Monocle"));
+        assert_eq!(Some("Grid"), previous("Dwindle"));
+        assert_eq!(None, next("Unknown"));
+        assert_eq!(None, previous("Unknown"));
+
+        let single = Layouts::new(vec![defaults::grid()]);
+        assert_eq!(single.get("Grid"), single.next_of("Grid"));
+        assert_eq!(single.get("Grid"), single.previous_of("Grid"));
+    }
+
+    #[test]
+    fn filter_by_tag_keeps_the_order() {
+        let mut layouts = Layouts::default();
+        for name in ["CenterMain", "Monocle", "CenterMainFluid"] {
+            let layout = layouts.get_mut(name).unwrap();
+            layout.tags = vec!["ultrawide".to_string(), "media".to_string()];
+        }
+        let ultrawide = layouts.filter_by_tag("ultrawide");
+        assert_eq!(
+            vec!["Monocle", "CenterMain", "CenterMainFluid"],
+            ultrawide.names().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            ultrawide.get("Monocle"),
+            ultrawide.next_of("CenterMainFluid")
+        );
+        assert!(layouts.filter_by_tag("coding").is_empty());
+    }
+
+    #[test]
+    fn layout_sets_resolve_to_the_referenced_layouts() {
+        let layouts = Layouts {
+            sets: vec![
+                LayoutSet::new("media", ["Monocle", "Grid"]),
+                LayoutSet::new("broken", ["Grid", "Unknown"]),
+            ],
+            ..Default::default()
+        };
+        let media = layouts.resolve_set("media").unwrap();
+        assert_eq!(vec!["Monocle", "Grid"], media.names().collect::<Vec<_>>());
+        assert_eq!(
+            vec!["Grid"],
+            layouts
+                .resolve_set("broken")
+                .unwrap()
+                .names()
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(None, layouts.resolve_set("unknown"));
+        assert_eq!(
+            vec![LayoutIssue::UnknownLayoutInSet(
+                "broken".to_string(),
+                "Unknown".to_string()
+            )],
+            layouts.validate_sets()
+        );
+
+        let json = serde_json::to_string(&layouts).unwrap();
+        assert_eq!(layouts, serde_json::from_str(&json).unwrap());
+    }
+
+    #[test]
+    fn layouts_can_be_inserted_removed_moved_and_replaced() {
+        let mut layouts = Layouts::new(vec![defaults::monocle(), defaults::grid()]);
+        layouts.insert_at(1, defaults::dwindle());
+        layouts.insert_at(10, defaults::fibonacci());
+        assert_eq!(
+            vec!["Monocle", "Dwindle", "Grid", "Fibonacci"],
+            layouts.names().collect::<Vec<_>>()
+        );
+
+        assert!(layouts.move_to("Monocle", 2));
+        assert!(layouts.move_to("Fibonacci", 0));
+        assert!(!layouts.move_to("Unknown", 0));
+        assert_eq!(
+            vec!["Fibonacci", "Dwindle", "Grid", "Monocle"],
+            layouts.names().collect::<Vec<_>>()
+        );
+
+        assert_eq!(Some(defaults::dwindle()), layouts.remove("Dwindle"));
+        assert_eq!(None, layouts.remove("Dwindle"));
+
+        let mut rotated = defaults::grid();
+        rotated.rotate(true);
+        assert_eq!(
+            Some(defaults::grid()),
+            layouts.replace("Grid", rotated.clone())
+        );
+        assert_eq!(Some(&rotated), layouts.get("Grid"));
+        assert_eq!(None, layouts.replace("Unknown", defaults::grid()));
+        assert_eq!(
+            vec!["Fibonacci", "Grid", "Monocle"],
+            layouts.names().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn merge_replaces_layouts_and_sets_of_the_same_name() {
+        let mut grid = defaults::grid();
+        grid.rotate(true);
+        let mut layouts = Layouts::new(vec![defaults::monocle(), defaults::grid()]);
+        layouts.sets = vec![LayoutSet::new("all", ["Monocle", "Grid"])];
+        let other = Layouts {
+            sets: vec![LayoutSet::new("all", ["Grid"])],
+            ..Layouts::new(vec![grid.clone(), defaults::dwindle()])
+        };
+        layouts.merge(other);
+        assert_eq!(
+            vec!["Monocle", "Grid", "Dwindle"],
+            layouts.names().collect::<Vec<_>>()
+        );
+        assert_eq!(Some(&grid), layouts.get("Grid"));
+        assert_eq!(vec![LayoutSet::new("all", ["Grid"])], layouts.sets);
+
+        layouts.get_mut("Grid").unwrap().rotate(true);
+        assert!(layouts.reset("Grid"));
+        assert_eq!(Some(&grid), layouts.get("Grid"));
+        assert!(layouts.clone().dedup().is_empty());
+    }
+
     #[test]
     fn main_window_count_does_not_go_below_zero() {
         let mut layout = Layout::default();
@@ -0,0 +1,131 @@
+use serde::{Deserialize, Serialize};
+
+use crate::geometry::{Gaps, Margin, Remainder, Reserve, ReservedGaps, Size};
+
+use super::{Layout, LayoutPatch, Main};
+
+/// Values all layouts of a set of [`super::Layouts`] inherit unless they set
+/// them themselves, so that a config doesn't need to repeat them for every layout.
+/// A layout loaded from a config sets the values it contains, even if they are
+/// the built-in default. Any other layout sets a value itself if it differs from
+/// the built-in default (eg. [`Reserve::None`] or a `main` size of `50%`).
+///
+/// ```
+/// use leftwm_layouts::{
+///     geometry::{Reserve, Size},
+///     layouts::{LayoutDefaults, Layouts},
+/// };
+///
+/// let mut layouts = Layouts::default();
+/// layouts.apply_defaults(LayoutDefaults {
+///     reserve: Some(Reserve::Reserve),
+///     main_size: Some(Size::Ratio(0.6)),
+///     ..Default::default()
+/// });
+/// let layout = layouts.get("MainAndVertStack").unwrap();
+/// assert_eq!(Reserve::Reserve, layout.reserve);
+/// assert_eq!(Some(Size::Ratio(0.6)), layout.main_size());
+/// ```
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+#[serde(default)]
+pub struct LayoutDefaults {
+    /// See [`Layout::reserve`] (default: [`None`]).
+    pub reserve: Option<Reserve>,
+
+    /// See [`Layout::reserved_gaps`] (default: [`None`]).
+    pub reserved_gaps: Option<ReservedGaps>,
+
+    /// See [`Layout::remainder`] (default: [`None`]).
+    pub remainder: Option<Remainder>,
+
+    /// See [`Layout::title_bar_height`] (default: [`None`]).
+    pub title_bar_height: Option<u32>,
+
+    /// See [`Layout::gaps`], only inherited by layouts without gaps (default: [`None`]).
+    pub gaps: Option<Gaps>,
+
+    /// See [`Layout::margin`], only inherited by layouts without a margin (default: [`None`]).
+    pub margin: Option<Margin>,
+
+    /// See [`Main::size`], only inherited by layouts with a `main` column (default: [`None`]).
+    pub main_size: Option<Size>,
+
+    /// See [`Main::count`], only inherited by layouts with a `main` column (default: [`None`]).
+    pub main_count: Option<usize>,
+}
+
+impl LayoutDefaults {
+    /// Set the values of the provided layout it doesn't set itself.
+    pub fn apply_to(&self, layout: &mut Layout) {
+        self.apply_to_patched(layout, &LayoutPatch::default());
+    }
+
+    /// Same as [`LayoutDefaults::apply_to`], but the values the layout
+    /// was patched with count as set, see [`Layout::patch`].
+    pub(super) fn apply_to_patched(&self, layout: &mut Layout, patch: &LayoutPatch) {
+        let builtin = Layout::default();
+        inherit(
+            &mut layout.reserve,
+            builtin.reserve,
+            self.reserve.filter(|_| patch.reserve.is_none()),
+        );
+        inherit(
+            &mut layout.reserved_gaps,
+            builtin.reserved_gaps,
+            self.reserved_gaps.filter(|_| patch.reserved_gaps.is_none()),
+        );
+        inherit(
+            &mut layout.remainder,
+            builtin.remainder,
+            self.remainder.filter(|_| patch.remainder.is_none()),
+        );
+        inherit(
+            &mut layout.title_bar_height,
+            builtin.title_bar_height,
+            self.title_bar_height
+                .filter(|_| patch.title_bar_height.is_none()),
+        );
+        inherit(
+            &mut layout.gaps,
+            builtin.gaps,
+            self.gaps.map(Some).filter(|_| patch.gaps.is_none()),
+        );
+        inherit(
+            &mut layout.margin,
+            builtin.margin,
+            self.margin.map(Some).filter(|_| patch.margin.is_none()),
+        );
+        if let Some(main) = layout.columns.main.as_mut() {
+            let patch = patch
+                .columns
+                .as_ref()
+                .and_then(|columns| columns.main.clone().flatten())
+                .unwrap_or_default();
+            let builtin = Main::default();
+            inherit(
+                &mut main.size,
+                builtin.size,
+                self.main_size.filter(|_| patch.size.is_none()),
+            );
+            inherit(
+                &mut main.count,
+                builtin.count,
+                self.main_count.filter(|_| patch.count.is_none()),
+            );
+        }
+    }
+
+    pub(super) fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Replace the value by the inherited one, if there is one
+/// and the value is still the built-in default.
+fn inherit<T: PartialEq>(value: &mut T, builtin: T, inherited: Option<T>) {
+    if let Some(inherited) = inherited {
+        if *value == builtin {
+            *value = inherited;
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+use crate::Layout;
+
+use super::defaults;
+
+/// The default layouts, to reference them without typing their names.
+///
+/// ```
+/// use leftwm_layouts::layouts::{defaults, LayoutKind};
+///
+/// assert_eq!(defaults::center_main(), LayoutKind::CenterMain.to_layout());
+/// assert_eq!(Ok(LayoutKind::CenterMain), "CenterMain".parse());
+/// assert_eq!("CenterMain", LayoutKind::CenterMain.to_string());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum LayoutKind {
+    /// See [`defaults::even_horizontal`].
+    EvenHorizontal,
+    /// See [`defaults::even_vertical`].
+    EvenVertical,
+    /// See [`defaults::monocle`].
+    Monocle,
+    /// See [`defaults::grid`].
+    Grid,
+    /// See [`defaults::main_and_vert_stack`].
+    MainAndVertStack,
+    /// See [`defaults::main_and_horizontal_stack`].
+    MainAndHorizontalStack,
+    /// See [`defaults::right_main_and_vert_stack`].
+    RightMainAndVertStack,
+    /// See [`defaults::fibonacci`].
+    Fibonacci,
+    /// See [`defaults::dwindle`].
+    Dwindle,
+    /// See [`defaults::main_and_deck`].
+    MainAndDeck,
+    /// See [`defaults::center_main`].
+    CenterMain,
+    /// See [`defaults::center_main_balanced`].
+    CenterMainBalanced,
+    /// See [`defaults::center_main_fluid`].
+    CenterMainFluid,
+    /// See [`defaults::dual_main`].
+    DualMain,
+    /// See [`defaults::bsp`].
+    Bsp,
+    /// See [`defaults::top_main_and_stack`].
+    TopMainAndStack,
+    /// See [`defaults::main_and_grid_stack`].
+    MainAndGridStack,
+    /// See [`defaults::three_column_equal`].
+    ThreeColumnEqual,
+    /// See [`defaults::main_and_dual_stack`].
+    MainAndDualStack,
+}
+
+impl LayoutKind {
+    /// All default layouts, in the same order as they appear
+    /// in [`crate::layouts::Layouts::default`].
+    pub const ALL: [LayoutKind; 19] = [
+        LayoutKind::EvenHorizontal,
+        LayoutKind::EvenVertical,
+        LayoutKind::Monocle,
+        LayoutKind::Grid,
+        LayoutKind::MainAndVertStack,
+        LayoutKind::MainAndHorizontalStack,
+        LayoutKind::RightMainAndVertStack,
+        LayoutKind::Fibonacci,
+        LayoutKind::Dwindle,
+        LayoutKind::MainAndDeck,
+        LayoutKind::CenterMain,
+        LayoutKind::CenterMainBalanced,
+        LayoutKind::CenterMainFluid,
+        LayoutKind::DualMain,
+        LayoutKind::Bsp,
+        LayoutKind::TopMainAndStack,
+        LayoutKind::MainAndGridStack,
+        LayoutKind::ThreeColumnEqual,
+        LayoutKind::MainAndDualStack,
+    ];
+
+    /// The name of the default layout.
+    pub fn name(self) -> &'static str {
+        match self {
+            LayoutKind::EvenHorizontal => defaults::EVEN_HORIZONTAL,
+            LayoutKind::EvenVertical => defaults::EVEN_VERTICAL,
+            LayoutKind::Monocle => defaults::MONOCLE,
+            LayoutKind::Grid => defaults::GRID,
+            LayoutKind::MainAndVertStack => defaults::MAIN_AND_VERT_STACK,
+            LayoutKind::MainAndHorizontalStack => defaults::MAIN_AND_HORIZONTAL_STACK,
+            LayoutKind::RightMainAndVertStack => defaults::RIGHT_MAIN_AND_VERT_STACK,
+            LayoutKind::Fibonacci => defaults::FIBONACCI,
+            LayoutKind::Dwindle => defaults::DWINDLE,
+            LayoutKind::MainAndDeck => defaults::MAIN_AND_DECK,
+            LayoutKind::CenterMain => defaults::CENTER_MAIN,
+            LayoutKind::CenterMainBalanced => defaults::CENTER_MAIN_BALANCED,
+            LayoutKind::CenterMainFluid => defaults::CENTER_MAIN_FLUID,
+            LayoutKind::DualMain => defaults::DUAL_MAIN,
+            LayoutKind::Bsp => defaults::BSP,
+            LayoutKind::TopMainAndStack => defaults::TOP_MAIN_AND_STACK,
+            LayoutKind::MainAndGridStack => defaults::MAIN_AND_GRID_STACK,
+            LayoutKind::ThreeColumnEqual => defaults::THREE_COLUMN_EQUAL,
+            LayoutKind::MainAndDualStack => defaults::MAIN_AND_DUAL_STACK,
+        }
+    }
+
+    /// A new instance of the default layout.
+    pub fn to_layout(self) -> Layout {
+        match self {
+            LayoutKind::EvenHorizontal => defaults::even_horizontal(),
+            LayoutKind::EvenVertical => defaults::even_vertical(),
+            LayoutKind::Monocle => defaults::monocle(),
+            LayoutKind::Grid => defaults::grid(),
+            LayoutKind::MainAndVertStack => defaults::main_and_vert_stack(),
+            LayoutKind::MainAndHorizontalStack => defaults::main_and_horizontal_stack(),
+            LayoutKind::RightMainAndVertStack => defaults::right_main_and_vert_stack(),
+            LayoutKind::Fibonacci => defaults::fibonacci(),
+            LayoutKind::Dwindle => defaults::dwindle(),
+            LayoutKind::MainAndDeck => defaults::main_and_deck(),
+            LayoutKind::CenterMain => defaults::center_main(),
+            LayoutKind::CenterMainBalanced => defaults::center_main_balanced(),
+            LayoutKind::CenterMainFluid => defaults::center_main_fluid(),
+            LayoutKind::DualMain => defaults::dual_main(),
+            LayoutKind::Bsp => defaults::bsp(),
+            LayoutKind::TopMainAndStack => defaults::top_main_and_stack(),
+            LayoutKind::MainAndGridStack => defaults::main_and_grid_stack(),
+            LayoutKind::ThreeColumnEqual => defaults::three_column_equal(),
+            LayoutKind::MainAndDualStack => defaults::main_and_dual_stack(),
+        }
+    }
+}
+
+impl From<LayoutKind> for Layout {
+    fn from(kind: LayoutKind) -> Self {
+        kind.to_layout()
+    }
+}
+
+impl fmt::Display for LayoutKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for LayoutKind {
+    type Err = ();
+
+    /// Parses the name of a default layout, ignoring the case (eg. `centermain`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        LayoutKind::ALL
+            .into_iter()
+            .find(|kind| kind.name().eq_ignore_ascii_case(s))
+            .ok_or(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LayoutKind;
+    use crate::layouts::{defaults, Layouts};
+
+    #[test]
+    fn kinds_match_the_default_layouts() {
+        let layouts: Vec<_> = LayoutKind::ALL.map(LayoutKind::to_layout).into();
+        assert_eq!(Layouts::default().layouts(), layouts);
+        let names: Vec<_> = LayoutKind::ALL.map(LayoutKind::name).into();
+        assert_eq!(defaults::all_names(), names);
+    }
+
+    #[test]
+    fn kinds_parse_their_names_in_any_case() {
+        for kind in LayoutKind::ALL {
+            assert_eq!(Ok(kind), kind.to_string().parse());
+            assert_eq!(Ok(kind), kind.name().to_ascii_uppercase().parse());
+        }
+        assert_eq!(Err(()), "CenterMane".parse::<LayoutKind>());
+    }
+}
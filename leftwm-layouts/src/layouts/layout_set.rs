@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+/// A named, ordered list of layouts of a set of [`super::Layouts`], referenced by their names,
+/// eg. the layouts a workspace cycles through. See [`super::Layouts::resolve_set`].
+///
+/// ```
+/// use leftwm_layouts::layouts::{LayoutSet, Layouts};
+///
+/// let mut layouts = Layouts::default();
+/// layouts.sets.push(LayoutSet::new("coding", ["MainAndVertStack", "Monocle"]));
+/// let coding = layouts.resolve_set("coding").unwrap();
+/// assert_eq!(vec!["MainAndVertStack", "Monocle"], coding.names().collect::<Vec<_>>());
+/// ```
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+#[serde(default)]
+pub struct LayoutSet {
+    /// Name and identifier of the layout set.
+    pub name: String,
+
+    /// The names of the layouts in the set, in order.
+    pub layouts: Vec<String>,
+}
+
+impl LayoutSet {
+    /// Create a layout set with the provided name, referencing the layouts with the provided names.
+    pub fn new<I, S>(name: &str, layouts: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            name: name.to_string(),
+            layouts: layouts.into_iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -0,0 +1,89 @@
+//! Compatibility with the layout names of LeftWM before it used this crate.
+//!
+//! LeftWM used to have a fixed set of built-in layouts, which users referenced by
+//! the names of its `Layout` enum in their configs. This module maps those names
+//! (in any casing, and in `snake_case` or `kebab-case`) to the [`super::defaults`]
+//! replacing them, so that old configs keep working.
+//!
+//! ## Example
+//! ```rust
+//! use leftwm_layouts::layouts::legacy;
+//!
+//! assert_eq!(Some("Grid"), legacy::name("GridHorizontal"));
+//! assert_eq!(Some("CenterMainBalanced"), legacy::name("center_main_balanced"));
+//! assert_eq!(None, legacy::name("Spiral"));
+//! ```
+
+use crate::Layout;
+
+use super::{defaults, Layouts};
+
+/// The legacy names that don't match the name of their replacement,
+/// in their normalized form (see [`normalize`]).
+const RENAMED: [(&str, &str); 4] = [
+    ("gridhorizontal", defaults::GRID),
+    ("leftmain", defaults::MAIN_AND_VERT_STACK),
+    ("leftwiderrightstack", defaults::MAIN_AND_VERT_STACK),
+    ("rightwiderleftstack", defaults::RIGHT_MAIN_AND_VERT_STACK),
+];
+
+/// The name of the default layout replacing the provided legacy layout name,
+/// or [`None`] if it is neither a legacy name nor the name of a default layout.
+pub fn name(legacy: &str) -> Option<&'static str> {
+    let normalized = normalize(legacy);
+    RENAMED
+        .iter()
+        .find(|(old, _)| *old == normalized)
+        .map(|(_, new)| *new)
+        .or_else(|| {
+            defaults::all_names()
+                .iter()
+                .find(|name| normalize(name) == normalized)
+                .copied()
+        })
+}
+
+/// The default layout replacing the provided legacy layout name, see [`name`].
+pub fn layout(legacy: &str) -> Option<Layout> {
+    Layouts::default().get(name(legacy)?).cloned()
+}
+
+/// Lowercase the name and drop the separators, so that `CenterMain`,
+/// `center_main` and `center-main` are all the same name.
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| !matches!(c, '_' | '-' | ' '))
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{layout, name};
+    use crate::layouts::{defaults, Layouts};
+
+    #[test]
+    fn every_default_name_maps_to_itself() {
+        for default in Layouts::default() {
+            assert_eq!(Some(default.name.as_str()), name(&default.name));
+            assert_eq!(Some(&default), layout(&default.name).as_ref());
+        }
+    }
+
+    #[test]
+    fn legacy_names_map_in_any_casing() {
+        for legacy in [
+            "GridHorizontal",
+            "grid_horizontal",
+            "grid-horizontal",
+            "GRIDHORIZONTAL",
+        ] {
+            assert_eq!(Some(defaults::GRID), name(legacy));
+        }
+        assert_eq!(
+            Some(defaults::right_main_and_vert_stack()),
+            layout("RightWiderLeftStack")
+        );
+        assert_eq!(None, layout("NotALayout"));
+    }
+}
@@ -0,0 +1,246 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::geometry::{Flip, Reserve, Rotation, Size};
+
+use super::{ColumnKind, Layout, Layouts};
+
+/// The size of the `main` column from which on the stack is barely visible,
+/// see [`LintWarning::LargeMain`].
+const LARGE_MAIN_RATIO: f32 = 0.9;
+
+/// A suspicious, but valid configuration of a [`Layout`], see [`Layout::lint`].
+///
+/// Other than an invalid configuration, the layout works as configured,
+/// but most likely not as intended.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum LintWarning {
+    /// The `main` column takes up more than 90% of the space,
+    /// while a stack is configured to show windows next to it.
+    LargeMain(f32),
+
+    /// The `second_stack` is ignored, because there is no `main` column
+    /// or there is a `second_main` column instead.
+    IgnoredSecondStack,
+
+    /// The `second_stack` is not reserved (see [`Reserve::None`]), so the layout
+    /// behaves like `CenterMain`, whose stacks take over the space of each other.
+    /// Layouts named like a default layout doing the same are not flagged.
+    UnreservedSecondStack,
+
+    /// The layout is the same as the default layout with the provided name,
+    /// apart from its own name and tags.
+    SameAsDefault(String),
+
+    /// The rotation and flip of the whole layout cancel each other out.
+    LayoutTransformIsIdentity,
+
+    /// The rotation and flip of the columns cancel each other out.
+    ColumnsTransformIsIdentity,
+
+    /// The rotation and flip of the contents of a column cancel each other out.
+    ColumnTransformIsIdentity(ColumnKind),
+}
+
+impl fmt::Display for LintWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LintWarning::LargeMain(ratio) => write!(
+                f,
+                "the main column takes up {:.0}% of the space, the stack is barely visible",
+                ratio * 100.0
+            ),
+            LintWarning::IgnoredSecondStack => {
+                write!(f, "the second stack is ignored by this layout")
+            }
+            LintWarning::UnreservedSecondStack => write!(
+                f,
+                "the second stack is not reserved, the layout behaves like CenterMain"
+            ),
+            LintWarning::SameAsDefault(name) => {
+                write!(f, "the layout is the same as the default layout {name}")
+            }
+            LintWarning::LayoutTransformIsIdentity => {
+                write!(
+                    f,
+                    "the rotation and flip of the layout cancel each other out"
+                )
+            }
+            LintWarning::ColumnsTransformIsIdentity => {
+                write!(
+                    f,
+                    "the rotation and flip of the columns cancel each other out"
+                )
+            }
+            LintWarning::ColumnTransformIsIdentity(kind) => write!(
+                f,
+                "the rotation and flip of the {kind:?} column cancel each other out"
+            ),
+        }
+    }
+}
+
+/// Whether rotating by `rotation` and then flipping by `flip` leaves every tile in place,
+/// which is the case for a half turn followed by flipping in both directions.
+fn is_identity(rotation: Rotation, flip: Flip) -> bool {
+    rotation == Rotation::South && flip == Flip::Both
+}
+
+pub(super) fn lint(layout: &Layout) -> Vec<LintWarning> {
+    let mut warnings = vec![];
+    let columns = &layout.columns;
+
+    if let Some(main) = &columns.main {
+        if let Size::Ratio(ratio) = main.size {
+            if ratio > LARGE_MAIN_RATIO && columns.stack.split.is_some() {
+                warnings.push(LintWarning::LargeMain(ratio));
+            }
+        }
+    }
+
+    if columns.second_stack.is_some() && (columns.main.is_none() || columns.second_main.is_some()) {
+        warnings.push(LintWarning::IgnoredSecondStack);
+    }
+
+    let defaults = Layouts::default();
+    for default in &defaults {
+        let mut renamed = layout.clone();
+        renamed.name.clone_from(&default.name);
+        renamed.tags.clone_from(&default.tags);
+        if layout.name != default.name && renamed == *default {
+            warnings.push(LintWarning::SameAsDefault(default.name.clone()));
+        }
+    }
+
+    let has_unreserved_second_stack = |layout: &Layout| {
+        let columns = &layout.columns;
+        layout.reserve == Reserve::None
+            && columns.second_stack.is_some()
+            && columns.main.is_some()
+            && columns.second_main.is_none()
+    };
+    // a copy of a default layout is already flagged as such
+    let is_copy = matches!(warnings.last(), Some(LintWarning::SameAsDefault(_)));
+    if has_unreserved_second_stack(layout)
+        && !is_copy
+        && !defaults
+            .get(&layout.name)
+            .is_some_and(has_unreserved_second_stack)
+    {
+        warnings.push(LintWarning::UnreservedSecondStack);
+    }
+
+    if is_identity(layout.rotate, layout.flip) {
+        warnings.push(LintWarning::LayoutTransformIsIdentity);
+    }
+    if is_identity(columns.rotate, columns.flip) {
+        warnings.push(LintWarning::ColumnsTransformIsIdentity);
+    }
+    let column_transforms = [
+        (
+            ColumnKind::Main,
+            columns.main.as_ref().map(|c| (c.rotate, c.flip)),
+        ),
+        (
+            ColumnKind::Stack,
+            Some((columns.stack.rotate, columns.stack.flip)),
+        ),
+        (
+            ColumnKind::SecondStack,
+            columns.second_stack.as_ref().map(|c| (c.rotate, c.flip)),
+        ),
+        (
+            ColumnKind::SecondMain,
+            columns.second_main.as_ref().map(|c| (c.rotate, c.flip)),
+        ),
+    ];
+    for (kind, transform) in column_transforms {
+        if let Some((rotation, flip)) = transform {
+            if is_identity(rotation, flip) {
+                warnings.push(LintWarning::ColumnTransformIsIdentity(kind));
+            }
+        }
+    }
+
+    warnings
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        geometry::{Flip, Reserve, Rotation, Size},
+        layouts::{presets, ColumnKind, Layouts, SecondStack},
+        Layout,
+    };
+
+    use super::LintWarning;
+
+    #[test]
+    fn default_layouts_and_presets_have_no_warnings() {
+        let layouts = Layouts::default().into_iter().chain(presets::all());
+        for layout in layouts {
+            assert_eq!(Vec::<LintWarning>::new(), layout.lint(), "{}", layout.name);
+        }
+    }
+
+    #[test]
+    fn large_main_with_stack_is_flagged() {
+        let mut layout = Layout::default();
+        layout.set_main_size(Size::Ratio(0.95));
+        assert_eq!(vec![LintWarning::LargeMain(0.95)], layout.lint());
+        layout.columns.stack.split = None;
+        assert!(layout.lint().is_empty());
+    }
+
+    #[test]
+    fn copy_of_a_default_layout_is_flagged() {
+        let mut layout = Layouts::default().get("CenterMain").unwrap().clone();
+        layout.name = "MyCenterMain".to_string();
+        assert_eq!(
+            vec![LintWarning::SameAsDefault("CenterMain".to_string())],
+            layout.lint()
+        );
+    }
+
+    #[test]
+    fn ignored_second_stack_is_flagged() {
+        let mut layout = Layouts::default().get("EvenVertical").unwrap().clone();
+        layout.columns.second_stack = Some(SecondStack::default());
+        assert_eq!(vec![LintWarning::IgnoredSecondStack], layout.lint());
+    }
+
+    #[test]
+    fn unreserved_second_stack_is_flagged() {
+        let mut layout = Layouts::default().get("CenterMainFluid").unwrap().clone();
+        layout.name = "MyCenterMain".to_string();
+        layout.reserve = Reserve::None;
+        layout.set_main_size(Size::Ratio(0.6));
+        assert_eq!(vec![LintWarning::UnreservedSecondStack], layout.lint());
+        // the default layouts doing the same on purpose are not flagged
+        let mut layout = Layouts::default().get("CenterMain").unwrap().clone();
+        layout.set_main_size(Size::Ratio(0.6));
+        assert!(layout.lint().is_empty());
+    }
+
+    #[test]
+    fn transforms_cancelling_each_other_out_are_flagged() {
+        let mut layout = Layout {
+            rotate: Rotation::South,
+            flip: Flip::Both,
+            ..Default::default()
+        };
+        layout.columns.rotate = Rotation::South;
+        layout.columns.flip = Flip::Both;
+        layout.columns.stack.rotate = Rotation::South;
+        layout.columns.stack.flip = Flip::Both;
+        assert_eq!(
+            vec![
+                LintWarning::LayoutTransformIsIdentity,
+                LintWarning::ColumnsTransformIsIdentity,
+                LintWarning::ColumnTransformIsIdentity(ColumnKind::Stack),
+            ],
+            layout.lint()
+        );
+    }
+}
@@ -1,13 +1,65 @@
+#[cfg(any(feature = "ron", feature = "json", feature = "yaml"))]
+mod canonical;
 pub mod columns;
-mod defaults;
+mod command;
+#[cfg(any(feature = "ron", feature = "json", feature = "yaml"))]
+mod config;
+pub mod defaults;
+mod display_name;
 mod layout;
+mod layout_defaults;
+mod layout_kind;
+mod layout_set;
+pub mod legacy;
+mod lint;
+mod patch;
+pub mod presets;
+mod resize;
+mod size_memory;
+mod suggestion;
+mod validate;
 
+pub use columns::column_list;
+pub use columns::dual_main;
 pub use columns::three_column;
+pub use columns::three_column_along;
 pub use columns::two_column;
+pub use columns::two_column_along;
 
+pub use command::LayoutCommand;
+
+#[cfg(any(feature = "ron", feature = "json", feature = "yaml"))]
+pub use config::ConfigError;
+
+pub use display_name::DisplayNameResolver;
+
+pub use layout::Column;
+pub use layout::ColumnKind;
 pub use layout::Columns;
 pub use layout::Layout;
 pub use layout::Layouts;
 pub use layout::Main;
+pub use layout::MainPosition;
 pub use layout::SecondStack;
 pub use layout::Stack;
+pub use layout::StackFillOrder;
+
+pub use layout_defaults::LayoutDefaults;
+
+pub use layout_kind::LayoutKind;
+
+pub use layout_set::LayoutSet;
+
+pub use lint::LintWarning;
+
+pub use patch::ColumnsPatch;
+pub use patch::LayoutPatch;
+pub use patch::MainPatch;
+pub use patch::SecondStackPatch;
+pub use patch::StackPatch;
+
+pub use size_memory::SizeMemory;
+
+pub use suggestion::LayoutSuggestions;
+
+pub use validate::LayoutIssue;
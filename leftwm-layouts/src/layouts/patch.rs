@@ -0,0 +1,364 @@
+use serde::{Deserialize, Deserializer};
+
+use crate::geometry::{
+    Flip, Gaps, Margin, Orientation, Remainder, Reserve, ReservedGaps, Rotation, Size, Spiral,
+    Split, TakeoverOrder,
+};
+
+use super::{Column, Columns, Layout, Main, MainPosition, SecondStack, StackFillOrder};
+
+/// Declare a struct with the provided fields of another struct, each of which is
+/// [`None`] unless it is present in the deserialized input.
+macro_rules! partial {
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident {
+            $($(#[$field_meta:meta])* $field:ident: $ty:ty),+ $(,)?
+        }
+    ) => {
+        #[derive(Deserialize, Debug, Default, Clone, PartialEq)]
+        $(#[$meta])*
+        #[serde(default)]
+        pub struct $name {
+            $(
+                $(#[$field_meta])*
+                #[serde(deserialize_with = "present")]
+                pub $field: Option<$ty>,
+            )+
+        }
+    };
+}
+
+partial! {
+    /// The fields of a [`Layout`] to change, see [`Layout::patch`].
+    ///
+    /// Other than a deserialized [`Layout`], a deserialized patch tells the fields
+    /// that are present apart from the ones that are left out, even if they are
+    /// set to their default value, eg. to remove the [`Reserve`] of a layout.
+    ///
+    /// ```
+    /// use leftwm_layouts::{geometry::Reserve, layouts::{defaults, LayoutPatch}};
+    ///
+    /// let mut layout = defaults::center_main_fluid();
+    /// layout.patch(LayoutPatch {
+    ///     reserve: Some(Reserve::None),
+    ///     ..Default::default()
+    /// });
+    /// assert_eq!(Reserve::None, layout.reserve);
+    /// ```
+    #[serde(rename = "Layout")]
+    pub struct LayoutPatch {
+        /// See [`Layout::name`].
+        name: String,
+        /// See [`Layout::tags`].
+        tags: Vec<String>,
+        /// See [`Layout::flip`].
+        flip: Flip,
+        /// See [`Layout::rotate`].
+        rotate: Rotation,
+        /// See [`Layout::reserve`].
+        reserve: Reserve,
+        /// See [`Layout::reserved_gaps`].
+        reserved_gaps: ReservedGaps,
+        /// See [`Layout::takeover_order`].
+        takeover_order: TakeoverOrder,
+        /// See [`Layout::stable_main`].
+        stable_main: bool,
+        /// See [`Layout::remainder`].
+        remainder: Remainder,
+        /// See [`Layout::title_bar_height`].
+        title_bar_height: u32,
+        /// See [`Layout::gaps`].
+        gaps: Option<Gaps>,
+        /// See [`Layout::margin`].
+        margin: Option<Margin>,
+        /// See [`Layout::columns`], patched field by field.
+        columns: ColumnsPatch,
+    }
+}
+
+partial! {
+    /// The fields of [`Columns`] to change, see [`LayoutPatch`].
+    #[serde(rename = "Columns")]
+    pub struct ColumnsPatch {
+        /// See [`Columns::flip`].
+        flip: Flip,
+        /// See [`Columns::rotate`].
+        rotate: Rotation,
+        /// See [`Columns::orientation`].
+        orientation: Orientation,
+        /// See [`Columns::main_position`].
+        main_position: Option<MainPosition>,
+        /// See [`Columns::adapt_to_portrait`].
+        adapt_to_portrait: bool,
+        /// See [`Columns::main`], patched field by field if the layout has a `main` column.
+        main: Option<MainPatch>,
+        /// See [`Columns::stack`], patched field by field.
+        stack: StackPatch,
+        /// See [`Columns::second_stack`], patched field by field
+        /// if the layout has a `second_stack` column.
+        second_stack: Option<SecondStackPatch>,
+        /// See [`Columns::balance_stacks`].
+        balance_stacks: Option<bool>,
+        /// See [`Columns::stack_distribution`].
+        stack_distribution: Option<f32>,
+        /// See [`Columns::stack_fill_order`].
+        stack_fill_order: StackFillOrder,
+        /// See [`Columns::second_main`], patched field by field
+        /// if the layout has a `second_main` column.
+        second_main: Option<MainPatch>,
+        /// See [`Columns::list`], which is replaced as a whole.
+        list: Vec<Column>,
+    }
+}
+
+partial! {
+    /// The fields of a [`Main`] column to change, see [`LayoutPatch`].
+    #[serde(rename = "Main")]
+    pub struct MainPatch {
+        /// See [`Main::count`].
+        count: usize,
+        /// See [`Main::max_count`].
+        max_count: Option<usize>,
+        /// See [`Main::count_ratio`].
+        count_ratio: Option<f32>,
+        /// See [`Main::size`].
+        size: Size,
+        /// See [`Main::size_curve`].
+        size_curve: Vec<(usize, Size)>,
+        /// See [`Main::max_size`].
+        max_size: Option<Size>,
+        /// See [`Main::flip`].
+        flip: Flip,
+        /// See [`Main::rotate`].
+        rotate: Rotation,
+        /// See [`Main::split`].
+        split: Option<Split>,
+        /// See [`Main::spiral`].
+        spiral: Spiral,
+        /// See [`Main::reverse`].
+        reverse: bool,
+    }
+}
+
+partial! {
+    /// The fields of a [`super::Stack`] column to change, see [`LayoutPatch`].
+    #[serde(rename = "Stack")]
+    pub struct StackPatch {
+        /// See [`super::Stack::flip`].
+        flip: Flip,
+        /// See [`super::Stack::rotate`].
+        rotate: Rotation,
+        /// See [`super::Stack::split`].
+        split: Option<Split>,
+        /// See [`super::Stack::spiral`].
+        spiral: Spiral,
+        /// See [`super::Stack::min_size`].
+        min_size: Option<(u32, u32)>,
+        /// See [`super::Stack::reverse`].
+        reverse: bool,
+    }
+}
+
+partial! {
+    /// The fields of a [`SecondStack`] column to change, see [`LayoutPatch`].
+    #[serde(rename = "SecondStack")]
+    pub struct SecondStackPatch {
+        /// See [`SecondStack::size`].
+        size: Option<Size>,
+        /// See [`SecondStack::flip`].
+        flip: Flip,
+        /// See [`SecondStack::rotate`].
+        rotate: Rotation,
+        /// See [`SecondStack::split`].
+        split: Split,
+        /// See [`SecondStack::spiral`].
+        spiral: Spiral,
+        /// See [`SecondStack::min_size`].
+        min_size: Option<(u32, u32)>,
+        /// See [`SecondStack::reverse`].
+        reverse: bool,
+    }
+}
+
+/// Deserialize a field that is present in the input, so that
+/// it can be told apart from a field that is left out.
+fn present<'de, D: Deserializer<'de>, T: Deserialize<'de>>(
+    deserializer: D,
+) -> Result<Option<T>, D::Error> {
+    T::deserialize(deserializer).map(Some)
+}
+
+/// Set the provided fields of the target to the ones the patch contains.
+macro_rules! patch_fields {
+    ($target:expr, $patch:expr, $($field:ident),+ $(,)?) => {
+        $(
+            if let Some(value) = $patch.$field {
+                $target.$field = value;
+            }
+        )+
+    };
+}
+
+pub(super) fn patch(layout: &mut Layout, patch: LayoutPatch) {
+    patch_fields!(
+        layout,
+        patch,
+        name,
+        tags,
+        flip,
+        rotate,
+        reserve,
+        reserved_gaps,
+        takeover_order,
+        stable_main,
+        remainder,
+        title_bar_height,
+        gaps,
+        margin,
+    );
+    if let Some(columns) = patch.columns {
+        patch_columns(&mut layout.columns, columns);
+    }
+}
+
+fn patch_columns(columns: &mut Columns, patch: ColumnsPatch) {
+    patch_fields!(
+        columns,
+        patch,
+        flip,
+        rotate,
+        orientation,
+        main_position,
+        adapt_to_portrait,
+        balance_stacks,
+        stack_distribution,
+        stack_fill_order,
+        list,
+    );
+    patch_main(&mut columns.main, patch.main);
+    patch_main(&mut columns.second_main, patch.second_main);
+    if let Some(patch) = patch.stack {
+        patch_fields!(
+            columns.stack,
+            patch,
+            flip,
+            rotate,
+            split,
+            spiral,
+            min_size,
+            reverse
+        );
+    }
+    match patch.second_stack {
+        Some(Some(patch)) => {
+            let second_stack = columns
+                .second_stack
+                .get_or_insert_with(SecondStack::default);
+            patch_fields!(
+                second_stack,
+                patch,
+                size,
+                flip,
+                rotate,
+                split,
+                spiral,
+                min_size,
+                reverse
+            );
+        }
+        Some(None) => columns.second_stack = None,
+        None => {}
+    }
+}
+
+/// Patch the fields of an existing column, add a column
+/// with the fields of the patch, or remove the column.
+fn patch_main(main: &mut Option<Main>, patch: Option<Option<MainPatch>>) {
+    match patch {
+        Some(Some(patch)) => {
+            let main = main.get_or_insert_with(Main::default);
+            patch_fields!(
+                main,
+                patch,
+                count,
+                max_count,
+                count_ratio,
+                size,
+                size_curve,
+                max_size,
+                flip,
+                rotate,
+                split,
+                spiral,
+                reverse,
+            );
+        }
+        Some(None) => *main = None,
+        None => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LayoutPatch;
+    use crate::{
+        geometry::{Reserve, Rotation, Size, Split},
+        layouts::{defaults, Main},
+    };
+
+    fn patch(json: &str) -> LayoutPatch {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn patches_only_change_the_fields_they_set() {
+        let mut layout = defaults::center_main();
+        layout.patch(patch(
+            r#"{"name": "CenterMain", "columns": {"main": {"size": 0.6}}}"#,
+        ));
+        let mut expected = defaults::center_main();
+        expected.set_main_size(Size::Ratio(0.6));
+        assert_eq!(expected, layout);
+    }
+
+    #[test]
+    fn patches_keep_the_columns_they_leave_alone() {
+        let mut layout = defaults::monocle();
+        layout.patch(patch(
+            r#"{"name": "Monocle", "rotate": "East", "columns": {"stack": {"split": "Grid"}}}"#,
+        ));
+        assert_eq!(Rotation::East, layout.rotate);
+        assert_eq!(Some(Split::Grid), layout.columns.stack.split);
+        // the patch leaves out `main`, so monocle still has none
+        assert_eq!(None, layout.columns.main);
+    }
+
+    #[test]
+    fn patches_set_fields_back_to_their_default() {
+        let mut layout = defaults::center_main_fluid();
+        layout.patch(patch(r#"{"name": "CenterMainFluid", "reserve": "None"}"#));
+        assert_eq!(Reserve::None, layout.reserve);
+
+        let mut layout = defaults::main_and_deck();
+        layout.patch(patch(
+            r#"{"name": "MainAndDeck", "columns": {"stack": {"split": "Horizontal"}}}"#,
+        ));
+        assert_eq!(Some(Split::Horizontal), layout.columns.stack.split);
+    }
+
+    #[test]
+    fn patches_add_and_remove_columns() {
+        let mut layout = defaults::monocle();
+        layout.patch(patch(r#"{"columns": {"main": {"count": 2}}}"#));
+        assert_eq!(
+            Some(Main {
+                count: 2,
+                ..Default::default()
+            }),
+            layout.columns.main
+        );
+        layout.patch(patch(r#"{"columns": {"main": null}}"#));
+        assert_eq!(None, layout.columns.main);
+    }
+}
@@ -0,0 +1,173 @@
+//! Useful, but less obvious combinations of the columns of a [`Layout`].
+//!
+//! Other than the [`crate::layouts::defaults`], the presets are not part of
+//! [`crate::layouts::Layouts::default`]. They are meant to show what the column
+//! model is capable of, and can be used as a starting point for custom layouts.
+//!
+//! *Note: A `main` column without a split (a "deck") only ever displays a single
+//! window, which is why there are no presets with a deck main and more than one
+//! main window.*
+
+use crate::{
+    geometry::{Size, Split},
+    Layout,
+};
+
+use super::layout::{Columns, Main, Stack};
+
+/// Name of the [`grid_main_and_deck_stack`] layout.
+pub const GRID_MAIN_AND_DECK_STACK: &str = "GridMainAndDeckStack";
+/// Name of the [`dual_main_and_dwindle_stack`] layout.
+pub const DUAL_MAIN_AND_DWINDLE_STACK: &str = "DualMainAndDwindleStack";
+/// Name of the [`main_and_accordion_stack`] layout.
+pub const MAIN_AND_ACCORDION_STACK: &str = "MainAndAccordionStack";
+
+/// Names of all the presets, in the same order as they are returned by [`all`].
+pub fn all_names() -> &'static [&'static str] {
+    &[
+        GRID_MAIN_AND_DECK_STACK,
+        DUAL_MAIN_AND_DWINDLE_STACK,
+        MAIN_AND_ACCORDION_STACK,
+    ]
+}
+
+/// All the presets.
+///
+/// ## Example
+/// ```rust
+/// use leftwm_layouts::layouts::{presets, Layouts};
+///
+/// let mut layouts = Layouts::default();
+/// layouts.extend(presets::all());
+/// assert!(layouts.get(presets::GRID_MAIN_AND_DECK_STACK).is_some());
+/// ```
+pub fn all() -> Vec<Layout> {
+    vec![
+        grid_main_and_deck_stack(),
+        dual_main_and_dwindle_stack(),
+        main_and_accordion_stack(),
+    ]
+}
+
+/// Layout with a large main column of up to four windows arranged in a grid,
+/// and a deck stack displaying a single window at a time.
+///
+/// ```txt
+/// +-----+-----+----+
+/// |     |     |    |
+/// +-----+-----+    |
+/// |     |     |    |
+/// +-----+-----+----+
+///    main     stack
+/// ```
+pub fn grid_main_and_deck_stack() -> Layout {
+    Layout {
+        name: GRID_MAIN_AND_DECK_STACK.to_string(),
+        columns: Columns {
+            main: Some(Main {
+                count: 4,
+                size: Size::Ratio(0.75),
+                split: Some(Split::Grid),
+                ..Default::default()
+            }),
+            stack: Stack {
+                split: None,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+/// Layout with two main windows on top of each other,
+/// and a stack arranged in a [`Split::Dwindle`] pattern.
+///
+/// ```txt
+/// +-------+-------+
+/// |       |       |
+/// +-------+---+---+
+/// |       |   |   |
+/// +-------+---+---+
+///   main    stack
+/// ```
+pub fn dual_main_and_dwindle_stack() -> Layout {
+    Layout {
+        name: DUAL_MAIN_AND_DWINDLE_STACK.to_string(),
+        columns: Columns {
+            main: Some(Main {
+                count: 2,
+                split: Some(Split::Horizontal),
+                ..Default::default()
+            }),
+            stack: Stack {
+                split: Some(Split::Dwindle),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+/// Layout with a single main window and an overlapping stack,
+/// folded like an accordion so that the top of every stack window stays visible
+/// (see [`Split::HorizontalAccordion`]).
+///
+/// ```txt
+/// +-------+-------+
+/// |       |-------|
+/// |       |-------|
+/// |       |       |
+/// +-------+-------+
+///   main    stack
+/// ```
+pub fn main_and_accordion_stack() -> Layout {
+    Layout {
+        name: MAIN_AND_ACCORDION_STACK.to_string(),
+        columns: Columns {
+            main: Some(Main::default()),
+            stack: Stack {
+                split: Some(Split::HorizontalAccordion),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        apply,
+        geometry::{check_tiling, Rect},
+        layouts::{
+            defaults,
+            presets::{all, all_names},
+        },
+    };
+
+    #[test]
+    fn presets_have_unique_names_and_match_all_names() {
+        let names: Vec<String> = all().into_iter().map(|layout| layout.name).collect();
+        assert_eq!(all_names(), names);
+        for name in all_names() {
+            assert_eq!(1, names.iter().filter(|n| n == name).count());
+            assert!(!defaults::all_names().contains(name));
+        }
+    }
+
+    #[test]
+    fn presets_satisfy_tiling_invariants() {
+        let container = Rect::new(0, 0, 1920, 1080);
+        for layout in all() {
+            for i in 0usize..12 {
+                let rects = apply(&layout, i, &container);
+                assert!(rects.len() <= i);
+                let issues = check_tiling(&rects, &container, layout.may_overlap());
+                assert!(issues.is_empty(), "{} with {} windows", layout.name, i);
+            }
+        }
+    }
+}
@@ -0,0 +1,430 @@
+use crate::{
+    geometry::{self, Direction, Gaps, Rect, Size},
+    Tiling,
+};
+
+use super::{ColumnKind, Layout};
+
+pub(super) fn resize_edge(
+    layout: &mut Layout,
+    tiling: &Tiling,
+    index: usize,
+    edge: Direction,
+    delta: i32,
+    container: &Rect,
+) -> bool {
+    let (Some(tile), Some(role)) = (tiling.tiles.get(index), tiling.roles.get(index)) else {
+        return false;
+    };
+
+    // the tiles are laid out inside the margin, and the sizes of the columns
+    // are relative to the container enlarged by the gaps, like `apply` does
+    let gaps = layout.gaps.unwrap_or_default();
+    let columns_container = gaps.grow_columns(&crate::tiling_container(layout, container));
+    let container_length = match edge {
+        Direction::East | Direction::West => columns_container.w,
+        Direction::North | Direction::South => columns_container.h,
+    };
+    if !layout.columns.list.is_empty() {
+        let columns = crate::list_column_rects(layout, tiling.window_count, container);
+        let container = &layout.margin.unwrap_or_default().shrink(container);
+        let edge = ListEdge {
+            tile,
+            edge,
+            container,
+            container_length,
+            gaps,
+        };
+        return resize_list_edge(layout, &columns, &edge, delta);
+    }
+    let container = &layout.margin.unwrap_or_default().shrink(container);
+
+    let Some(column) = column_rect(tiling, *role) else {
+        return false;
+    };
+    let Some(position) = draggable_edge(tile, &column, edge, container) else {
+        return false;
+    };
+
+    // the column grows if the dragged edge is its right or bottom edge
+    let far = matches!(edge, Direction::East | Direction::South);
+    let (kind, column, growth) = match role {
+        // the stack takes up the space the other columns leave,
+        // so the neighboring column is resized instead
+        ColumnKind::Stack => {
+            let Some((kind, neighbor)) = neighbor_column(tiling, position, edge) else {
+                return false;
+            };
+            (kind, neighbor, if far { -delta } else { delta })
+        }
+        kind => (*kind, column, if far { delta } else { -delta }),
+    };
+    let (_, column_length) = edge_position(&column, edge);
+    let column_length = column_length + gaps.inner + gaps.gutter;
+    let lengths = (column_length, container_length);
+    resize_column(layout, kind, lengths, growth, tiling.window_count)
+}
+
+/// The dragged edge of a tile of a layout built from [`super::Columns::list`].
+struct ListEdge<'a> {
+    tile: &'a Rect,
+    edge: Direction,
+    /// The container inside the margin.
+    container: &'a Rect,
+    /// The length of the container the sizes of the columns are relative to.
+    container_length: u32,
+    gaps: Gaps,
+}
+
+/// Same as [`resize_edge`], but for a layout built from [`super::Columns::list`],
+/// whose columns are told apart by their position instead of their role.
+fn resize_list_edge(
+    layout: &mut Layout,
+    columns: &[Option<Rect>],
+    drag: &ListEdge<'_>,
+    delta: i32,
+) -> bool {
+    let edge = drag.edge;
+    let columns = columns
+        .iter()
+        .enumerate()
+        .filter_map(|(index, column)| column.map(|column| (index, column)));
+    let Some((index, column)) = columns
+        .clone()
+        .find(|(_, column)| contains(column, drag.tile))
+    else {
+        return false;
+    };
+    let Some(position) = draggable_edge(drag.tile, &column, edge, drag.container) else {
+        return false;
+    };
+    let Some((neighbor_index, neighbor)) = nearest_beyond(columns, position, edge) else {
+        return false;
+    };
+
+    // the columns without a size take up the space the others leave, so a column
+    // with a size is resized, preferably the one of the dragged tile
+    let list = &mut layout.columns.list;
+    let far = matches!(edge, Direction::East | Direction::South);
+    let (index, column, growth) =
+        if list[index].size.is_some() || list[neighbor_index].size.is_none() {
+            (index, column, if far { delta } else { -delta })
+        } else {
+            (neighbor_index, neighbor, if far { -delta } else { delta })
+        };
+    let (_, column_length) = edge_position(&column, edge);
+    let column_length = column_length + drag.gaps.inner + drag.gaps.gutter;
+    // without a size, the column starts from the share it currently has
+    let current = list[index].size.unwrap_or_else(|| {
+        Size::Ratio(geometry::ratio_of(
+            i64::from(column_length),
+            drag.container_length,
+        ))
+    });
+    let size = Some(grow(current, growth, drag.container_length));
+    let changed = size != list[index].size;
+    list[index].size = size;
+    changed
+}
+
+/// The position of the provided edge of the tile, if it can be dragged. Only edges
+/// between columns can be dragged, the edges between the tiles of a column
+/// and the edges of the container can't.
+fn draggable_edge(tile: &Rect, column: &Rect, edge: Direction, container: &Rect) -> Option<i32> {
+    let (position, _) = edge_position(column, edge);
+    let (start, _) = edge_position(container, opposite(edge));
+    let (end, _) = edge_position(container, edge);
+    (edge_position(tile, edge).0 == position && position != start && position != end)
+        .then_some(position)
+}
+
+/// Whether the `inner` [`Rect`] lies within the `outer` one.
+fn contains(outer: &Rect, inner: &Rect) -> bool {
+    inner.left_edge() >= outer.left_edge()
+        && inner.right_edge() <= outer.right_edge()
+        && inner.top_edge() >= outer.top_edge()
+        && inner.bottom_edge() <= outer.bottom_edge()
+}
+
+/// The smallest [`Rect`] containing all tiles of the provided column.
+fn column_rect(tiling: &Tiling, kind: ColumnKind) -> Option<Rect> {
+    tiling
+        .tiles
+        .iter()
+        .zip(&tiling.roles)
+        .filter(|(_, role)| **role == kind)
+        .map(|(tile, _)| *tile)
+        .reduce(|a, b| {
+            let x = a.x.min(b.x);
+            let y = a.y.min(b.y);
+            let right = a.right_edge().max(b.right_edge());
+            let bottom = a.bottom_edge().max(b.bottom_edge());
+            Rect::new(x, y, (right - x) as u32, (bottom - y) as u32)
+        })
+}
+
+/// The position of the provided edge of the [`Rect`] and the length of the [`Rect`]
+/// perpendicular to it.
+fn edge_position(rect: &Rect, edge: Direction) -> (i32, u32) {
+    match edge {
+        Direction::North => (rect.top_edge(), rect.h),
+        Direction::East => (rect.right_edge(), rect.w),
+        Direction::South => (rect.bottom_edge(), rect.h),
+        Direction::West => (rect.left_edge(), rect.w),
+    }
+}
+
+fn opposite(edge: Direction) -> Direction {
+    match edge {
+        Direction::North => Direction::South,
+        Direction::East => Direction::West,
+        Direction::South => Direction::North,
+        Direction::West => Direction::East,
+    }
+}
+
+/// The nearest column other than the stack beyond the provided edge at the provided position.
+fn neighbor_column(tiling: &Tiling, position: i32, edge: Direction) -> Option<(ColumnKind, Rect)> {
+    let columns = [
+        ColumnKind::Main,
+        ColumnKind::SecondMain,
+        ColumnKind::SecondStack,
+    ]
+    .into_iter()
+    .filter_map(|kind| column_rect(tiling, kind).map(|rect| (kind, rect)));
+    nearest_beyond(columns, position, edge)
+}
+
+/// The nearest of the provided columns beyond the provided edge at the provided position.
+fn nearest_beyond<T>(
+    columns: impl Iterator<Item = (T, Rect)>,
+    position: i32,
+    edge: Direction,
+) -> Option<(T, Rect)> {
+    let far = matches!(edge, Direction::East | Direction::South);
+    columns
+        .filter_map(|(column, rect)| {
+            let (near_edge, _) = edge_position(&rect, opposite(edge));
+            let distance = if far {
+                near_edge - position
+            } else {
+                position - near_edge
+            };
+            (distance >= 0).then_some((distance, column, rect))
+        })
+        .min_by_key(|(distance, _, _)| *distance)
+        .map(|(_, column, rect)| (column, rect))
+}
+
+/// Resize the column of the provided kind, whose length and the length of the
+/// container it is relative to are the provided `lengths`.
+fn resize_column(
+    layout: &mut Layout,
+    kind: ColumnKind,
+    (column_length, container_length): (u32, u32),
+    growth: i32,
+    window_count: usize,
+) -> bool {
+    let stable_main = layout.stable_main;
+    let columns = &mut layout.columns;
+    match kind {
+        ColumnKind::Main | ColumnKind::SecondMain => {
+            let main = match kind {
+                ColumnKind::Main => columns.main.as_mut(),
+                _ => columns.second_main.as_mut(),
+            };
+            let Some(main) = main else {
+                return false;
+            };
+            // the size in use for the current amount of windows is resized,
+            // which is the entry of the size curve if one applies
+            let current = if stable_main {
+                main.size
+            } else {
+                main.size_for(window_count)
+            };
+            let grown =
+                main.bounded_size_in(grow(current, growth, container_length), container_length);
+            let size = if stable_main {
+                &mut main.size
+            } else {
+                main.size_curve
+                    .iter_mut()
+                    .filter(|(breakpoint, _)| *breakpoint <= window_count)
+                    .max_by_key(|(breakpoint, _)| *breakpoint)
+                    .map_or(&mut main.size, |(_, size)| size)
+            };
+            *size = grown;
+            grown != current
+        }
+        ColumnKind::SecondStack => {
+            let Some(second_stack) = columns.second_stack.as_mut() else {
+                return false;
+            };
+            // without a size, the second stack starts from the share it currently has
+            let current = second_stack.size.unwrap_or_else(|| {
+                Size::Ratio(geometry::ratio_of(
+                    i64::from(column_length),
+                    container_length,
+                ))
+            });
+            let size = Some(grow(current, growth, container_length));
+            let changed = size != second_stack.size;
+            second_stack.size = size;
+            changed
+        }
+        ColumnKind::Stack => false,
+    }
+}
+
+/// Grow the provided [`Size`] by the provided amount of pixels in a container
+/// of the provided length, but not below zero or beyond the container.
+fn grow(size: Size, pixels: i32, container_length: u32) -> Size {
+    match size {
+        Size::Pixel(px) => Size::Pixel((px + pixels).clamp(0, container_length as i32)),
+        Size::Ratio(ratio) => Size::Ratio(geometry::grow_ratio(
+            ratio,
+            i64::from(pixels),
+            container_length,
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        apply_detailed,
+        geometry::{Direction, Gaps, Margin, Orientation, Rect, Rotation, Size},
+        layouts::{defaults, ColumnKind},
+    };
+
+    const CONTAINER: Rect = Rect::new(0, 0, 400, 200);
+
+    #[test]
+    fn dragging_the_edge_between_main_and_stack_resizes_main() {
+        let mut layout = defaults::main_and_vert_stack();
+        let tiling = apply_detailed(&layout, 3, &CONTAINER);
+        assert!(layout.resize_edge(&tiling, 0, Direction::East, 40, &CONTAINER));
+        assert_eq!(Some(Size::Ratio(0.6)), layout.main_size());
+        // the same edge, dragged from the stack
+        assert!(layout.resize_edge(&tiling, 1, Direction::West, -80, &CONTAINER));
+        assert_eq!(Some(Size::Ratio(0.4)), layout.main_size());
+    }
+
+    #[test]
+    fn edges_are_dragged_inside_the_margin_and_the_gaps() {
+        let mut layout = defaults::main_and_vert_stack();
+        layout.margin = Some(Margin::uniform(50));
+        layout.gaps = Some(Gaps {
+            inner: 10,
+            ..Default::default()
+        });
+        let container = Rect::new(0, 0, 500, 300);
+        let tiling = apply_detailed(&layout, 3, &container);
+        assert_eq!(Rect::new(50, 50, 195, 200), tiling.tiles[0]);
+        assert!(layout.resize_edge(&tiling, 0, Direction::East, 41, &container));
+        assert_eq!(Some(Size::Ratio(0.6)), layout.main_size());
+        // the edges of the container inside the margin are still not draggable
+        let tiling = apply_detailed(&layout, 3, &container);
+        assert!(!layout.resize_edge(&tiling, 0, Direction::West, 20, &container));
+        assert!(!layout.resize_edge(&tiling, 1, Direction::East, 20, &container));
+    }
+
+    #[test]
+    fn edges_of_a_column_list_resize_the_columns() {
+        let mut layout = defaults::main_and_dual_stack();
+        let tiling = apply_detailed(&layout, 4, &CONTAINER);
+        assert!(layout.resize_edge(&tiling, 0, Direction::East, 40, &CONTAINER));
+        assert_eq!(Some(Size::Ratio(0.6)), layout.columns.list[0].size);
+        // the same edge, dragged from the stack without a size
+        let tiling = apply_detailed(&layout, 4, &CONTAINER);
+        assert!(layout.resize_edge(&tiling, 1, Direction::West, 40, &CONTAINER));
+        assert_eq!(Some(Size::Ratio(0.7)), layout.columns.list[0].size);
+
+        // between the stacks, the stack of the dragged tile gets a size
+        let tiling = apply_detailed(&layout, 4, &CONTAINER);
+        assert_eq!(Rect::new(340, 0, 60, 200), tiling.tiles[3]);
+        assert!(layout.resize_edge(&tiling, 3, Direction::West, 20, &CONTAINER));
+        assert_eq!(Some(Size::Ratio(0.1)), layout.columns.list[2].size);
+        assert_eq!(
+            Rect::new(280, 0, 80, 100),
+            apply_detailed(&layout, 4, &CONTAINER).tiles[1]
+        );
+    }
+
+    #[test]
+    fn dragging_main_resizes_the_size_in_use_of_the_curve() {
+        let mut layout = defaults::main_and_vert_stack();
+        let main = layout.columns.main.as_mut().unwrap();
+        main.size_curve = vec![(3, Size::Ratio(0.7))];
+
+        let tiling = apply_detailed(&layout, 2, &CONTAINER);
+        assert!(layout.resize_edge(&tiling, 0, Direction::East, 40, &CONTAINER));
+        let main = layout.columns.main.as_ref().unwrap();
+        assert_eq!(Size::Ratio(0.6), main.size);
+        assert_eq!(vec![(3, Size::Ratio(0.7))], main.size_curve);
+
+        let tiling = apply_detailed(&layout, 3, &CONTAINER);
+        assert!(layout.resize_edge(&tiling, 0, Direction::East, -40, &CONTAINER));
+        let main = layout.columns.main.as_ref().unwrap();
+        assert_eq!(Size::Ratio(0.6), main.size);
+        assert_eq!(vec![(3, Size::Ratio(0.6))], main.size_curve);
+        assert_eq!(
+            Rect::new(0, 0, 240, 200),
+            apply_detailed(&layout, 3, &CONTAINER).tiles[0]
+        );
+    }
+
+    #[test]
+    fn edges_inside_columns_and_of_the_container_are_not_draggable() {
+        let mut layout = defaults::main_and_vert_stack();
+        let before = layout.clone();
+        let tiling = apply_detailed(&layout, 3, &CONTAINER);
+        assert!(!layout.resize_edge(&tiling, 1, Direction::South, 20, &CONTAINER));
+        assert!(!layout.resize_edge(&tiling, 0, Direction::West, 20, &CONTAINER));
+        assert!(!layout.resize_edge(&tiling, 1, Direction::East, 20, &CONTAINER));
+        assert!(!layout.resize_edge(&tiling, 3, Direction::East, 20, &CONTAINER));
+        assert_eq!(before, layout);
+    }
+
+    #[test]
+    fn dragging_rotated_layouts_resizes_the_rotated_columns() {
+        let mut layout = defaults::main_and_vert_stack();
+        layout.rotate = Rotation::South;
+        let tiling = apply_detailed(&layout, 2, &CONTAINER);
+        assert_eq!(Rect::new(200, 0, 200, 200), tiling.tiles[0]);
+        assert!(layout.resize_edge(&tiling, 0, Direction::West, -40, &CONTAINER));
+        assert_eq!(Some(Size::Ratio(0.6)), layout.main_size());
+
+        let mut layout = defaults::main_and_vert_stack();
+        layout.columns.orientation = Orientation::Vertical;
+        let tiling = apply_detailed(&layout, 2, &CONTAINER);
+        assert_eq!(Rect::new(0, 0, 400, 100), tiling.tiles[0]);
+        assert!(layout.resize_edge(&tiling, 1, Direction::North, 20, &CONTAINER));
+        assert_eq!(Some(Size::Ratio(0.6)), layout.main_size());
+    }
+
+    #[test]
+    fn dragging_the_second_stack_gives_it_a_size() {
+        let mut layout = defaults::center_main();
+        let tiling = apply_detailed(&layout, 3, &CONTAINER);
+        let index = tiling
+            .roles
+            .iter()
+            .position(|role| *role == ColumnKind::SecondStack)
+            .unwrap();
+        let edge = if tiling.tiles[index].x == 0 {
+            Direction::East
+        } else {
+            Direction::West
+        };
+        let delta = if edge == Direction::East { 40 } else { -40 };
+        assert!(layout.resize_edge(&tiling, index, edge, delta, &CONTAINER));
+        let second_stack = layout.columns.second_stack.unwrap();
+        assert_eq!(Some(Size::Ratio(0.35)), second_stack.size);
+        assert_eq!(
+            Some(Size::Ratio(0.5)),
+            layout.columns.main.map(|main| main.size)
+        );
+    }
+}
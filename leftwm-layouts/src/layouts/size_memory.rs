@@ -0,0 +1,112 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::geometry::{Rect, Size};
+use crate::Layout;
+
+/// Remembers the [`crate::layouts::Main`] size per container width,
+/// so that a layout used on a laptop screen and on an external monitor
+/// can keep an appropriate main size for each resolution.
+///
+/// The memory can be serialized to persist it along with the state of the window manager.
+///
+/// ## Example
+/// ```rust
+/// use leftwm_layouts::Layout;
+/// use leftwm_layouts::geometry::{Rect, Size};
+/// use leftwm_layouts::layouts::SizeMemory;
+///
+/// let laptop = Rect::new(0, 0, 1366, 768);
+/// let monitor = Rect::new(0, 0, 3440, 1440);
+/// let mut memory = SizeMemory::default();
+/// let mut layout = Layout::default();
+///
+/// layout.set_main_size(Size::Ratio(0.6));
+/// memory.save(&layout, &laptop);
+/// layout.set_main_size(Size::Ratio(0.3));
+/// memory.save(&layout, &monitor);
+///
+/// assert!(memory.restore(&mut layout, &laptop));
+/// assert_eq!(Some(Size::Ratio(0.6)), layout.main_size());
+/// ```
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct SizeMemory {
+    sizes: BTreeMap<u32, Size>,
+}
+
+impl SizeMemory {
+    /// Remember the provided main [`Size`] for containers of the provided width.
+    pub fn remember(&mut self, container_width: u32, size: Size) {
+        self.sizes.insert(container_width, size);
+    }
+
+    /// The main [`Size`] remembered for containers of the provided width, if any.
+    pub fn recall(&self, container_width: u32) -> Option<Size> {
+        self.sizes.get(&container_width).copied()
+    }
+
+    /// Forget the main [`Size`] remembered for containers of the provided width,
+    /// returns the [`Size`] if there was one.
+    pub fn forget(&mut self, container_width: u32) -> Option<Size> {
+        self.sizes.remove(&container_width)
+    }
+
+    /// Remember the current main size of the provided [`Layout`] for the width of the container.
+    /// Nothing is remembered if the layout has no main column.
+    pub fn save(&mut self, layout: &Layout, container: &Rect) {
+        if let Some(size) = layout.main_size() {
+            self.remember(container.w, size);
+        }
+    }
+
+    /// Set the main size of the provided [`Layout`] to the size remembered for the width
+    /// of the container. Returns `false` and leaves the layout unchanged
+    /// if there is no remembered size or the layout has no main column.
+    pub fn restore(&self, layout: &mut Layout, container: &Rect) -> bool {
+        match (self.recall(container.w), layout.main_size()) {
+            (Some(size), Some(_)) => {
+                layout.set_main_size(size);
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SizeMemory;
+    use crate::geometry::{Rect, Size};
+    use crate::layouts::Layouts;
+    use crate::Layout;
+
+    #[test]
+    fn restore_without_remembered_size_keeps_the_layout() {
+        let memory = SizeMemory::default();
+        let mut layout = Layout::default();
+        let before = layout.clone();
+        assert!(!memory.restore(&mut layout, &Rect::new(0, 0, 1920, 1080)));
+        assert_eq!(before, layout);
+    }
+
+    #[test]
+    fn layout_without_main_is_not_remembered() {
+        let monocle = Layouts::default().get("Monocle").unwrap().clone();
+        let mut memory = SizeMemory::default();
+        memory.save(&monocle, &Rect::new(0, 0, 1920, 1080));
+        assert_eq!(None, memory.recall(1920));
+    }
+
+    #[test]
+    fn memory_survives_a_serde_round_trip() {
+        let mut memory = SizeMemory::default();
+        memory.remember(1366, Size::Ratio(0.6));
+        memory.remember(3440, Size::Pixel(1200));
+        let json = serde_json::to_string(&memory).unwrap();
+        assert_eq!(r#"{"sizes":{"1366":0.6,"3440":1200}}"#, json);
+        assert_eq!(memory, serde_json::from_str(&json).unwrap());
+        assert_eq!(Some(Size::Pixel(1200)), memory.forget(3440));
+        assert_eq!(None, memory.recall(3440));
+    }
+}
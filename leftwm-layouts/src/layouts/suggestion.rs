@@ -0,0 +1,115 @@
+use serde::{Deserialize, Serialize};
+
+use crate::geometry::{self, Rect};
+
+use super::defaults;
+
+/// Which layout [`super::Layouts::suggest_for`] picks for a container,
+/// depending on its aspect ratio (width divided by height).
+///
+/// ```
+/// use leftwm_layouts::{geometry::Rect, layouts::Layouts};
+///
+/// let mut layouts = Layouts::default();
+/// assert_eq!("CenterMain", layouts.suggest_for(&Rect::new(0, 0, 3440, 1440)).unwrap().name);
+/// assert_eq!("MainAndVertStack", layouts.suggest_for(&Rect::new(0, 0, 1920, 1080)).unwrap().name);
+/// assert_eq!("EvenVertical", layouts.suggest_for(&Rect::new(0, 0, 1080, 1920)).unwrap().name);
+///
+/// layouts.suggestions.ultrawide_ratio = 3.0;
+/// assert_eq!("MainAndVertStack", layouts.suggest_for(&Rect::new(0, 0, 3440, 1440)).unwrap().name);
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct LayoutSuggestions {
+    /// Containers with at least this aspect ratio are ultrawide (default: `2.0`).
+    pub ultrawide_ratio: f32,
+
+    /// Containers with an aspect ratio below this one are portrait (default: `1.0`).
+    pub portrait_ratio: f32,
+
+    /// The name of the layout for ultrawide containers (default: `CenterMain`).
+    pub ultrawide: String,
+
+    /// The name of the layout for the containers that are neither ultrawide
+    /// nor portrait (default: `MainAndVertStack`).
+    pub landscape: String,
+
+    /// The name of the layout for portrait containers (default: `EvenVertical`).
+    pub portrait: String,
+}
+
+impl LayoutSuggestions {
+    /// The name of the layout suggested for the provided container.
+    pub fn name_for(&self, container: &Rect) -> &str {
+        // compare the width with the height scaled by the ratios, to avoid dividing
+        let width = u64::from(container.w);
+        let height = container.h.max(1);
+        if width >= geometry::scale(height, self.ultrawide_ratio) {
+            &self.ultrawide
+        } else if width < geometry::scale(height, self.portrait_ratio) {
+            &self.portrait
+        } else {
+            &self.landscape
+        }
+    }
+
+    pub(super) fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl Default for LayoutSuggestions {
+    fn default() -> Self {
+        Self {
+            ultrawide_ratio: 2.0,
+            portrait_ratio: 1.0,
+            ultrawide: defaults::CENTER_MAIN.to_string(),
+            landscape: defaults::MAIN_AND_VERT_STACK.to_string(),
+            portrait: defaults::EVEN_VERTICAL.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        geometry::Rect,
+        layouts::{defaults, Layouts},
+    };
+
+    #[test]
+    fn suggest_for_falls_back_to_the_first_layout() {
+        let layouts = Layouts::new(vec![defaults::monocle(), defaults::grid()]);
+        let ultrawide = Rect::new(0, 0, 5120, 1440);
+        assert_eq!(
+            defaults::MONOCLE,
+            layouts.suggest_for(&ultrawide).unwrap().name
+        );
+        // a container without height is not portrait
+        let flat = Rect::new(0, 0, 1920, 0);
+        assert_eq!(defaults::MONOCLE, layouts.suggest_for(&flat).unwrap().name);
+    }
+
+    #[test]
+    fn nothing_is_suggested_without_layouts() {
+        let layouts = Layouts::new(vec![]);
+        assert_eq!(None, layouts.suggest_for(&Rect::new(0, 0, 1920, 1080)));
+    }
+
+    #[test]
+    fn suggestions_survive_a_serde_round_trip() {
+        let mut layouts = Layouts::default();
+        layouts.suggestions.portrait = defaults::EVEN_HORIZONTAL.to_string();
+        let json = serde_json::to_string(&layouts).unwrap();
+        let deserialized: Layouts = serde_json::from_str(&json).unwrap();
+        assert_eq!(layouts, deserialized);
+        let portrait = Rect::new(0, 0, 1080, 1920);
+        assert_eq!(
+            defaults::EVEN_HORIZONTAL,
+            deserialized.suggest_for(&portrait).unwrap().name
+        );
+
+        let json = serde_json::to_string(&Layouts::default()).unwrap();
+        assert!(!json.contains("suggestions"));
+    }
+}
@@ -0,0 +1,321 @@
+use std::{cmp, fmt};
+
+use serde::{Deserialize, Serialize};
+
+use crate::geometry::Size;
+
+use super::{ColumnKind, Layout, Layouts, Main};
+
+/// An invalid configuration of a [`Layout`] or of a set of [`Layouts`],
+/// see [`Layout::validate`] and [`Layouts::validate`].
+///
+/// Other than a [`super::LintWarning`], parts of an invalid configuration
+/// are ignored or lead to results that can't be what was intended.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum LayoutIssue {
+    /// A `second_stack` is configured, but there is no `main` column
+    /// it could be next to, so it is ignored.
+    SecondStackWithoutMain,
+
+    /// A ratio size of a column is not between `0.0` and `1.0`.
+    RatioOutOfRange(ColumnKind, f32),
+
+    /// The `main` column can't hold any windows,
+    /// but its space is reserved and remains empty.
+    ZeroMainCountWithReservedSpace,
+
+    /// The `main` column holds more than one window, but has no split,
+    /// so only the first of them is displayed.
+    MainWithoutSplit,
+
+    /// The `main` column holds more windows than its `max_count` allows.
+    MainCountAboveMax(usize, usize),
+
+    /// Another layout of the set has the same name, so only the first
+    /// one of them can be found by its name.
+    DuplicateName(String),
+
+    /// A [`super::LayoutSet`] references a layout that isn't part of the set of layouts,
+    /// with the name of the layout set and of the missing layout.
+    UnknownLayoutInSet(String, String),
+}
+
+impl fmt::Display for LayoutIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LayoutIssue::SecondStackWithoutMain => {
+                write!(f, "the second stack is ignored without a main column")
+            }
+            LayoutIssue::RatioOutOfRange(kind, ratio) => write!(
+                f,
+                "the size {ratio} of the {kind:?} column is not between 0.0 and 1.0"
+            ),
+            LayoutIssue::ZeroMainCountWithReservedSpace => write!(
+                f,
+                "the main column holds no windows, but its space is reserved"
+            ),
+            LayoutIssue::MainWithoutSplit => write!(
+                f,
+                "the main column holds multiple windows, but only displays one"
+            ),
+            LayoutIssue::MainCountAboveMax(count, max) => write!(
+                f,
+                "the main column holds {count} windows, but at most {max} are allowed"
+            ),
+            LayoutIssue::DuplicateName(name) => {
+                write!(f, "there is another layout named {name}")
+            }
+            LayoutIssue::UnknownLayoutInSet(set, name) => {
+                write!(
+                    f,
+                    "the layout set {set} references the unknown layout {name}"
+                )
+            }
+        }
+    }
+}
+
+pub(super) fn validate(layout: &Layout) -> Vec<LayoutIssue> {
+    let mut issues = vec![];
+    let columns = &layout.columns;
+
+    if columns.second_stack.is_some() && columns.main.is_none() {
+        issues.push(LayoutIssue::SecondStackWithoutMain);
+    }
+
+    let main_sizes = [&columns.main, &columns.second_main]
+        .into_iter()
+        .zip([ColumnKind::Main, ColumnKind::SecondMain])
+        .filter_map(|(main, kind)| main.as_ref().map(|main| (main, kind)))
+        .flat_map(|(main, kind)| {
+            let curve = main.size_curve.iter().map(|(_, size)| Some(*size));
+            [Some(main.size), main.max_size]
+                .into_iter()
+                .chain(curve)
+                .map(move |size| (kind, size))
+        });
+    let second_stack_size = columns
+        .second_stack
+        .as_ref()
+        .map(|second_stack| (ColumnKind::SecondStack, second_stack.size));
+    let list_sizes = columns.list.iter().map(|column| (column.kind, column.size));
+    for (kind, size) in main_sizes.chain(second_stack_size).chain(list_sizes) {
+        if let Some(Size::Ratio(ratio)) = size {
+            if !(0.0..=1.0).contains(&ratio) {
+                issues.push(LayoutIssue::RatioOutOfRange(kind, ratio));
+            }
+        }
+    }
+
+    if let Some(main) = &columns.main {
+        if main.count == 0 && layout.reserve.is_reserved() {
+            issues.push(LayoutIssue::ZeroMainCountWithReservedSpace);
+        }
+        if main.count > 1 && main.split.is_none() {
+            issues.push(LayoutIssue::MainWithoutSplit);
+        }
+        if let Some(max) = main.max_count.filter(|max| main.count > *max) {
+            issues.push(LayoutIssue::MainCountAboveMax(main.count, max));
+        }
+    }
+
+    issues
+}
+
+pub(super) fn normalize(layout: &mut Layout) -> Vec<LayoutIssue> {
+    let issues = validate(layout);
+    let columns = &mut layout.columns;
+    for issue in &issues {
+        match issue {
+            LayoutIssue::SecondStackWithoutMain => columns.second_stack = None,
+            LayoutIssue::RatioOutOfRange(..) => {
+                let mains = columns
+                    .main
+                    .iter_mut()
+                    .chain(columns.second_main.iter_mut());
+                for main in mains {
+                    clamp_ratio(&mut main.size);
+                    main.max_size.iter_mut().for_each(clamp_ratio);
+                    main.size_curve
+                        .iter_mut()
+                        .for_each(|(_, size)| clamp_ratio(size));
+                }
+                let second_stack = columns.second_stack.iter_mut().flat_map(|s| &mut s.size);
+                let list = columns.list.iter_mut().flat_map(|column| &mut column.size);
+                second_stack.chain(list).for_each(clamp_ratio);
+            }
+            LayoutIssue::ZeroMainCountWithReservedSpace | LayoutIssue::MainWithoutSplit => {
+                if let Some(main) = columns.main.as_mut() {
+                    let default = Main::default();
+                    main.count = cmp::max(main.count, default.count);
+                    if main.count > 1 {
+                        main.split = main.split.or(default.split);
+                    }
+                }
+            }
+            LayoutIssue::MainCountAboveMax(_, max) => {
+                if let Some(main) = columns.main.as_mut() {
+                    main.count = *max;
+                }
+            }
+            LayoutIssue::DuplicateName(_) | LayoutIssue::UnknownLayoutInSet(..) => {}
+        }
+    }
+    issues
+}
+
+/// Clamp a ratio between `0.0` and `1.0`, `NaN` becomes `0.0`.
+fn clamp_ratio(size: &mut Size) {
+    if let Size::Ratio(ratio) = size {
+        *ratio = if ratio.is_nan() {
+            0.0
+        } else {
+            ratio.clamp(0.0, 1.0)
+        };
+    }
+}
+
+pub(super) fn validate_set(layouts: &Layouts) -> Vec<(usize, LayoutIssue)> {
+    let mut issues = vec![];
+    for (index, layout) in layouts.layouts().iter().enumerate() {
+        issues.extend(validate(layout).into_iter().map(|issue| (index, issue)));
+        if layouts.layouts()[..index]
+            .iter()
+            .any(|other| other.name == layout.name)
+        {
+            issues.push((index, LayoutIssue::DuplicateName(layout.name.clone())));
+        }
+    }
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        geometry::{Reserve, Size},
+        layouts::{presets, ColumnKind, Columns, Layouts, Main, SecondStack},
+        Layout,
+    };
+
+    use super::LayoutIssue;
+
+    #[test]
+    fn default_layouts_and_presets_are_valid() {
+        assert!(Layouts::default().validate().is_empty());
+        for layout in presets::all() {
+            assert!(layout.validate().is_empty(), "{}", layout.name);
+        }
+    }
+
+    #[test]
+    fn second_stack_without_main_is_invalid() {
+        let mut layout = Layouts::default().get("EvenVertical").unwrap().clone();
+        layout.columns.second_stack = Some(SecondStack::default());
+        assert_eq!(vec![LayoutIssue::SecondStackWithoutMain], layout.validate());
+    }
+
+    #[test]
+    fn ratios_out_of_range_are_invalid() {
+        let mut layout = Layout::default();
+        layout.set_main_size(Size::Ratio(1.5));
+        layout.columns.second_stack = Some(SecondStack {
+            size: Some(Size::Ratio(-0.2)),
+            ..Default::default()
+        });
+        assert_eq!(
+            vec![
+                LayoutIssue::RatioOutOfRange(ColumnKind::Main, 1.5),
+                LayoutIssue::RatioOutOfRange(ColumnKind::SecondStack, -0.2),
+            ],
+            layout.validate()
+        );
+    }
+
+    #[test]
+    fn reserved_main_without_windows_is_invalid() {
+        let layout = Layout {
+            reserve: Reserve::Reserve,
+            columns: Columns {
+                main: Some(Main {
+                    count: 0,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert_eq!(
+            vec![LayoutIssue::ZeroMainCountWithReservedSpace],
+            layout.validate()
+        );
+    }
+
+    #[test]
+    fn main_count_above_max_count_is_invalid() {
+        let mut layout = Layout::default();
+        let main = layout.columns.main.as_mut().unwrap();
+        main.count = 3;
+        main.max_count = Some(2);
+        assert_eq!(
+            vec![LayoutIssue::MainCountAboveMax(3, 2)],
+            layout.normalize()
+        );
+        assert_eq!(Some(2), layout.main_window_count());
+    }
+
+    #[test]
+    fn duplicate_names_are_invalid() {
+        let mut layouts = Layouts::default();
+        let monocle = layouts.get("Monocle").unwrap().clone();
+        layouts.push(monocle);
+        assert_eq!(
+            vec![(
+                layouts.len() - 1,
+                LayoutIssue::DuplicateName("Monocle".to_string())
+            )],
+            layouts.validate()
+        );
+    }
+
+    #[test]
+    fn normalize_fixes_the_issues_it_reports() {
+        let mut layout = Layouts::default().get("EvenVertical").unwrap().clone();
+        layout.columns.second_stack = Some(SecondStack::default());
+        let mut main = Layout {
+            reserve: Reserve::Reserve,
+            columns: Columns {
+                main: Some(Main {
+                    count: 0,
+                    size: Size::Ratio(f32::NAN),
+                    max_size: Some(Size::Ratio(1.2)),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        for layout in [&mut layout, &mut main] {
+            let issues = layout.validate();
+            assert!(!issues.is_empty());
+            // compared by their debug output, as NaN isn't equal to itself
+            assert_eq!(format!("{issues:?}"), format!("{:?}", layout.normalize()));
+            assert_eq!(Vec::<LayoutIssue>::new(), layout.validate());
+            assert!(layout.normalize().is_empty());
+        }
+        assert_eq!(None, layout.columns.second_stack);
+        let main = main.columns.main.unwrap();
+        assert_eq!(1, main.count);
+        assert_eq!(Size::Ratio(0.0), main.size);
+        assert_eq!(Some(Size::Ratio(1.0)), main.max_size);
+    }
+
+    #[test]
+    fn normalize_adds_a_split_to_main_with_multiple_windows() {
+        let mut layout = Layout::default();
+        let main = layout.columns.main.as_mut().unwrap();
+        main.count = 3;
+        main.split = None;
+        assert_eq!(vec![LayoutIssue::MainWithoutSplit], layout.normalize());
+        assert_eq!(Main::default().split, layout.columns.main.unwrap().split);
+    }
+}
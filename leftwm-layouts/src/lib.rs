@@ -1,28 +1,188 @@
 use std::cmp;
 use std::vec;
 
-use geometry::Rect;
-use geometry::Split;
-use layouts::three_column;
-use layouts::two_column;
+use geometry::{Rect, Size, Split, SplitOptions};
+pub use hints::Hints;
+pub use incremental::IncrementalApply;
+use layouts::column_list;
+use layouts::columns::rebalance_stacks;
+use layouts::dual_main;
+use layouts::three_column_along;
+use layouts::two_column_along;
+use layouts::ColumnKind;
 pub use layouts::Layout;
 use layouts::Main;
 use layouts::SecondStack;
+use layouts::StackFillOrder;
+pub use tiling::Tiling;
 
+pub mod compat;
 pub mod geometry;
+mod hints;
+mod incremental;
+pub mod ipc;
 pub mod layouts;
+#[cfg(test)]
+mod odd_containers;
+pub mod prelude;
+pub mod render;
+mod tiling;
 
+/// Calculate the tiles of the provided amount of windows in the `container`,
+/// arranged as described by the layout `definition`.
+///
+/// The tiles are returned in the same order as the windows. There may be fewer tiles than
+/// windows if the layout can't display all of them (eg. `Monocle`), the windows without a
+/// tile are meant to be hidden.
+///
+/// ## Complexity
+/// The calculation takes linear time in the amount of windows for every built-in [`Split`],
+/// except for [`Split::BinaryTree`], which takes `O(n log n)`. A minimum tile size
+/// (see [`geometry::SplitOptions::min_size`]) adds a factor of `log n`.
+///
+/// [`Split`]: geometry::Split
+/// [`Split::BinaryTree`]: geometry::Split::BinaryTree
 pub fn apply(definition: &Layout, window_count: usize, container: &Rect) -> Vec<Rect> {
+    apply_with_hints(definition, window_count, container, &Hints::default())
+}
+
+/// Same as [`apply`], but with [`Hints`] adjusting the result for this call only.
+///
+/// ## Example
+/// ```rust
+/// use leftwm_layouts::{apply_with_hints, geometry::Rect, layouts::Layouts, Hints};
+///
+/// let layout = Layouts::default().get("CenterMainBalanced").unwrap().clone();
+/// let hints = Hints {
+///     stack_balance: Some(0.75),
+///     ..Default::default()
+/// };
+/// let rects = apply_with_hints(&layout, 3, &Rect::new(0, 0, 400, 200), &hints);
+/// assert_eq!(Rect::new(0, 0, 150, 200), rects[1]);
+/// assert_eq!(Rect::new(350, 0, 50, 200), rects[2]);
+/// ```
+pub fn apply_with_hints(
+    definition: &Layout,
+    window_count: usize,
+    container: &Rect,
+    hints: &Hints,
+) -> Vec<Rect> {
+    let container = &tiling_container(definition, container);
+    let (mut tiles, _) = apply_with_roles(definition, window_count, container, hints);
+    definition.gaps.unwrap_or_default().shrink(&mut tiles);
+    tiling::shave_title_bars(&mut tiles, definition.title_bar_height);
+    tiles
+}
+
+/// Same as [`apply`], but returns a detailed [`Tiling`] which, next to the tiles,
+/// contains the column of each tile, the areas of reserved but empty columns
+/// (see [`geometry::Reserve`]), and the title bars (see [`Layout::title_bar_height`]).
+///
+/// ## Example
+/// ```rust
+/// use leftwm_layouts::{apply_detailed, geometry::{Rect, Reserve}, layouts::Layouts};
+///
+/// let mut layout = Layouts::default().get("MainAndVertStack").unwrap().clone();
+/// layout.reserve = Reserve::Reserve;
+/// let tiling = apply_detailed(&layout, 1, &Rect::new(0, 0, 400, 200));
+/// assert_eq!(vec![Rect::new(0, 0, 200, 200)], tiling.tiles);
+/// assert_eq!(vec![Rect::new(200, 0, 200, 200)], tiling.reserved);
+/// ```
+pub fn apply_detailed(definition: &Layout, window_count: usize, container: &Rect) -> Tiling {
+    let container = &tiling_container(definition, container);
+    let (tiles, roles) = apply_with_roles(definition, window_count, container, &Hints::default());
+    Tiling::new(tiles, roles, window_count, container, definition)
+}
+
+/// Calculate only the [`Rect`] of the `main` column, as it would be arranged by [`apply`].
+///
+/// This skips splitting the columns into tiles, for when only the position of the `main`
+/// column is of interest (eg. to center a dialog on it). Returns [`None`] if the layout
+/// has no `main` column, or if there is no window in it.
+///
+/// ## Example
+/// ```rust
+/// use leftwm_layouts::{geometry::Rect, layouts::Layouts, main_rect};
+///
+/// let layout = Layouts::default().get("CenterMain").unwrap().clone();
+/// let container = Rect::new(0, 0, 400, 200);
+/// assert_eq!(Some(Rect::new(100, 0, 200, 200)), main_rect(&layout, 30, &container));
+/// assert_eq!(None, main_rect(&layout, 0, &container));
+/// ```
+pub fn main_rect(definition: &Layout, window_count: usize, container: &Rect) -> Option<Rect> {
+    if window_count == 0 {
+        return None;
+    }
+
+    let definition = &*definition.with_counts_for(window_count);
+    let container = &tiling_container(definition, container);
+    let columns = &definition.columns;
+    let second_stack = columns.effective_second_stack();
+    let main_column = match (&columns.main, &second_stack, &columns.second_main) {
+        _ if !columns.list.is_empty() => {
+            let (rects, _) = list_columns(container, window_count, definition);
+            columns
+                .list
+                .iter()
+                .zip(rects)
+                .filter(|(column, _)| column.kind == ColumnKind::Main)
+                .find_map(|(_, rect)| rect)
+        }
+        (None, _, _) => None,
+        (Some(main), _, Some(second_main)) => {
+            main_stack_main_columns(container, window_count, definition, main, second_main)[0]
+        }
+        (Some(main), None, None) => main_stack_columns(container, window_count, definition, main).0,
+        (Some(main), Some(_), None) => {
+            let hints = Hints::default();
+            stack_main_stack_columns(container, window_count, definition, main, &hints).1
+        }
+    };
+
+    // flip and rotate the whole layout, like apply does
+    let mut rects = [main_column?];
+    geometry::flip(&mut rects, definition.flip, container);
+    geometry::rotate(&mut rects, definition.rotate, container);
+    definition.gaps.unwrap_or_default().shrink(&mut rects);
+    Some(rects[0])
+}
+
+/// The container the tiles are calculated in, which is the provided container inside
+/// the [`Layout::margin`], enlarged by the [`Layout::gaps`] (see [`geometry::Gaps`]).
+pub(crate) fn tiling_container(definition: &Layout, container: &Rect) -> Rect {
+    let container = definition.margin.unwrap_or_default().shrink(container);
+    definition.gaps.unwrap_or_default().grow(&container)
+}
+
+fn apply_with_roles(
+    definition: &Layout,
+    window_count: usize,
+    container: &Rect,
+    hints: &Hints,
+) -> (Vec<Rect>, Vec<ColumnKind>) {
+    let window_count = hints.capped(window_count);
     if window_count == 0 {
-        return vec![];
+        return (vec![], vec![]);
     }
 
-    let mut rects = match (&definition.columns.main, &definition.columns.second_stack) {
-        (None, _) => stack(container, window_count, definition.columns.stack.split),
-        (Some(main), None) => main_stack(container, window_count, definition, main),
-        (Some(main), Some(alternate_stack)) => {
-            stack_main_stack(container, window_count, definition, main, alternate_stack)
+    let definition = &*definition.with_counts_for(window_count);
+    let columns = &definition.columns;
+    let second_stack = columns.effective_second_stack();
+    let (mut rects, roles) = match (&columns.main, &second_stack, &columns.second_main) {
+        _ if !columns.list.is_empty() => list(container, window_count, definition),
+        (None, _, _) => stack(container, window_count, definition),
+        (Some(main), _, Some(second_main)) => {
+            main_stack_main(container, window_count, definition, main, second_main)
         }
+        (Some(main), None, None) => main_stack(container, window_count, definition, main),
+        (Some(main), Some(alternate_stack), None) => stack_main_stack(
+            container,
+            window_count,
+            definition,
+            main,
+            alternate_stack,
+            hints,
+        ),
     };
 
     // flip the whole layout
@@ -31,11 +191,37 @@ pub fn apply(definition: &Layout, window_count: usize, container: &Rect) -> Vec<
     // rotate the whole layout
     geometry::rotate(&mut rects, definition.rotate, container);
 
-    rects
+    (rects, roles)
+}
+
+fn stack(
+    container: &Rect,
+    window_count: usize,
+    definition: &Layout,
+) -> (Vec<Rect>, Vec<ColumnKind>) {
+    let stack = &definition.columns.stack;
+    let options = stack.split_options(definition.remainder);
+    let mut tiles = split_column(container, window_count, stack.split, &options);
+    if stack.reverse {
+        tiles.reverse();
+    }
+    let roles = vec![ColumnKind::Stack; tiles.len()];
+    (tiles, roles)
 }
 
-fn stack(container: &Rect, window_count: usize, split: Option<Split>) -> Vec<Rect> {
-    geometry::split(container, window_count, split)
+/// Split a column into a tile for each window, the windows that don't fit because
+/// of the [`geometry::SplitOptions::min_size`] share the last tile of the column.
+fn split_column(
+    rect: &Rect,
+    window_count: usize,
+    split: Option<Split>,
+    options: &SplitOptions,
+) -> Vec<Rect> {
+    let (mut tiles, merged) = geometry::split_with_merged(rect, window_count, split, options);
+    if let Some(last) = tiles.last().copied() {
+        tiles.resize(tiles.len() + merged, last);
+    }
+    tiles
 }
 
 fn main_stack(
@@ -43,12 +229,56 @@ fn main_stack(
     window_count: usize,
     definition: &Layout,
     main: &Main,
-) -> Vec<Rect> {
-    let (mut main_tile, mut stack_tile) = two_column(
-        window_count,
+) -> (Vec<Rect>, Vec<ColumnKind>) {
+    let (main_tile, stack_tile) = main_stack_columns(container, window_count, definition, main);
+
+    let mut main_tiles = vec![];
+    if let Some(tile) = main_tile {
+        main_tiles.append(&mut split_main(&tile, window_count, definition, main));
+        geometry::rotate(&mut main_tiles, main.rotate, container);
+        geometry::flip(&mut main_tiles, main.flip, container);
+    }
+
+    let mut stack_tiles = vec![];
+    if let Some(tile) = stack_tile {
+        stack_tiles.append(&mut split_column(
+            &tile,
+            window_count.saturating_sub(main.count),
+            definition.columns.stack.split,
+            &definition.columns.stack.split_options(definition.remainder),
+        ));
+        geometry::rotate(&mut stack_tiles, definition.columns.stack.rotate, container);
+        geometry::flip(&mut stack_tiles, definition.columns.stack.flip, container);
+        if definition.columns.stack.reverse {
+            stack_tiles.reverse();
+        }
+    }
+
+    let mut roles = vec![ColumnKind::Main; main_tiles.len()];
+    roles.resize(main_tiles.len() + stack_tiles.len(), ColumnKind::Stack);
+
+    let mut all = vec![];
+    all.append(&mut main_tiles);
+    all.append(&mut stack_tiles);
+    (all, roles)
+}
+
+/// The `main` and `stack` column of a [`main_stack`] layout, rotated and flipped.
+fn main_stack_columns(
+    container: &Rect,
+    window_count: usize,
+    definition: &Layout,
+    main: &Main,
+) -> (Option<Rect>, Option<Rect>) {
+    let gaps = definition.gaps.unwrap_or_default();
+    let container = &gaps.grow_columns(container);
+    let axis = definition.columns.orientation_in(container).axis();
+    let (mut main_tile, mut stack_tile) = two_column_along(
+        axis,
+        definition.column_window_count(main.count, window_count),
         container,
         main.count,
-        main.size,
+        definition.main_size_for(main, window_count, axis.length(container)),
         definition.reserve,
     );
 
@@ -58,137 +288,447 @@ fn main_stack(
         (None, Some(b)) => {
             let mut v = vec![b];
             geometry::rotate(&mut v, definition.columns.rotate, container);
-            geometry::flip(&mut v, definition.columns.flip, container);
+            geometry::flip(&mut v, definition.columns.flip_in(container), container);
             stack_tile = Some(v[0]);
         }
         (Some(a), None) => {
             let mut v = vec![a];
             geometry::rotate(&mut v, definition.columns.rotate, container);
-            geometry::flip(&mut v, definition.columns.flip, container);
+            geometry::flip(&mut v, definition.columns.flip_in(container), container);
             main_tile = Some(v[0]);
         }
         (Some(a), Some(b)) => {
             let mut v = vec![a, b];
             geometry::rotate(&mut v, definition.columns.rotate, container);
-            geometry::flip(&mut v, definition.columns.flip, container);
+            geometry::flip(&mut v, definition.columns.flip_in(container), container);
             main_tile = Some(v[0]);
             stack_tile = Some(v[1]);
         }
     }
 
-    //geometry::flip(container, &mut rects, definition.flip);
+    gaps.shrink_columns([&mut main_tile, &mut stack_tile]);
+    (main_tile, stack_tile)
+}
+
+/// Split the `main` column into its tiles. With [`Layout::stable_main`], the column
+/// is always split into [`Main::count`] tiles so that the existing tiles don't change
+/// when another window is added, the tiles without a window are left empty.
+/// The tiles are reversed before, so that [`Main::reverse`] doesn't break this promise.
+fn split_main(tile: &Rect, window_count: usize, definition: &Layout, main: &Main) -> Vec<Rect> {
+    let main_window_count = usize::min(main.count, window_count);
+    let amount = if definition.stable_main {
+        main.count
+    } else {
+        main_window_count
+    };
+    let mut tiles = geometry::split_with(
+        tile,
+        amount,
+        main.split,
+        &main.split_options(definition.remainder),
+    );
+    if main.reverse {
+        tiles.reverse();
+    }
+    tiles.truncate(main_window_count);
+    tiles
+}
+
+fn stack_main_stack(
+    container: &Rect,
+    window_count: usize,
+    definition: &Layout,
+    main: &Main,
+    alternate_stack: &SecondStack,
+    hints: &Hints,
+) -> (Vec<Rect>, Vec<ColumnKind>) {
+    let main_window_count = cmp::min(main.count, window_count);
+    let stack_window_count = window_count.saturating_sub(main_window_count);
+    let balance_stacks = definition.columns.balances_stacks();
+    let (left_window_count, right_window_count) = if balance_stacks {
+        match hints
+            .stack_share()
+            .or_else(|| definition.columns.stack_share())
+        {
+            Some(share) if stack_window_count > 1 => {
+                let left = geometry::scale(stack_window_count as u32, share) as usize;
+                let left = left.clamp(1, stack_window_count - 1);
+                (left, stack_window_count - left)
+            }
+            _ => {
+                let counts = geometry::remainderless_division(stack_window_count, 2);
+                match definition.columns.stack_fill_order {
+                    // the left stack keeps the first window
+                    StackFillOrder::SecondStackFirst if stack_window_count > 1 => {
+                        (counts[1], counts[0])
+                    }
+                    _ => (counts[0], counts[1]),
+                }
+            }
+        }
+    } else {
+        (
+            cmp::min(1, stack_window_count),
+            stack_window_count.saturating_sub(1),
+        )
+    };
+
+    let (left_column, main_column, right_column) =
+        stack_main_stack_columns(container, window_count, definition, main, hints);
 
     let mut main_tiles = vec![];
-    if let Some(tile) = main_tile {
-        main_tiles.append(&mut geometry::split(
-            &tile,
-            usize::min(main.count, window_count),
-            main.split,
-        ));
+    if let Some(tile) = main_column {
+        main_tiles.append(&mut split_main(&tile, window_count, definition, main));
         geometry::rotate(&mut main_tiles, main.rotate, container);
         geometry::flip(&mut main_tiles, main.flip, container);
     }
 
-    let mut stack_tiles = vec![];
-    if let Some(tile) = stack_tile {
-        stack_tiles.append(&mut geometry::split(
+    let mut left_tiles = vec![];
+    if let Some(tile) = left_column {
+        left_tiles.append(&mut split_column(
             &tile,
-            window_count.saturating_sub(main.count),
+            left_window_count,
             definition.columns.stack.split,
+            &definition.columns.stack.split_options(definition.remainder),
         ));
-        geometry::rotate(&mut stack_tiles, definition.columns.stack.rotate, container);
-        geometry::flip(&mut stack_tiles, definition.columns.stack.flip, container);
+        geometry::rotate(&mut left_tiles, definition.columns.stack.rotate, container);
+        geometry::flip(&mut left_tiles, definition.columns.stack.flip, container);
+        if definition.columns.stack.reverse {
+            left_tiles.reverse();
+        }
     }
 
-    let mut all = vec![];
-    all.append(&mut main_tiles);
-    all.append(&mut stack_tiles);
-    all
+    let mut right_tiles = vec![];
+    if let Some(tile) = right_column {
+        right_tiles.append(&mut split_column(
+            &tile,
+            right_window_count,
+            Some(alternate_stack.split),
+            &alternate_stack.split_options(definition.remainder),
+        ));
+        geometry::rotate(&mut right_tiles, alternate_stack.rotate, container);
+        geometry::flip(&mut right_tiles, alternate_stack.flip, container);
+        if alternate_stack.reverse {
+            right_tiles.reverse();
+        }
+    }
+
+    let mut roles = vec![ColumnKind::Main; main_tiles.len()];
+    let mut tiles = main_tiles;
+    if balance_stacks && definition.columns.stack_fill_order == StackFillOrder::Alternate {
+        let mut left_tiles = left_tiles.into_iter();
+        let mut right_tiles = right_tiles.into_iter();
+        loop {
+            let (left, right) = (left_tiles.next(), right_tiles.next());
+            if left.is_none() && right.is_none() {
+                break;
+            }
+            for (tile, role) in [(left, ColumnKind::Stack), (right, ColumnKind::SecondStack)] {
+                if let Some(tile) = tile {
+                    tiles.push(tile);
+                    roles.push(role);
+                }
+            }
+        }
+    } else {
+        roles.resize(tiles.len() + left_tiles.len(), ColumnKind::Stack);
+        roles.resize(
+            tiles.len() + left_tiles.len() + right_tiles.len(),
+            ColumnKind::SecondStack,
+        );
+        tiles.append(&mut left_tiles);
+        tiles.append(&mut right_tiles);
+    }
+    (tiles, roles)
 }
 
-fn stack_main_stack(
+/// The left stack, `main`, and right stack column of a [`stack_main_stack`] layout,
+/// rotated and flipped.
+fn stack_main_stack_columns(
     container: &Rect,
     window_count: usize,
     definition: &Layout,
     main: &Main,
-    alternate_stack: &SecondStack,
-) -> Vec<Rect> {
-    let main_window_count = cmp::min(main.count, window_count);
-    let stack_window_count = window_count.saturating_sub(main_window_count);
-    let balance_stacks = definition.columns.stack.split.is_some();
-    let (left_window_count, right_window_count) = if balance_stacks {
-        let counts = geometry::remainderless_division(stack_window_count, 2);
-        (counts[0], counts[1])
-    } else {
-        (1, cmp::max(0, stack_window_count.saturating_sub(1)))
-    };
-
-    let (mut left_column, mut main_column, mut right_column) = three_column(
-        window_count,
+    hints: &Hints,
+) -> (Option<Rect>, Option<Rect>, Option<Rect>) {
+    let gaps = definition.gaps.unwrap_or_default();
+    let container = &gaps.grow_columns(container);
+    let axis = definition.columns.orientation_in(container).axis();
+    let (mut left_column, mut main_column, mut right_column) = three_column_along(
+        axis,
+        definition.column_window_count(main.count, window_count),
         container,
-        main_window_count,
-        main.size,
+        cmp::min(main.count, window_count),
+        definition.main_size_for(main, window_count, axis.length(container)),
         definition.reserve,
-        balance_stacks,
+        definition.takeover_order,
+        definition.columns.balances_stacks(),
+        definition
+            .columns
+            .second_stack
+            .as_ref()
+            .and_then(|second_stack| second_stack.size),
     );
 
+    if let (Some(share), Some(left), Some(main), Some(right)) = (
+        hints.stack_share(),
+        left_column.as_mut(),
+        main_column.as_mut(),
+        right_column.as_mut(),
+    ) {
+        rebalance_stacks(axis, left, main, right, share);
+    }
+
     // prepare columns to rotate / flip
     let mut columns = vec![];
     columns.push(left_column.unwrap_or(Rect::new(0, 0, 0, 0)));
     columns.push(main_column.unwrap_or(Rect::new(0, 0, 0, 0)));
     columns.push(right_column.unwrap_or(Rect::new(0, 0, 0, 0)));
     geometry::rotate(&mut columns, definition.columns.rotate, container);
-    geometry::flip(&mut columns, definition.columns.flip, container);
+    geometry::flip(
+        &mut columns,
+        definition.columns.flip_in(container),
+        container,
+    );
 
     // copy rotated/flipped columns into the variables
     let non_empty = |rect: &&Rect| rect.surface_area() > 0;
     left_column = columns.first().filter(non_empty).copied();
     main_column = columns.get(1).filter(non_empty).copied();
     right_column = columns.get(2).filter(non_empty).copied();
+    gaps.shrink_columns([&mut left_column, &mut main_column, &mut right_column]);
+    (left_column, main_column, right_column)
+}
+
+fn main_stack_main(
+    container: &Rect,
+    window_count: usize,
+    definition: &Layout,
+    main: &Main,
+    second_main: &Main,
+) -> (Vec<Rect>, Vec<ColumnKind>) {
+    let main_window_count = cmp::min(main.count, window_count);
+    let second_main_window_count = cmp::min(second_main.count, window_count - main_window_count);
+    let stack_window_count = window_count - main_window_count - second_main_window_count;
+
+    let [main_column, stack_column, second_main_column] =
+        main_stack_main_columns(container, window_count, definition, main, second_main);
 
     let mut main_tiles = vec![];
     if let Some(tile) = main_column {
-        main_tiles.append(&mut geometry::split(&tile, main_window_count, main.split));
+        main_tiles.append(&mut split_main(&tile, window_count, definition, main));
         geometry::rotate(&mut main_tiles, main.rotate, container);
         geometry::flip(&mut main_tiles, main.flip, container);
     }
 
-    let mut left_tiles = vec![];
-    if let Some(tile) = left_column {
-        left_tiles.append(&mut geometry::split(
+    let mut second_main_tiles = vec![];
+    if let Some(tile) = second_main_column {
+        second_main_tiles.append(&mut split_main(
             &tile,
-            left_window_count,
-            definition.columns.stack.split,
+            window_count - main_window_count,
+            definition,
+            second_main,
         ));
-        geometry::rotate(&mut left_tiles, definition.columns.stack.rotate, container);
-        geometry::flip(&mut left_tiles, definition.columns.stack.flip, container);
+        geometry::rotate(&mut second_main_tiles, second_main.rotate, container);
+        geometry::flip(&mut second_main_tiles, second_main.flip, container);
     }
 
-    let mut right_tiles = vec![];
-    if let Some(tile) = right_column {
-        right_tiles.append(&mut geometry::split(
+    let mut stack_tiles = vec![];
+    if let Some(tile) = stack_column {
+        stack_tiles.append(&mut split_column(
             &tile,
-            right_window_count,
-            Some(alternate_stack.split),
+            stack_window_count,
+            definition.columns.stack.split,
+            &definition.columns.stack.split_options(definition.remainder),
         ));
-        geometry::rotate(&mut right_tiles, alternate_stack.rotate, container);
-        geometry::flip(&mut right_tiles, alternate_stack.flip, container);
+        geometry::rotate(&mut stack_tiles, definition.columns.stack.rotate, container);
+        geometry::flip(&mut stack_tiles, definition.columns.stack.flip, container);
+        if definition.columns.stack.reverse {
+            stack_tiles.reverse();
+        }
     }
 
+    let mut roles = vec![ColumnKind::Main; main_tiles.len()];
+    roles.resize(
+        main_tiles.len() + second_main_tiles.len(),
+        ColumnKind::SecondMain,
+    );
+    roles.resize(
+        main_tiles.len() + second_main_tiles.len() + stack_tiles.len(),
+        ColumnKind::Stack,
+    );
+
     let mut tiles = vec![];
     tiles.append(&mut main_tiles);
-    tiles.append(&mut left_tiles);
-    tiles.append(&mut right_tiles);
-    tiles
+    tiles.append(&mut second_main_tiles);
+    tiles.append(&mut stack_tiles);
+    (tiles, roles)
+}
+
+/// The `main`, `stack`, and second `main` column of a [`main_stack_main`] layout,
+/// rotated and flipped.
+fn main_stack_main_columns(
+    container: &Rect,
+    window_count: usize,
+    definition: &Layout,
+    main: &Main,
+    second_main: &Main,
+) -> [Option<Rect>; 3] {
+    let gaps = definition.gaps.unwrap_or_default();
+    let container = &gaps.grow_columns(container);
+    let orientation = definition.columns.orientation_in(container);
+    let length = orientation.axis().length(container);
+    let (main_column, stack_column, second_main_column) = dual_main(
+        definition.column_window_count(main.count + second_main.count, window_count),
+        &orientation.orient(*container),
+        main.count,
+        definition.main_size_for(main, window_count, length),
+        second_main.count,
+        definition.main_size_for(second_main, window_count, length),
+        definition.reserve,
+    );
+
+    // prepare columns to rotate / flip
+    let mut columns = [main_column, stack_column, second_main_column]
+        .map(|column| orientation.orient(column.unwrap_or(Rect::new(0, 0, 0, 0))));
+    geometry::rotate(&mut columns, definition.columns.rotate, container);
+    geometry::flip(
+        &mut columns,
+        definition.columns.flip_in(container),
+        container,
+    );
+    let mut columns = columns.map(|rect| Some(rect).filter(|rect| rect.surface_area() > 0));
+    gaps.shrink_columns(&mut columns);
+    columns
+}
+
+fn list(
+    container: &Rect,
+    window_count: usize,
+    definition: &Layout,
+) -> (Vec<Rect>, Vec<ColumnKind>) {
+    let (rects, window_counts) = list_columns(container, window_count, definition);
+
+    let mut tiles = vec![];
+    let mut roles = vec![];
+    for ((column, rect), count) in definition.columns.list.iter().zip(rects).zip(window_counts) {
+        if let Some(rect) = rect {
+            let mut column_tiles = split_column(
+                &rect,
+                count,
+                column.split,
+                &column.split_options(definition.remainder),
+            );
+            geometry::rotate(&mut column_tiles, column.rotate, container);
+            geometry::flip(&mut column_tiles, column.flip, container);
+            if column.reverse {
+                column_tiles.reverse();
+            }
+            roles.resize(roles.len() + column_tiles.len(), column.kind);
+            tiles.append(&mut column_tiles);
+        }
+    }
+    (tiles, roles)
+}
+
+/// The columns of a layout built from [`layouts::Columns::list`] as arranged by [`apply`],
+/// in the same order as the list, [`None`] for the columns without any space.
+pub(crate) fn list_column_rects(
+    definition: &Layout,
+    window_count: usize,
+    container: &Rect,
+) -> Vec<Option<Rect>> {
+    let container = &tiling_container(definition, container);
+    let (columns, _) = list_columns(container, window_count, definition);
+    let mut rects: Vec<Rect> = columns
+        .iter()
+        .map(|column| column.unwrap_or(Rect::new(0, 0, 0, 0)))
+        .collect();
+    geometry::flip(&mut rects, definition.flip, container);
+    geometry::rotate(&mut rects, definition.rotate, container);
+    definition.gaps.unwrap_or_default().shrink(&mut rects);
+    columns
+        .iter()
+        .zip(rects)
+        .map(|(column, rect)| column.map(|_| rect))
+        .collect()
+}
+
+/// The columns of a [`list`] layout, rotated and flipped,
+/// and the amount of windows in each of them.
+fn list_columns(
+    container: &Rect,
+    window_count: usize,
+    definition: &Layout,
+) -> (Vec<Option<Rect>>, Vec<usize>) {
+    let gaps = definition.gaps.unwrap_or_default();
+    let container = &gaps.grow_columns(container);
+    let list = &definition.columns.list;
+
+    // fill the columns in the order of the list, the last one takes the windows left over
+    let mut remaining = window_count;
+    let mut window_counts: Vec<usize> = list
+        .iter()
+        .map(|column| {
+            let count = column
+                .capacity
+                .map_or(remaining, |c| cmp::min(c, remaining));
+            remaining -= count;
+            count
+        })
+        .collect();
+    if let Some(last) = window_counts.last_mut() {
+        *last += remaining;
+    }
+
+    // with a stable main, the columns are arranged as if all of them contained windows
+    let column_window_counts: Vec<usize> = window_counts
+        .iter()
+        .map(|&count| {
+            if definition.stable_main {
+                count.max(1)
+            } else {
+                count
+            }
+        })
+        .collect();
+
+    let orientation = definition.columns.orientation_in(container);
+    let sizes: Vec<Option<Size>> = list.iter().map(|column| column.size).collect();
+    let columns = column_list(
+        &column_window_counts,
+        &orientation.orient(*container),
+        &sizes,
+        definition.reserve,
+    );
+
+    // prepare columns to rotate / flip
+    let mut rects: Vec<Rect> = columns
+        .into_iter()
+        .map(|column| orientation.orient(column.unwrap_or(Rect::new(0, 0, 0, 0))))
+        .collect();
+    geometry::rotate(&mut rects, definition.columns.rotate, container);
+    geometry::flip(&mut rects, definition.columns.flip_in(container), container);
+    let mut columns: Vec<Option<Rect>> = rects
+        .into_iter()
+        .map(|rect| Some(rect).filter(|rect| rect.surface_area() > 0))
+        .collect();
+    gaps.shrink_columns(&mut columns);
+    (columns, window_counts)
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
-        apply,
-        geometry::{Rect, Split},
-        layouts::{Columns, Layouts, SecondStack, Stack},
-        Layout,
+        apply, apply_detailed, apply_with_hints,
+        geometry::{
+            check_tiling, FixedGrid, Flip, Gaps, GridFill, GridOverflow, Margin, Orientation, Rect,
+            Remainder, Reserve, Rotation, Size, Split,
+        },
+        layouts::{
+            Column, ColumnKind, Columns, Layouts, MainPosition, SecondStack, Stack, StackFillOrder,
+        },
+        main_rect, Hints, Layout,
     };
 
     #[test]
@@ -223,6 +763,208 @@ mod tests {
         assert_eq!(Rect::new(3840, 2160, 1280, 720), rects[2]);
     }
 
+    #[test]
+    fn flipped_main_stack_works_with_offset() {
+        let layout = Layout {
+            flip: Flip::Both,
+            ..Default::default()
+        };
+        let rect = Rect::new(2560, 1440, 2560, 1440);
+        let rects = apply(&layout, 3, &rect);
+
+        assert_eq!(Rect::new(3840, 1440, 1280, 1440), rects[0]);
+        assert_eq!(Rect::new(2560, 2160, 1280, 720), rects[1]);
+        assert_eq!(Rect::new(2560, 1440, 1280, 720), rects[2]);
+    }
+
+    #[test]
+    fn min_size_stacks_the_windows_that_dont_fit_onto_the_last_tile() {
+        let mut layout = Layout::default();
+        layout.columns.stack.min_size = Some((0, 60));
+        let rects = apply(&layout, 5, &Rect::new(0, 0, 400, 200));
+        assert_eq!(
+            vec![
+                Rect::new(0, 0, 200, 200),
+                Rect::new(200, 0, 200, 67),
+                Rect::new(200, 67, 200, 67),
+                Rect::new(200, 134, 200, 66),
+                Rect::new(200, 134, 200, 66),
+            ],
+            rects
+        );
+
+        // the same applies to the columns of a list
+        layout.add_column(1, Column::default());
+        layout.columns.list[1].min_size = Some((0, 100));
+        layout.columns.list[1].capacity = Some(3);
+        let rects = apply(&layout, 5, &Rect::new(0, 0, 600, 200));
+        assert_eq!(Rect::new(300, 0, 150, 100), rects[1]);
+        assert_eq!(Rect::new(300, 100, 150, 100), rects[2]);
+        assert_eq!(Rect::new(300, 100, 150, 100), rects[3]);
+        assert_eq!(Rect::new(450, 0, 150, 200), rects[4]);
+    }
+
+    #[test]
+    fn vertical_orientation_puts_main_on_top_of_the_stack() {
+        let mut layout = Layouts::default().get("MainAndVertStack").unwrap().clone();
+        layout.columns.orientation = Orientation::Vertical;
+        let container = Rect::new(0, 0, 400, 200);
+
+        let rects = apply(&layout, 3, &container);
+        assert_eq!(Rect::new(0, 0, 400, 100), rects[0]);
+        assert_eq!(Rect::new(0, 100, 400, 50), rects[1]);
+        assert_eq!(Rect::new(0, 150, 400, 50), rects[2]);
+
+        assert_eq!(vec![container], apply(&layout, 1, &container));
+    }
+
+    #[test]
+    fn main_position_arranges_main_without_changing_the_stack() {
+        let mut layout = Layouts::default().get("MainAndVertStack").unwrap().clone();
+        let container = Rect::new(0, 0, 400, 200);
+
+        layout.columns.main_position = Some(MainPosition::Right);
+        let rects = apply(&layout, 3, &container);
+        assert_eq!(Rect::new(200, 0, 200, 200), rects[0]);
+        assert_eq!(Rect::new(0, 0, 200, 100), rects[1]);
+        assert_eq!(Rect::new(0, 100, 200, 100), rects[2]);
+
+        layout.columns.main_position = Some(MainPosition::Bottom);
+        let rects = apply(&layout, 3, &container);
+        assert_eq!(Rect::new(0, 100, 400, 100), rects[0]);
+        assert_eq!(Rect::new(0, 0, 400, 50), rects[1]);
+        assert_eq!(Rect::new(0, 50, 400, 50), rects[2]);
+        assert_eq!(Some(rects[0]), main_rect(&layout, 3, &container));
+
+        layout.columns.main_position = Some(MainPosition::Top);
+        layout.columns.orientation = Orientation::Horizontal;
+        let rects = apply(&layout, 3, &container);
+        assert_eq!(Rect::new(0, 0, 400, 100), rects[0]);
+    }
+
+    #[test]
+    fn centered_main_puts_the_stack_on_both_sides() {
+        let mut layout = Layouts::default().get("MainAndVertStack").unwrap().clone();
+        let mut three_columns = layout.clone();
+        three_columns.columns.second_stack = Some(SecondStack::default());
+        layout.columns.main_position = Some(MainPosition::Center);
+        let container = Rect::new(0, 0, 400, 200);
+        for window_count in 0..8 {
+            assert_eq!(
+                apply(&three_columns, window_count, &container),
+                apply(&layout, window_count, &container)
+            );
+        }
+    }
+
+    #[test]
+    fn adapt_to_portrait_puts_main_on_top_in_portrait_containers() {
+        let mut layout = Layouts::default().get("MainAndVertStack").unwrap().clone();
+        layout.columns.adapt_to_portrait = true;
+
+        let portrait = Rect::new(0, 0, 200, 400);
+        let rects = apply(&layout, 3, &portrait);
+        assert_eq!(Rect::new(0, 0, 200, 200), rects[0]);
+        assert_eq!(Rect::new(0, 200, 200, 100), rects[1]);
+        assert_eq!(Rect::new(0, 300, 200, 100), rects[2]);
+        assert_eq!(Some(rects[0]), main_rect(&layout, 3, &portrait));
+
+        let landscape = Rect::new(0, 0, 400, 200);
+        let rects = apply(&layout, 3, &landscape);
+        assert_eq!(Rect::new(0, 0, 200, 200), rects[0]);
+        assert_eq!(Rect::new(200, 0, 200, 100), rects[1]);
+    }
+
+    fn four_columns() -> Layout {
+        let column = |kind, size, capacity| Column {
+            kind,
+            size,
+            capacity,
+            ..Default::default()
+        };
+        Layout {
+            columns: Columns {
+                list: vec![
+                    column(ColumnKind::Main, Some(Size::Ratio(0.4)), Some(1)),
+                    column(ColumnKind::Stack, None, Some(2)),
+                    column(ColumnKind::Stack, None, Some(2)),
+                    column(ColumnKind::SecondStack, None, None),
+                ],
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn column_list_fills_the_columns_in_order() {
+        let layout = four_columns();
+        let container = Rect::new(0, 0, 5120, 1440);
+        let tiling = apply_detailed(&layout, 7, &container);
+        assert_eq!(
+            vec![
+                Rect::new(0, 0, 2048, 1440),
+                Rect::new(2048, 0, 1024, 720),
+                Rect::new(2048, 720, 1024, 720),
+                Rect::new(3072, 0, 1024, 720),
+                Rect::new(3072, 720, 1024, 720),
+                Rect::new(4096, 0, 1024, 720),
+                Rect::new(4096, 720, 1024, 720),
+            ],
+            tiling.tiles
+        );
+        assert_eq!(ColumnKind::Main, tiling.roles[0]);
+        assert_eq!(ColumnKind::SecondStack, tiling.roles[6]);
+        assert_eq!(
+            Some(Rect::new(0, 0, 2048, 1440)),
+            main_rect(&layout, 7, &container)
+        );
+
+        // empty columns are taken over
+        let rects = apply(&layout, 3, &container);
+        assert_eq!(Rect::new(2048, 0, 3072, 720), rects[1]);
+    }
+
+    #[test]
+    fn column_list_keeps_its_columns_with_stable_main() {
+        let mut layout = four_columns();
+        layout.stable_main = true;
+        let container = Rect::new(0, 0, 5120, 1440);
+        assert_eq!(
+            vec![Rect::new(0, 0, 2048, 1440)],
+            apply(&layout, 1, &container)
+        );
+    }
+
+    #[test]
+    fn column_list_matches_the_built_in_columns() {
+        let main_and_vert_stack = Layouts::default().get("MainAndVertStack").unwrap().clone();
+        let mut listed = main_and_vert_stack.clone();
+        listed.columns.list = vec![
+            Column {
+                kind: ColumnKind::Main,
+                size: Some(Size::Ratio(0.5)),
+                capacity: Some(1),
+                split: Some(Split::Vertical),
+                ..Default::default()
+            },
+            Column::default(),
+        ];
+        let container = Rect::new(0, 0, 1279, 1023);
+        for reserve in [Reserve::None, Reserve::Reserve, Reserve::ReserveAndCenter] {
+            let mut built_in = main_and_vert_stack.clone();
+            built_in.reserve = reserve;
+            listed.reserve = reserve;
+            for i in 0..10 {
+                assert_eq!(
+                    apply_detailed(&built_in, i, &container),
+                    apply_detailed(&listed, i, &container),
+                    "{reserve:?} with {i} windows"
+                );
+            }
+        }
+    }
+
     #[test]
     fn stack_main_stack_works_with_offset() {
         let layout = Layout {
@@ -239,10 +981,294 @@ mod tests {
         assert_eq!(Rect::new(4480, 1440, 640, 1440), rects[2]);
     }
 
+    #[test]
+    fn auto_split_main_follows_rotated_column() {
+        let mut layout = Layout {
+            columns: Columns {
+                main: Some(crate::layouts::Main {
+                    count: 2,
+                    split: Some(Split::Auto),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let rect = Rect::new(0, 0, 1920, 1080);
+        let rects = apply(&layout, 3, &rect);
+        assert_eq!(Rect::new(0, 0, 960, 540), rects[0]);
+        assert_eq!(Rect::new(0, 540, 960, 540), rects[1]);
+
+        // the main column is on top, wider than tall
+        layout.columns.rotate = Rotation::East;
+        let rects = apply(&layout, 3, &rect);
+        assert_eq!(Rect::new(0, 0, 960, 540), rects[0]);
+        assert_eq!(Rect::new(960, 0, 960, 540), rects[1]);
+    }
+
+    #[test]
+    fn stack_balance_hint_divides_windows_and_space() {
+        let layout = Layouts::default()
+            .get("CenterMainBalanced")
+            .unwrap()
+            .clone();
+        let container = Rect::new(0, 0, 400, 200);
+        let hints = Hints {
+            stack_balance: Some(0.75),
+            ..Default::default()
+        };
+        let tiling = apply_detailed(&layout, 5, &container);
+        let rects = apply_with_hints(&layout, 5, &container, &hints);
+        assert_eq!(tiling.tiles.len(), rects.len());
+        assert_eq!(Rect::new(150, 0, 200, 200), rects[0]);
+        // three windows in the left stack, one in the right stack
+        assert!(rects[1..4].iter().all(|rect| rect.right_edge() <= 150));
+        assert_eq!(Rect::new(350, 0, 50, 200), rects[4]);
+        assert_eq!(
+            apply(&layout, 5, &container),
+            apply_with_hints(&layout, 5, &container, &Hints::default())
+        );
+    }
+
+    #[test]
+    fn balance_stacks_overrides_the_stack_split() {
+        let mut layout = Layouts::default().get("CenterMain").unwrap().clone();
+        let container = Rect::new(0, 0, 400, 200);
+        layout.columns.stack.split = Some(Split::Horizontal);
+        let roles = |layout: &Layout| apply_detailed(layout, 5, &container).roles;
+        assert_eq!(
+            vec![
+                ColumnKind::Main,
+                ColumnKind::Stack,
+                ColumnKind::SecondStack,
+                ColumnKind::SecondStack,
+                ColumnKind::SecondStack,
+            ],
+            roles(&layout)
+        );
+
+        layout.columns.balance_stacks = None;
+        assert_eq!(
+            vec![
+                ColumnKind::Main,
+                ColumnKind::Stack,
+                ColumnKind::Stack,
+                ColumnKind::SecondStack,
+                ColumnKind::SecondStack,
+            ],
+            roles(&layout)
+        );
+    }
+
+    #[test]
+    fn stack_distribution_divides_the_windows_of_balanced_stacks() {
+        let mut layout = Layouts::default()
+            .get("CenterMainBalanced")
+            .unwrap()
+            .clone();
+        layout.columns.stack_distribution = Some(1.0 / 3.0);
+        let container = Rect::new(0, 0, 400, 200);
+        let roles = apply_detailed(&layout, 7, &container).roles;
+        let in_stack = |kind| roles.iter().filter(|role| **role == kind).count();
+        assert_eq!(2, in_stack(ColumnKind::Stack));
+        assert_eq!(4, in_stack(ColumnKind::SecondStack));
+
+        // each stack keeps a window, and the hint takes precedence
+        layout.columns.stack_distribution = Some(0.0);
+        let roles = apply_detailed(&layout, 3, &container).roles;
+        assert_eq!(ColumnKind::Stack, roles[1]);
+        let hints = Hints {
+            stack_balance: Some(0.5),
+            ..Default::default()
+        };
+        layout.columns.stack_distribution = None;
+        let even = apply(&layout, 7, &container);
+        layout.columns.stack_distribution = Some(0.0);
+        assert_eq!(even, apply_with_hints(&layout, 7, &container, &hints));
+    }
+
+    #[test]
+    fn stack_fill_order_decides_where_the_windows_go() {
+        let mut layout = Layouts::default()
+            .get("CenterMainBalanced")
+            .unwrap()
+            .clone();
+        let container = Rect::new(0, 0, 400, 200);
+        let roles = |layout: &Layout| apply_detailed(layout, 4, &container).roles;
+        let (main, stack, second) = (ColumnKind::Main, ColumnKind::Stack, ColumnKind::SecondStack);
+        assert_eq!(vec![main, stack, stack, second], roles(&layout));
+
+        layout.columns.stack_fill_order = StackFillOrder::SecondStackFirst;
+        assert_eq!(vec![main, stack, second, second], roles(&layout));
+        assert_eq!(
+            vec![main, stack],
+            apply_detailed(&layout, 2, &container).roles
+        );
+
+        layout.columns.stack_fill_order = StackFillOrder::Alternate;
+        assert_eq!(vec![main, stack, second, stack], roles(&layout));
+        let rects = apply(&layout, 4, &container);
+        assert!(rects[1].right_edge() <= 100 && rects[3].right_edge() <= 100);
+        assert!(rects[2].x >= 300);
+    }
+
+    #[test]
+    fn hard_cap_hint_limits_the_tiles() {
+        let layout = Layouts::default().get("MainAndVertStack").unwrap().clone();
+        let container = Rect::new(0, 0, 400, 200);
+        let hints = Hints {
+            hard_cap: Some(3),
+            ..Default::default()
+        };
+        assert_eq!(
+            apply(&layout, 3, &container),
+            apply_with_hints(&layout, 10_000, &container, &hints)
+        );
+        assert_eq!(
+            apply(&layout, 2, &container),
+            apply_with_hints(&layout, 2, &container, &hints)
+        );
+        let hints = Hints {
+            hard_cap: Some(0),
+            ..Default::default()
+        };
+        assert!(apply_with_hints(&layout, 5, &container, &hints).is_empty());
+    }
+
+    #[test]
+    fn reverse_assigns_tiles_in_reverse_order() {
+        let mut layout = Layouts::default().get("MainAndVertStack").unwrap().clone();
+        let container = Rect::new(0, 0, 400, 200);
+        let rects = apply(&layout, 4, &container);
+        layout.columns.stack.reverse = true;
+        let reversed = apply(&layout, 4, &container);
+        assert_eq!(rects[0], reversed[0]);
+        assert_eq!(
+            rects[1..].iter().rev().collect::<Vec<_>>(),
+            reversed[1..].iter().collect::<Vec<_>>()
+        );
+    }
+
+    /// A tiny linear congruential generator, to generate
+    /// reproducible but arbitrary inputs for property tests.
+    struct Lcg(u64);
+
+    impl Lcg {
+        fn next(&mut self, bound: usize) -> usize {
+            self.0 = self
+                .0
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            ((self.0 >> 33) % bound as u64) as usize
+        }
+
+        fn pick<T: Clone>(&mut self, values: &[T]) -> T {
+            values[self.next(values.len())].clone()
+        }
+    }
+
+    #[test]
+    fn dual_main_fills_both_main_columns_before_the_stack() {
+        let layout = Layouts::default().get("DualMain").unwrap().clone();
+        let container = Rect::new(0, 0, 1000, 600);
+
+        let tiling = apply_detailed(&layout, 4, &container);
+        assert_eq!(
+            vec![
+                ColumnKind::Main,
+                ColumnKind::SecondMain,
+                ColumnKind::Stack,
+                ColumnKind::Stack
+            ],
+            tiling.roles
+        );
+        assert_eq!(Rect::new(0, 0, 350, 600), tiling.tiles[0]);
+        assert_eq!(Rect::new(650, 0, 350, 600), tiling.tiles[1]);
+        assert_eq!(Rect::new(350, 0, 300, 300), tiling.tiles[2]);
+
+        let rects = apply(&layout, 2, &container);
+        assert_eq!(
+            vec![Rect::new(0, 0, 500, 600), Rect::new(500, 0, 500, 600)],
+            rects
+        );
+    }
+
+    #[test]
+    fn stable_main_never_moves_main_windows() {
+        let mut rng = Lcg(42);
+        let splits = [
+            None,
+            Some(Split::Vertical),
+            Some(Split::Horizontal),
+            Some(Split::Grid),
+            Some(Split::Fibonacci),
+            Some(Split::Dwindle),
+            Some(Split::BinaryTree(0.6)),
+            Some(Split::Tatami),
+        ];
+        let flips = [Flip::None, Flip::Horizontal, Flip::Vertical, Flip::Both];
+        let rotations = [
+            Rotation::North,
+            Rotation::East,
+            Rotation::South,
+            Rotation::West,
+        ];
+        let reserves = [Reserve::None, Reserve::Reserve, Reserve::ReserveAndCenter];
+        let defaults = Layouts::default().layouts().to_vec();
+
+        for _ in 0..500 {
+            let mut layout = rng.pick(&defaults);
+            let Some(main) = layout.columns.main.as_mut() else {
+                continue;
+            };
+            main.count = 1 + rng.next(4);
+            main.split = rng.pick(&splits);
+            main.size_curve = vec![(3, Size::Ratio(0.7))];
+            main.flip = rng.pick(&flips);
+            main.rotate = rng.pick(&rotations);
+            layout.stable_main = true;
+            layout.reserve = rng.pick(&reserves);
+            layout.flip = rng.pick(&flips);
+            layout.rotate = rng.pick(&rotations);
+            layout.columns.rotate = rng.pick(&rotations);
+            layout.columns.stack.split = rng.pick(&splits);
+            let container = Rect::new(
+                rng.next(100) as i32,
+                rng.next(100) as i32,
+                200 + rng.next(2000) as u32,
+                200 + rng.next(2000) as u32,
+            );
+
+            let main_tiles = |window_count| {
+                let tiling = apply_detailed(&layout, window_count, &container);
+                let roles = tiling.roles.iter();
+                tiling
+                    .tiles
+                    .iter()
+                    .zip(roles)
+                    .filter(|(_, role)| **role == ColumnKind::Main)
+                    .map(|(tile, _)| *tile)
+                    .collect::<Vec<Rect>>()
+            };
+            let mut previous = main_tiles(0);
+            for window_count in 1..12 {
+                let rects = main_tiles(window_count);
+                assert_eq!(
+                    previous[..],
+                    rects[..previous.len()],
+                    "{:?} with {} windows",
+                    layout,
+                    window_count
+                );
+                previous = rects;
+            }
+        }
+    }
+
     #[test]
     fn should_never_return_more_rects_than_windows_for_any_layout() {
         let container = Rect::new(0, 0, 40, 20);
-        let mut layouts = Layouts::default().layouts;
+        let mut layouts = Layouts::default().layouts().to_vec();
 
         // this specific layout does not exists in the defaults,
         // but has lead to the issue tested here in the past when user-defined
@@ -258,17 +1284,241 @@ mod tests {
             ..Default::default()
         });
 
-        for layout in layouts {
-            for i in 0usize..6 {
+        for mut layout in layouts {
+            for stable_main in [false, true] {
+                layout.stable_main = stable_main;
+                for i in 0usize..6 {
+                    let rects = apply(&layout, i, &container);
+                    assert!(
+                        rects.len() <= i,
+                        "got {}, expected <= {}, layout {} (stable main: {})",
+                        rects.len(),
+                        i,
+                        &layout.name,
+                        stable_main
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn default_layouts_satisfy_tiling_invariants() {
+        let container = Rect::new(0, 0, 1920, 1080);
+        for layout in Layouts::default() {
+            for i in 0usize..12 {
                 let rects = apply(&layout, i, &container);
+                let issues = check_tiling(&rects, &container, layout.may_overlap());
                 assert!(
-                    rects.len() <= i,
-                    "got {}, expected <= {}, layout {}",
-                    rects.len(),
+                    issues.is_empty(),
+                    "layout {} with {} windows: {:?}",
+                    &layout.name,
                     i,
-                    &layout.name
+                    issues
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn main_rect_matches_the_main_tiles() {
+        let container = Rect::new(0, 0, 1920, 1080);
+        let mut layouts = Layouts::default().layouts().to_vec();
+        for layout in layouts.clone() {
+            let mut rotated = layout.clone();
+            rotated.rotate = Rotation::East;
+            rotated.flip = Flip::Horizontal;
+            rotated.columns.rotate = Rotation::South;
+            layouts.push(rotated);
+        }
+        for layout in layouts {
+            for i in 0usize..8 {
+                let tiling = apply_detailed(&layout, i, &container);
+                let main_tiles: Vec<&Rect> = tiling
+                    .tiles
+                    .iter()
+                    .zip(&tiling.roles)
+                    .filter(|(_, role)| **role == ColumnKind::Main)
+                    .map(|(tile, _)| tile)
+                    .collect();
+                let bounding_box = main_tiles.iter().map(|tile| **tile).reduce(|a, b| {
+                    let x = i32::min(a.x, b.x);
+                    let y = i32::min(a.y, b.y);
+                    let w = i32::max(a.right_edge(), b.right_edge()) - x;
+                    let h = i32::max(a.bottom_edge(), b.bottom_edge()) - y;
+                    Rect::new(x, y, w as u32, h as u32)
+                });
+                assert_eq!(
+                    bounding_box,
+                    main_rect(&layout, i, &container),
+                    "layout {} with {} windows",
+                    &layout.name,
+                    i
                 );
             }
         }
     }
+
+    /// Every built-in split, for the stress tests.
+    const ALL_SPLITS: [Option<Split>; 17] = [
+        None,
+        Some(Split::Horizontal),
+        Some(Split::Vertical),
+        Some(Split::Grid),
+        Some(Split::RowGrid),
+        Some(Split::Fibonacci),
+        Some(Split::Dwindle),
+        Some(Split::BinaryTree(0.6)),
+        Some(Split::Columns(3)),
+        Some(Split::Rows(3)),
+        Some(Split::Tatami),
+        Some(Split::HorizontalAccordion),
+        Some(Split::VerticalAccordion),
+        Some(Split::Auto),
+        Some(Split::AspectRatio(Split::DEFAULT_ASPECT_RATIO)),
+        Some(Split::AspectRatio(0.25)),
+        Some(Split::FixedGrid(FixedGrid {
+            rows: 2,
+            columns: 3,
+            fill: GridFill::ColumnByColumn,
+            overflow: GridOverflow::ShareLastCell,
+        })),
+    ];
+
+    /// Every default layout with each of the built-in splits in its stack,
+    /// rotated and with the remainder in the center to hit the slower paths.
+    fn stress_layouts() -> Vec<Layout> {
+        let mut layouts = vec![];
+        for layout in Layouts::default() {
+            for split in ALL_SPLITS {
+                let mut layout = layout.clone();
+                layout.columns.stack.split = split;
+                layout.rotate = Rotation::East;
+                layout.remainder = Remainder::Center;
+                layouts.push(layout);
+            }
+        }
+        layouts
+    }
+
+    #[test]
+    fn count_ratio_puts_a_share_of_the_windows_into_main() {
+        let mut layout = Layouts::default().get("MainAndVertStack").unwrap().clone();
+        layout.columns.main.as_mut().unwrap().count_ratio = Some(0.5);
+        let container = Rect::new(0, 0, 400, 200);
+        let tiling = apply_detailed(&layout, 4, &container);
+        let mains = tiling
+            .roles
+            .iter()
+            .filter(|role| **role == ColumnKind::Main);
+        assert_eq!(2, mains.count());
+        assert_eq!(
+            Some(Rect::new(0, 0, 200, 200)),
+            main_rect(&layout, 6, &container)
+        );
+    }
+
+    #[test]
+    fn gaps_separate_adjacent_tiles_only() {
+        let mut layout = Layouts::default().get("MainAndVertStack").unwrap().clone();
+        layout.gaps = Some(Gaps {
+            inner: 10,
+            ..Default::default()
+        });
+        let container = Rect::new(10, 20, 400, 200);
+        assert_eq!(
+            vec![
+                Rect::new(10, 20, 195, 200),
+                Rect::new(215, 20, 195, 95),
+                Rect::new(215, 125, 195, 95),
+            ],
+            apply(&layout, 3, &container)
+        );
+        assert_eq!(
+            Some(Rect::new(10, 20, 195, 200)),
+            main_rect(&layout, 3, &container)
+        );
+
+        // the reserved space is separated by the gap as well
+        layout.reserve = Reserve::Reserve;
+        let tiling = apply_detailed(&layout, 1, &container);
+        assert_eq!(vec![Rect::new(10, 20, 195, 200)], tiling.tiles);
+        assert_eq!(vec![Rect::new(215, 20, 195, 200)], tiling.reserved);
+    }
+
+    #[test]
+    fn gutter_adds_to_the_gaps_between_columns_only() {
+        let mut layout = Layouts::default().get("MainAndVertStack").unwrap().clone();
+        layout.gaps = Some(Gaps {
+            inner: 10,
+            gutter: 20,
+        });
+        let container = Rect::new(0, 0, 430, 200);
+        assert_eq!(
+            vec![
+                Rect::new(0, 0, 200, 200),
+                Rect::new(230, 0, 200, 95),
+                Rect::new(230, 105, 200, 95),
+            ],
+            apply(&layout, 3, &container)
+        );
+        assert_eq!(
+            Some(Rect::new(0, 0, 200, 200)),
+            main_rect(&layout, 3, &container)
+        );
+    }
+
+    #[test]
+    fn margin_shrinks_the_container_before_reserving() {
+        let mut layout = Layouts::default().get("CenterMain").unwrap().clone();
+        layout.reserve = Reserve::ReserveAndCenter;
+        layout.margin = Some(Margin {
+            left: 100,
+            ..Default::default()
+        });
+        let container = Rect::new(0, 0, 500, 200);
+        let tiling = apply_detailed(&layout, 1, &container);
+        assert_eq!(vec![Rect::new(200, 0, 200, 200)], tiling.tiles);
+        assert_eq!(
+            Some(Rect::new(200, 0, 200, 200)),
+            main_rect(&layout, 1, &container)
+        );
+        assert!(tiling.reserved.iter().all(|rect| rect.x >= 100));
+    }
+
+    #[test]
+    fn default_layouts_with_gaps_stay_within_the_container() {
+        let container = Rect::new(0, 0, 1920, 1080);
+        for mut layout in Layouts::default() {
+            layout.gaps = Some(Gaps {
+                inner: 7,
+                gutter: 5,
+            });
+            for count in 1..12 {
+                for tile in apply(&layout, count, &container) {
+                    let inside = tile.x >= container.x
+                        && tile.y >= container.y
+                        && tile.right_edge() <= container.right_edge()
+                        && tile.bottom_edge() <= container.bottom_edge();
+                    assert!(inside, "{}: {tile:?}", layout.name);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn apply_handles_a_thousand_windows() {
+        let container = Rect::new(0, 0, 1920, 1080);
+        for layout in stress_layouts() {
+            let rects = apply(&layout, 1_000, &container);
+            assert!(rects.len() <= 1_000);
+            if layout.columns.stack.split.is_some() {
+                assert!(rects.len() >= 1_000 - 2, "{layout:?}");
+            }
+            assert!(rects.iter().all(|rect| rect.x >= 0
+                && rect.y >= 0
+                && rect.right_edge() <= 1920
+                && rect.bottom_edge() <= 1080));
+        }
+    }
 }
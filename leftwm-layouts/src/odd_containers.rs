@@ -0,0 +1,108 @@
+//! Runs all default layouts against awkward container sizes, like odd and prime
+//! dimensions, offsets, and containers with fewer pixels than windows, where
+//! rounding bugs show up that nicely divisible sizes hide.
+
+use crate::{
+    apply,
+    geometry::{check_tiling, Rect, TilingIssue},
+    layouts::Layouts,
+};
+
+const MAX_WINDOW_COUNT: usize = 16;
+
+/// Containers with odd and prime dimensions, some of them not at the origin.
+fn odd_containers() -> Vec<Rect> {
+    vec![
+        Rect::new(0, 0, 1366, 768),
+        Rect::new(0, 0, 1279, 1023),
+        Rect::new(0, 0, 1021, 1009),
+        Rect::new(1366, 0, 1279, 1023),
+        Rect::new(-7, 13, 997, 541),
+    ]
+}
+
+/// Containers too small to give every window a surface, eg. when
+/// the layout is calculated in character units for a preview.
+fn tiny_containers() -> Vec<Rect> {
+    vec![
+        Rect::new(0, 0, 40, 20),
+        Rect::new(0, 0, 13, 7),
+        Rect::new(3, 5, 41, 23),
+        Rect::new(0, 0, 1, 1),
+    ]
+}
+
+#[test]
+fn default_layouts_satisfy_tiling_invariants_in_odd_containers() {
+    for layout in Layouts::default() {
+        for container in odd_containers() {
+            for window_count in 0..=MAX_WINDOW_COUNT {
+                let tiles = apply(&layout, window_count, &container);
+                let issues = check_tiling(&tiles, &container, layout.may_overlap());
+                assert!(
+                    issues.is_empty(),
+                    "layout {} with {} windows in {:?}: {:?}\n{:?}",
+                    &layout.name,
+                    window_count,
+                    container,
+                    issues,
+                    tiles
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn default_layouts_stay_in_tiny_containers() {
+    for layout in Layouts::default() {
+        for container in tiny_containers() {
+            for window_count in 0..=MAX_WINDOW_COUNT {
+                let tiles = apply(&layout, window_count, &container);
+                // not every window can get a surface, but they must still stay
+                // inside of the container without overlapping each other
+                let issues: Vec<TilingIssue> =
+                    check_tiling(&tiles, &container, layout.may_overlap())
+                        .into_iter()
+                        .filter(|issue| !matches!(issue, TilingIssue::Empty(_)))
+                        .collect();
+                assert!(
+                    issues.is_empty(),
+                    "layout {} with {} windows in {:?}: {:?}\n{:?}",
+                    &layout.name,
+                    window_count,
+                    container,
+                    issues,
+                    tiles
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn default_layouts_cover_odd_containers_without_gaps() {
+    for layout in Layouts::default() {
+        if layout.may_overlap() || layout.reserve.is_reserved() {
+            continue;
+        }
+        for container in odd_containers().into_iter().chain(tiny_containers()) {
+            for window_count in 1..=MAX_WINDOW_COUNT {
+                let tiles = apply(&layout, window_count, &container);
+                let area: u64 = tiles
+                    .iter()
+                    .map(|tile| u64::from(tile.surface_area()))
+                    .sum();
+                assert_eq!(
+                    u64::from(container.surface_area()),
+                    area,
+                    "layout {} with {} windows in {:?}: {:?}",
+                    &layout.name,
+                    window_count,
+                    container,
+                    tiles
+                );
+            }
+        }
+    }
+}
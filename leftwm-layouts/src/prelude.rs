@@ -0,0 +1,14 @@
+//! Re-exports of the commonly needed types and functions,
+//! so that they can be imported with a single `use` statement.
+//!
+//! ```rust
+//! use leftwm_layouts::prelude::*;
+//!
+//! let layout = Layouts::default().get("MainAndVertStack").unwrap().clone();
+//! let rects = apply(&layout, 2, &Rect::new(0, 0, 400, 200));
+//! assert_eq!(Rect::new(200, 0, 200, 200), rects[1]);
+//! ```
+
+pub use crate::geometry::{Flip, Rect, Reserve, Rotation, Size, Split};
+pub use crate::layouts::{Layout, LayoutCommand, LayoutKind, Layouts};
+pub use crate::{apply, apply_detailed, apply_with_hints, Hints, Tiling};
@@ -0,0 +1,624 @@
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+    ops::Range,
+};
+
+use crate::{apply, geometry::Rect, Layout};
+
+/// How [`render_ascii_with`], [`contact_sheet_with`] and [`format_contact_sheet`]
+/// draw the tiles and previews.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderOptions {
+    /// Label the tiles with their (1-based) position, and the previews
+    /// of [`format_contact_sheet`] with their amount of windows.
+    pub labels: bool,
+
+    /// The colors of the tile labels, as ANSI 256 color codes (`ESC[38;5;<n>m`).
+    /// The tiles cycle through the colors, the labels aren't colored if it is empty.
+    pub colors: Vec<u8>,
+
+    /// The amount of blank columns between the previews of [`format_contact_sheet`].
+    pub spacing: usize,
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        Self {
+            labels: true,
+            colors: vec![],
+            spacing: 2,
+        }
+    }
+}
+
+/// Render the provided `tiles` as an ASCII diagram, in the style
+/// of the diagrams found in the documentation of this crate,
+/// with the [`RenderOptions::default`], see [`render_ascii_with`].
+///
+/// The tiles are expected to be in character units, each tile is drawn
+/// from its left to its right edge (both inclusive), which means the diagram
+/// of a container of `w` x `h` characters is `w + 1` characters wide and
+/// `h + 1` lines high. Tiles are labeled with their (1-based) position if
+/// there is enough space inside of them.
+///
+/// ## Example
+/// ```rust
+/// use leftwm_layouts::{geometry::Rect, render::render_ascii};
+///
+/// let tiles = vec![Rect::new(0, 0, 6, 4), Rect::new(6, 0, 6, 4)];
+/// let expected = "\
+/// +-----+-----+
+/// |     |     |
+/// |  1  |  2  |
+/// |     |     |
+/// +-----+-----+";
+/// assert_eq!(expected, render_ascii(&tiles, &Rect::new(0, 0, 12, 4)));
+/// ```
+pub fn render_ascii(tiles: &[Rect], container: &Rect) -> String {
+    render_ascii_with(tiles, container, &RenderOptions::default())
+}
+
+/// Render the provided `tiles` as an ASCII diagram like [`render_ascii`],
+/// labeled and colored as configured by the provided `options`.
+///
+/// ## Example
+/// ```rust
+/// use leftwm_layouts::{geometry::Rect, render::{render_ascii_with, RenderOptions}};
+///
+/// let tiles = vec![Rect::new(0, 0, 6, 2), Rect::new(6, 0, 6, 2)];
+/// let options = RenderOptions { colors: vec![1, 2], ..Default::default() };
+/// let expected = "\
+/// +-----+-----+
+/// |  \x1b[38;5;1m1\x1b[0m  |  \x1b[38;5;2m2\x1b[0m  |
+/// +-----+-----+";
+/// assert_eq!(expected, render_ascii_with(&tiles, &Rect::new(0, 0, 12, 2), &options));
+/// ```
+pub fn render_ascii_with(tiles: &[Rect], container: &Rect, options: &RenderOptions) -> String {
+    let width = container.w as usize + 1;
+    let height = container.h as usize + 1;
+    let mut canvas = vec![vec![' '; width]; height];
+
+    let mut put = |col: i32, row: i32, c: char| {
+        let (col, row) = (col - container.x, row - container.y);
+        if col < 0 || row < 0 || col as usize >= width || row as usize >= height {
+            return;
+        }
+        let cell = &mut canvas[row as usize][col as usize];
+        *cell = match *cell {
+            ' ' => c,
+            existing if existing == c => c,
+            // crossing lines
+            _ => '+',
+        };
+    };
+
+    for tile in tiles {
+        let (left, right) = (tile.left_edge(), tile.right_edge());
+        let (top, bottom) = (tile.top_edge(), tile.bottom_edge());
+        for col in left + 1..right {
+            put(col, top, '-');
+            put(col, bottom, '-');
+        }
+        for row in top + 1..bottom {
+            put(left, row, '|');
+            put(right, row, '|');
+        }
+        for (col, row) in [(left, top), (right, top), (left, bottom), (right, bottom)] {
+            put(col, row, '+');
+        }
+    }
+
+    // the row, the first and the last column, and the color of each label
+    let mut spans = vec![];
+    for (i, tile) in tiles.iter().enumerate().filter(|_| options.labels) {
+        let label: Vec<char> = (i + 1).to_string().chars().collect();
+        if tile.w as usize <= label.len() || tile.h < 2 {
+            continue;
+        }
+        let (center_x, center_y) = tile.center();
+        let start = center_x - (label.len() as i32 - 1) / 2;
+        if !options.colors.is_empty() {
+            let first = start - container.x;
+            let last = first + label.len() as i32 - 1;
+            let color = options.colors[i % options.colors.len()];
+            spans.push((center_y - container.y, first, last, color));
+        }
+        for (offset, c) in label.iter().enumerate() {
+            let (col, row) = (start + offset as i32 - container.x, center_y - container.y);
+            if let Some(cell) = canvas
+                .get_mut(row as usize)
+                .and_then(|line| line.get_mut(col as usize))
+            {
+                *cell = *c;
+            }
+        }
+    }
+
+    canvas
+        .iter()
+        .enumerate()
+        .map(|(row, line)| {
+            let len = line
+                .iter()
+                .rposition(|c| *c != ' ')
+                .map_or(0, |last| last + 1);
+            let mut out = String::new();
+            for (col, c) in line[..len].iter().enumerate() {
+                let col = col as i32;
+                let span = spans.iter().find(|(r, first, last, _)| {
+                    *r == row as i32 && (*first..=*last).contains(&col)
+                });
+                if let Some((_, first, _, color)) = span {
+                    if *first == col || col == 0 {
+                        out.push_str(&format!("\x1b[38;5;{color}m"));
+                    }
+                }
+                out.push(*c);
+                if let Some((_, _, last, _)) = span {
+                    if *last == col || col as usize == len - 1 {
+                        out.push_str(RESET);
+                    }
+                }
+            }
+            out
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Resets the color of the text, see [`RenderOptions::colors`].
+const RESET: &str = "\x1b[0m";
+
+/// A preview of a layout for a specific amount of windows, see [`contact_sheet`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderedPreview {
+    /// The amount of windows the preview shows.
+    pub window_count: usize,
+
+    /// The tiles of the windows, same as [`crate::apply`] returns them.
+    pub tiles: Vec<Rect>,
+
+    /// The tiles rendered by [`render_ascii`].
+    pub ascii: String,
+}
+
+/// Render a preview of the provided layout for each amount of windows in `counts`,
+/// all of them in the same `container` (in character units, see [`render_ascii`]),
+/// with the [`RenderOptions::default`], see [`contact_sheet_with`].
+///
+/// Meant as the single source of previews for documentation, demos, and tooltips,
+/// so that they all look alike.
+///
+/// ## Example
+/// ```rust
+/// use leftwm_layouts::{geometry::Rect, layouts::Layouts, render::contact_sheet};
+///
+/// let layout = Layouts::default().get("MainAndVertStack").unwrap().clone();
+/// let sheet = contact_sheet(&layout, &Rect::new(0, 0, 24, 8), 1..4);
+/// assert_eq!(3, sheet.len());
+/// assert_eq!(3, sheet[2].window_count);
+/// assert_eq!(3, sheet[2].tiles.len());
+/// ```
+pub fn contact_sheet(
+    definition: &Layout,
+    container: &Rect,
+    counts: Range<usize>,
+) -> Vec<RenderedPreview> {
+    contact_sheet_with(definition, container, counts, &RenderOptions::default())
+}
+
+/// Render a preview of the provided layout for each amount of windows in `counts`
+/// like [`contact_sheet`], styled by the provided `options` (see [`render_ascii_with`]).
+pub fn contact_sheet_with(
+    definition: &Layout,
+    container: &Rect,
+    counts: Range<usize>,
+    options: &RenderOptions,
+) -> Vec<RenderedPreview> {
+    counts
+        .map(|window_count| {
+            let tiles = apply(definition, window_count, container);
+            let ascii = render_ascii_with(&tiles, container, options);
+            RenderedPreview {
+                window_count,
+                tiles,
+                ascii,
+            }
+        })
+        .collect()
+}
+
+/// Format the previews of a [`contact_sheet`] side by side, [`RenderOptions::spacing`]
+/// columns apart, and with their amount of windows below them if [`RenderOptions::labels`].
+///
+/// ## Example
+/// ```rust
+/// use leftwm_layouts::{
+///     geometry::Rect,
+///     layouts::Layouts,
+///     render::{contact_sheet, format_contact_sheet, RenderOptions},
+/// };
+///
+/// let layout = Layouts::default().get("EvenHorizontal").unwrap().clone();
+/// let sheet = contact_sheet(&layout, &Rect::new(0, 0, 8, 2), 1..3);
+/// let expected = "\
+/// +-------+  +---+---+
+/// |   1   |  | 1 | 2 |
+/// +-------+  +---+---+
+/// 1 window   2 windows";
+/// assert_eq!(expected, format_contact_sheet(&sheet, &RenderOptions::default()));
+/// ```
+pub fn format_contact_sheet(sheet: &[RenderedPreview], options: &RenderOptions) -> String {
+    let columns: Vec<Vec<String>> = sheet
+        .iter()
+        .map(|preview| {
+            let mut lines: Vec<String> = preview.ascii.lines().map(String::from).collect();
+            if options.labels {
+                let plural = if preview.window_count == 1 { "" } else { "s" };
+                lines.push(format!("{} window{plural}", preview.window_count));
+            }
+            lines
+        })
+        .collect();
+    let height = columns.iter().map(Vec::len).max().unwrap_or(0);
+    let widths: Vec<usize> = columns
+        .iter()
+        .map(|lines| {
+            lines
+                .iter()
+                .map(|line| visible_width(line))
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    (0..height)
+        .map(|row| {
+            let mut line = String::new();
+            for (lines, width) in columns.iter().zip(&widths) {
+                // the caption is always the last line, below the shorter previews
+                let index = match lines.len() {
+                    len if options.labels && row == height - 1 => len - 1,
+                    len if options.labels && row >= len - 1 => usize::MAX,
+                    _ => row,
+                };
+                let cell = lines.get(index).map_or("", String::as_str);
+                line.push_str(cell);
+                let padding = width - visible_width(cell) + options.spacing;
+                line.extend(std::iter::repeat_n(' ', padding));
+            }
+            line.trim_end().to_string()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// The amount of characters of `line` that are visible, ie. not part of a color.
+fn visible_width(line: &str) -> usize {
+    let mut width = 0;
+    let mut in_escape = false;
+    for c in line.chars() {
+        match c {
+            '\x1b' => in_escape = true,
+            'm' if in_escape => in_escape = false,
+            _ if !in_escape => width += 1,
+            _ => {}
+        }
+    }
+    width
+}
+
+/// Format the provided `rects` as a table with a line per rect, listing their
+/// position and size in pixels and in percent of the `container`.
+///
+/// Meant to make the results of [`crate::apply`] readable in test failures and logs,
+/// where the [`Debug`] output of a [`Vec<Rect>`] is hard to compare.
+///
+/// ## Example
+/// ```rust
+/// use leftwm_layouts::{geometry::Rect, render::format_rects};
+///
+/// let container = Rect::new(0, 0, 1920, 1080);
+/// let rects = vec![Rect::new(0, 0, 960, 1080), Rect::new(960, 0, 960, 540)];
+/// let expected = "\
+/// ##    x  y    w     h     x%    y%     w%      h%
+/// 1    0  0  960  1080   0.0%  0.0%  50.0%  100.0%
+/// 2  960  0  960   540  50.0%  0.0%  50.0%   50.0%";
+/// assert_eq!(expected, format_rects(&rects, &container));
+/// ```
+pub fn format_rects(rects: &[Rect], container: &Rect) -> String {
+    let header = ["#", "x", "y", "w", "h", "x%", "y%", "w%", "h%"].map(String::from);
+    let rows: Vec<[String; 9]> = rects
+        .iter()
+        .enumerate()
+        .map(|(i, rect)| {
+            [
+                (i + 1).to_string(),
+                rect.x.to_string(),
+                rect.y.to_string(),
+                rect.w.to_string(),
+                rect.h.to_string(),
+                percentage(i64::from(rect.x) - i64::from(container.x), container.w),
+                percentage(i64::from(rect.y) - i64::from(container.y), container.h),
+                percentage(i64::from(rect.w), container.w),
+                percentage(i64::from(rect.h), container.h),
+            ]
+        })
+        .collect();
+
+    let mut widths = [0; 9];
+    for row in std::iter::once(&header).chain(&rows) {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = usize::max(*width, cell.len());
+        }
+    }
+
+    std::iter::once(&header)
+        .chain(&rows)
+        .map(|row| {
+            row.iter()
+                .zip(widths)
+                .map(|(cell, width)| format!("{cell:>width$}"))
+                .collect::<Vec<String>>()
+                .join("  ")
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Format `value` in percent of `whole` with one decimal, using integer math only.
+fn percentage(value: i64, whole: u32) -> String {
+    let permille = match whole {
+        0 => 0,
+        _ => value * 1000 / i64::from(whole),
+    };
+    let sign = if permille < 0 { "-" } else { "" };
+    let permille = permille.unsigned_abs();
+    format!("{sign}{}.{}%", permille / 10, permille % 10)
+}
+
+/// Calculate a hash of the geometry of the provided layout definition,
+/// ie. of the tiles it results in for the provided `window_count` and `container`.
+///
+/// Two layouts resulting in the same tiles have the same geometry hash,
+/// even if their definitions differ (eg. in their name).
+pub fn geometry_hash(definition: &Layout, window_count: usize, container: &Rect) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    apply(definition, window_count, container).hash(&mut hasher);
+    hasher.finish()
+}
+
+/// A cache of ASCII thumbnails (see [`render_ascii`]) of layouts, for example
+/// for layout-picker UIs showing many layouts at once.
+///
+/// Thumbnails are cached by the name of the layout and only rendered again
+/// if the geometry of the layout actually changed (see [`geometry_hash`]).
+///
+/// ## Example
+/// ```rust
+/// use leftwm_layouts::{layouts::Layouts, render::ThumbnailCache};
+///
+/// let mut cache = ThumbnailCache::new(24, 8, 3);
+/// let mut layout = Layouts::default().get("MainAndVertStack").unwrap().clone();
+/// let thumbnail = cache.get(&layout).to_string();
+/// assert!(!cache.is_stale(&layout));
+///
+/// layout.increase_main_size(100);
+/// assert!(cache.is_stale(&layout));
+/// assert_ne!(thumbnail, cache.get(&layout));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThumbnailCache {
+    container: Rect,
+    window_count: usize,
+    thumbnails: HashMap<String, (u64, String)>,
+}
+
+impl ThumbnailCache {
+    /// Create a new, empty [`ThumbnailCache`] for thumbnails of
+    /// `width` x `height` characters showing `window_count` windows.
+    pub fn new(width: u32, height: u32, window_count: usize) -> Self {
+        Self {
+            container: Rect::new(0, 0, width.saturating_sub(1), height.saturating_sub(1)),
+            window_count,
+            thumbnails: HashMap::new(),
+        }
+    }
+
+    /// Get the thumbnail of the provided layout,
+    /// rendering it only if it isn't cached or [`ThumbnailCache::is_stale`].
+    pub fn get(&mut self, definition: &Layout) -> &str {
+        let hash = geometry_hash(definition, self.window_count, &self.container);
+        let (cached_hash, thumbnail) = self
+            .thumbnails
+            .entry(definition.name.clone())
+            .or_insert_with(|| (!hash, String::new()));
+        if *cached_hash != hash {
+            let tiles = apply(definition, self.window_count, &self.container);
+            *thumbnail = render_ascii(&tiles, &self.container);
+            *cached_hash = hash;
+        }
+        thumbnail
+    }
+
+    /// Returns `true` if there is no up-to-date thumbnail of the provided layout in the cache.
+    pub fn is_stale(&self, definition: &Layout) -> bool {
+        let hash = geometry_hash(definition, self.window_count, &self.container);
+        self.thumbnails
+            .get(&definition.name)
+            .is_none_or(|(cached_hash, _)| *cached_hash != hash)
+    }
+
+    /// Remove all cached thumbnails.
+    pub fn clear(&mut self) {
+        self.thumbnails.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        geometry::Rect,
+        layouts::Layouts,
+        render::{
+            contact_sheet, contact_sheet_with, format_contact_sheet, format_rects, geometry_hash,
+            render_ascii, render_ascii_with, RenderOptions, ThumbnailCache,
+        },
+    };
+
+    #[test]
+    fn render_three_tiles() {
+        let tiles = vec![
+            Rect::new(0, 0, 8, 4),
+            Rect::new(8, 0, 6, 2),
+            Rect::new(8, 2, 6, 2),
+        ];
+        let expected = "\
++-------+-----+
+|       |  2  |
+|   1   +-----+
+|       |  3  |
++-------+-----+";
+        assert_eq!(expected, render_ascii(&tiles, &Rect::new(0, 0, 14, 4)));
+    }
+
+    #[test]
+    fn geometry_hash_ignores_the_name() {
+        let container = Rect::new(0, 0, 400, 200);
+        let layout = Layouts::default().get("Fibonacci").unwrap().clone();
+        let mut renamed = layout.clone();
+        renamed.name = "Renamed".to_string();
+        let dwindle = Layouts::default().get("Dwindle").unwrap().clone();
+        assert_eq!(
+            geometry_hash(&layout, 5, &container),
+            geometry_hash(&renamed, 5, &container)
+        );
+        assert_ne!(
+            geometry_hash(&layout, 5, &container),
+            geometry_hash(&dwindle, 5, &container)
+        );
+    }
+
+    #[test]
+    fn cache_is_not_stale_after_unrelated_change() {
+        let mut cache = ThumbnailCache::new(24, 8, 1);
+        let mut layout = Layouts::default().get("MainAndVertStack").unwrap().clone();
+        assert!(cache.is_stale(&layout));
+        cache.get(&layout);
+        // with a single window, the stack doesn't matter
+        layout.columns.stack.split = None;
+        assert!(!cache.is_stale(&layout));
+        cache.clear();
+        assert!(cache.is_stale(&layout));
+    }
+
+    #[test]
+    fn contact_sheet_matches_render_ascii() {
+        let container = Rect::new(0, 0, 14, 4);
+        let layout = Layouts::default().get("EvenHorizontal").unwrap().clone();
+        let sheet = contact_sheet(&layout, &container, 0..3);
+        let counts: Vec<usize> = sheet.iter().map(|preview| preview.window_count).collect();
+        assert_eq!(vec![0, 1, 2], counts);
+        assert!(sheet[0].ascii.trim().is_empty());
+        assert_eq!(
+            "\
++------+------+
+|      |      |
+|   1  |   2  |
+|      |      |
++------+------+",
+            sheet[2].ascii
+        );
+    }
+
+    #[test]
+    fn render_without_labels() {
+        let tiles = vec![Rect::new(0, 0, 6, 2), Rect::new(6, 0, 6, 2)];
+        let options = RenderOptions {
+            labels: false,
+            ..Default::default()
+        };
+        let expected = "\
++-----+-----+
+|     |     |
++-----+-----+";
+        assert_eq!(
+            expected,
+            render_ascii_with(&tiles, &Rect::new(0, 0, 12, 2), &options)
+        );
+    }
+
+    #[test]
+    fn colors_cycle_through_the_tiles() {
+        let tiles = vec![
+            Rect::new(0, 0, 4, 2),
+            Rect::new(4, 0, 4, 2),
+            Rect::new(8, 0, 4, 2),
+        ];
+        let options = RenderOptions {
+            colors: vec![1, 2],
+            ..Default::default()
+        };
+        let rendered = render_ascii_with(&tiles, &Rect::new(0, 0, 12, 2), &options);
+        let line = rendered.lines().nth(1).unwrap();
+        assert_eq!(
+            "| \x1b[38;5;1m1\x1b[0m | \x1b[38;5;2m2\x1b[0m | \x1b[38;5;1m3\x1b[0m |",
+            line
+        );
+        // uncolored, the diagram is the same as with the default options
+        let plain = line.replace("\x1b[38;5;1m", "").replace("\x1b[38;5;2m", "");
+        assert_eq!(
+            render_ascii(&tiles, &Rect::new(0, 0, 12, 2)).lines().nth(1),
+            Some(plain.replace("\x1b[0m", "").as_str())
+        );
+    }
+
+    #[test]
+    fn format_contact_sheet_with_spacing_and_colors() {
+        let layout = Layouts::default().get("EvenHorizontal").unwrap().clone();
+        let options = RenderOptions {
+            colors: vec![4],
+            spacing: 1,
+            ..Default::default()
+        };
+        let sheet = contact_sheet_with(&layout, &Rect::new(0, 0, 8, 2), 1..3, &options);
+        let expected = "\
++-------+ +---+---+
+|   \x1b[38;5;4m1\x1b[0m   | | \x1b[38;5;4m1\x1b[0m | \x1b[38;5;4m2\x1b[0m |
++-------+ +---+---+
+1 window  2 windows";
+        assert_eq!(expected, format_contact_sheet(&sheet, &options));
+        assert_eq!("", format_contact_sheet(&[], &options));
+    }
+
+    #[test]
+    fn format_contact_sheet_keeps_the_captions_aligned() {
+        let layout = Layouts::default().get("EvenHorizontal").unwrap().clone();
+        let mut sheet = contact_sheet(&layout, &Rect::new(0, 0, 4, 2), 1..2);
+        sheet.extend(contact_sheet(&layout, &Rect::new(0, 0, 4, 4), 1..2));
+        let expected = "\
++---+     +---+
+| 1 |     |   |
++---+     | 1 |
+          |   |
+          +---+
+1 window  1 window";
+        assert_eq!(
+            expected,
+            format_contact_sheet(&sheet, &RenderOptions::default())
+        );
+    }
+
+    #[test]
+    fn format_rects_aligns_columns() {
+        let container = Rect::new(100, 50, 400, 200);
+        let rects = vec![Rect::new(100, 50, 400, 100), Rect::new(90, 150, 1, 100)];
+        let expected = "\
+#    x    y    w    h     x%     y%      w%     h%
+1  100   50  400  100   0.0%   0.0%  100.0%  50.0%
+2   90  150    1  100  -2.5%  50.0%    0.2%  50.0%";
+        assert_eq!(expected, format_rects(&rects, &container));
+        assert_eq!(1, format_rects(&[], &container).lines().count());
+    }
+}
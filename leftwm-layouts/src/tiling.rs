@@ -0,0 +1,298 @@
+use crate::{
+    geometry::{Rect, ReservedGaps},
+    layouts::ColumnKind,
+    Layout,
+};
+
+/// The detailed result of [`crate::apply_detailed`].
+///
+/// Next to the tiles, which are the same as the ones returned by [`crate::apply`],
+/// it contains further information a window manager may use for rendering.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tiling {
+    /// The tiles of the windows, in the same order as the windows.
+    pub tiles: Vec<Rect>,
+
+    /// The column each of the [`Tiling::tiles`] belongs to, in the same order as the tiles.
+    pub roles: Vec<ColumnKind>,
+
+    /// The areas of empty, but reserved column space (see [`crate::geometry::Reserve`]).
+    /// This is empty if no space is reserved.
+    pub reserved: Vec<Rect>,
+
+    /// Whether the gaps around the [`Tiling::reserved`] areas are kept,
+    /// or whether the reserved areas take them in.
+    pub reserved_gaps: ReservedGaps,
+
+    /// The title bar strips shaved off the top of the [`Tiling::tiles`], in the same
+    /// order as the tiles. This is empty if the [`Layout::title_bar_height`] is `0`.
+    pub title_bars: Vec<Rect>,
+
+    /// The amount of windows the tiling was calculated for,
+    /// which may be more than the amount of [`Tiling::tiles`].
+    pub window_count: usize,
+}
+
+impl Tiling {
+    /// Create a [`Tiling`] from the provided `tiles` of the `definition`,
+    /// the reserved areas are the parts of the `container` not covered by any tile.
+    /// The tiles and the container are the ones enlarged by the [`Layout::gaps`],
+    /// the gaps are taken off the tiles, and off the reserved areas unless
+    /// the [`Layout::reserved_gaps`] are [`ReservedGaps::Collapse`].
+    pub(crate) fn new(
+        mut tiles: Vec<Rect>,
+        roles: Vec<ColumnKind>,
+        window_count: usize,
+        container: &Rect,
+        definition: &Layout,
+    ) -> Self {
+        let mut reserved = if tiles.is_empty() {
+            vec![]
+        } else {
+            uncovered_strips(&tiles, container)
+        };
+        let gaps = definition.gaps.unwrap_or_default();
+        gaps.shrink(&mut tiles);
+        match definition.reserved_gaps {
+            ReservedGaps::Keep => gaps.shrink(&mut reserved),
+            ReservedGaps::Collapse => gaps.collapse(&mut reserved, container),
+        }
+        let title_bars = shave_title_bars(&mut tiles, definition.title_bar_height);
+        Self {
+            tiles,
+            roles,
+            reserved,
+            reserved_gaps: definition.reserved_gaps,
+            title_bars,
+            window_count,
+        }
+    }
+}
+
+/// Shave a strip of the provided `height` off the top of every tile
+/// and return the strips, in the same order as the tiles.
+pub(crate) fn shave_title_bars(tiles: &mut [Rect], height: u32) -> Vec<Rect> {
+    if height == 0 {
+        return vec![];
+    }
+    tiles
+        .iter_mut()
+        .map(|tile| {
+            let strip = Rect {
+                h: tile.h.min(height),
+                ..*tile
+            };
+            tile.y += strip.h as i32;
+            tile.h -= strip.h;
+            strip
+        })
+        .collect()
+}
+
+/// Find the parts of the `container` which are not covered by any of the `tiles`.
+///
+/// Columns always span the whole container along one axis, therefore the uncovered
+/// parts are found by projecting the tiles onto the horizontal axis first,
+/// and onto the vertical axis if the columns are rotated.
+fn uncovered_strips(tiles: &[Rect], container: &Rect) -> Vec<Rect> {
+    let tiles: Vec<&Rect> = tiles.iter().filter(|t| t.surface_area() > 0).collect();
+
+    let columns = uncovered_ranges(
+        tiles.iter().map(|t| (t.left_edge(), t.right_edge())),
+        container.left_edge(),
+        container.right_edge(),
+    );
+    if !columns.is_empty() {
+        return columns
+            .into_iter()
+            .map(|(start, end)| Rect::new(start, container.y, (end - start) as u32, container.h))
+            .collect();
+    }
+
+    uncovered_ranges(
+        tiles.iter().map(|t| (t.top_edge(), t.bottom_edge())),
+        container.top_edge(),
+        container.bottom_edge(),
+    )
+    .into_iter()
+    .map(|(start, end)| Rect::new(container.x, start, container.w, (end - start) as u32))
+    .collect()
+}
+
+/// Find the parts of `min..max` which are not covered by any of the `ranges`.
+fn uncovered_ranges(
+    ranges: impl Iterator<Item = (i32, i32)>,
+    min: i32,
+    max: i32,
+) -> Vec<(i32, i32)> {
+    let mut ranges: Vec<(i32, i32)> = ranges.collect();
+    ranges.sort_unstable();
+
+    let mut uncovered = vec![];
+    let mut covered_until = min;
+    for (start, end) in ranges {
+        if start > covered_until {
+            uncovered.push((covered_until, start.min(max)));
+        }
+        covered_until = covered_until.max(end);
+    }
+    if covered_until < max {
+        uncovered.push((covered_until, max));
+    }
+    uncovered.retain(|(start, end)| start < end);
+    uncovered
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        apply, apply_detailed,
+        geometry::{Gaps, Rect, Reserve, ReservedGaps, Rotation},
+        layouts::{ColumnKind, Layouts},
+    };
+
+    const CONTAINER: Rect = Rect {
+        x: 0,
+        y: 0,
+        w: 400,
+        h: 200,
+    };
+
+    #[test]
+    fn no_reserved_area_without_reserve() {
+        let layout = Layouts::default().get("MainAndVertStack").unwrap().clone();
+        let tiling = apply_detailed(&layout, 1, &CONTAINER);
+        assert_eq!(vec![CONTAINER], tiling.tiles);
+        assert!(tiling.reserved.is_empty());
+    }
+
+    #[test]
+    fn reserved_stack_is_reported() {
+        let mut layout = Layouts::default().get("MainAndVertStack").unwrap().clone();
+        layout.reserve = Reserve::Reserve;
+        layout.reserved_gaps = ReservedGaps::Collapse;
+        let tiling = apply_detailed(&layout, 1, &CONTAINER);
+        assert_eq!(vec![Rect::new(0, 0, 200, 200)], tiling.tiles);
+        assert_eq!(vec![Rect::new(200, 0, 200, 200)], tiling.reserved);
+        assert_eq!(ReservedGaps::Collapse, tiling.reserved_gaps);
+    }
+
+    #[test]
+    fn collapsed_reserved_areas_take_in_the_gaps() {
+        let mut layout = Layouts::default().get("MainAndVertStack").unwrap().clone();
+        layout.reserve = Reserve::ReserveAndCenter;
+        layout.gaps = Some(Gaps {
+            inner: 10,
+            ..Default::default()
+        });
+        let tiling = apply_detailed(&layout, 1, &CONTAINER);
+        assert_eq!(
+            vec![Rect::new(0, 0, 92, 200), Rect::new(307, 0, 93, 200)],
+            tiling.reserved
+        );
+
+        layout.reserved_gaps = ReservedGaps::Collapse;
+        let tiling = apply_detailed(&layout, 1, &CONTAINER);
+        assert_eq!(vec![Rect::new(102, 0, 195, 200)], tiling.tiles);
+        assert_eq!(
+            vec![Rect::new(0, 0, 102, 200), Rect::new(297, 0, 103, 200)],
+            tiling.reserved
+        );
+    }
+
+    #[test]
+    fn reserved_and_centered_main_has_reserved_areas_on_both_sides() {
+        let mut layout = Layouts::default().get("MainAndVertStack").unwrap().clone();
+        layout.reserve = Reserve::ReserveAndCenter;
+        let tiling = apply_detailed(&layout, 1, &CONTAINER);
+        assert_eq!(vec![Rect::new(100, 0, 200, 200)], tiling.tiles);
+        assert_eq!(
+            vec![Rect::new(0, 0, 100, 200), Rect::new(300, 0, 100, 200)],
+            tiling.reserved
+        );
+    }
+
+    #[test]
+    fn reserved_area_of_rotated_layout_is_reported() {
+        let mut layout = Layouts::default().get("MainAndVertStack").unwrap().clone();
+        layout.reserve = Reserve::Reserve;
+        layout.rotate = Rotation::East;
+        let tiling = apply_detailed(&layout, 1, &CONTAINER);
+        assert_eq!(vec![Rect::new(0, 0, 400, 100)], tiling.tiles);
+        assert_eq!(vec![Rect::new(0, 100, 400, 100)], tiling.reserved);
+    }
+
+    #[test]
+    fn roles_follow_the_columns() {
+        let layout = Layouts::default().get("CenterMain").unwrap().clone();
+        let tiling = apply_detailed(&layout, 4, &CONTAINER);
+        assert_eq!(
+            vec![
+                ColumnKind::Main,
+                ColumnKind::Stack,
+                ColumnKind::SecondStack,
+                ColumnKind::SecondStack
+            ],
+            tiling.roles
+        );
+    }
+
+    #[test]
+    fn dual_stacks_have_their_own_roles() {
+        let layout = Layouts::default().get("MainAndDualStack").unwrap().clone();
+        let tiling = apply_detailed(&layout, 5, &CONTAINER);
+        assert_eq!(
+            vec![
+                ColumnKind::Main,
+                ColumnKind::Stack,
+                ColumnKind::Stack,
+                ColumnKind::SecondStack,
+                ColumnKind::SecondStack
+            ],
+            tiling.roles
+        );
+        assert_eq!(
+            vec![
+                Rect::new(0, 0, 200, 200),
+                Rect::new(200, 0, 100, 100),
+                Rect::new(200, 100, 100, 100),
+                Rect::new(300, 0, 100, 100),
+                Rect::new(300, 100, 100, 100),
+            ],
+            tiling.tiles
+        );
+    }
+
+    #[test]
+    fn no_reserved_area_without_windows() {
+        let mut layout = Layouts::default().get("CenterMainFluid").unwrap().clone();
+        layout.reserve = Reserve::Reserve;
+        let tiling = apply_detailed(&layout, 0, &CONTAINER);
+        assert!(tiling.tiles.is_empty());
+        assert!(tiling.reserved.is_empty());
+    }
+
+    #[test]
+    fn title_bars_are_shaved_off_the_tiles() {
+        let mut layout = Layouts::default().get("MainAndVertStack").unwrap().clone();
+        layout.title_bar_height = 20;
+        layout.reserve = Reserve::Reserve;
+        layout.rotate = Rotation::East;
+        let tiling = apply_detailed(&layout, 1, &CONTAINER);
+        assert_eq!(vec![Rect::new(0, 20, 400, 80)], tiling.tiles);
+        assert_eq!(vec![Rect::new(0, 0, 400, 20)], tiling.title_bars);
+        assert_eq!(vec![Rect::new(0, 100, 400, 100)], tiling.reserved);
+        assert_eq!(tiling.tiles, apply(&layout, 1, &CONTAINER));
+
+        layout.title_bar_height = 150;
+        let tiling = apply_detailed(&layout, 1, &CONTAINER);
+        assert_eq!(vec![Rect::new(0, 100, 400, 0)], tiling.tiles);
+        assert_eq!(vec![Rect::new(0, 0, 400, 100)], tiling.title_bars);
+    }
+
+    #[test]
+    fn no_title_bars_by_default() {
+        let layout = Layouts::default().get("CenterMain").unwrap().clone();
+        assert!(apply_detailed(&layout, 3, &CONTAINER).title_bars.is_empty());
+    }
+}